@@ -8,17 +8,23 @@ use embedded_graphics::{
     prelude::*,
     text::Text,
 };
-use embedded_layout::{layout::linear::LinearLayout, prelude::*, ViewGroup};
+use embedded_layout::{
+    layout::linear::LinearLayout, prelude::*, view_group::ConstLenViewGroup, ViewGroup,
+};
 
-// We need to make our Layout generic over the pixel color, because `derive(ViewGroup)` implements
-// `Drawable<C>` only if the struct has a PixelColor type parameter.
+// `derive(ViewGroup)` implements `Drawable` for structs with a `PixelColor` type parameter. Our
+// struct uses a concrete color type, so we need to tell the macro which color to use.
 #[derive(ViewGroup)]
-struct Layout<'txt, C: PixelColor> {
-    text_vertical: Text<'txt, MonoTextStyle<'static, C>>,
-    text_linear: Text<'txt, MonoTextStyle<'static, C>>,
-    text_layout: Text<'txt, MonoTextStyle<'static, C>>,
+#[viewgroup(color = BinaryColor)]
+struct Layout<'txt> {
+    text_vertical: Text<'txt, MonoTextStyle<'static, BinaryColor>>,
+    text_linear: Text<'txt, MonoTextStyle<'static, BinaryColor>>,
+    text_layout: Text<'txt, MonoTextStyle<'static, BinaryColor>>,
 }
 
+// The number of views in a derived view group is known at compile time.
+const _: () = assert!(<Layout<'static> as ConstLenViewGroup>::LEN == 3);
+
 fn main() -> Result<(), core::convert::Infallible> {
     let mut display: SimulatorDisplay<BinaryColor> = SimulatorDisplay::new(Size::new(64, 48));
     let output_settings = OutputSettingsBuilder::new()
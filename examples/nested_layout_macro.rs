@@ -18,6 +18,7 @@ use embedded_layout_macros::ViewGroup;
 // We need to make our Layout generic over the pixel color, because `derive(ViewGroup)` implements
 // `Drawable<C>` only if the struct has a PixelColor type parameter.
 #[derive(ViewGroup)]
+#[allow(clippy::type_complexity)]
 struct Layout<'txt, C: PixelColor> {
     layout: LinearLayout<
         Vertical<Center, Tight>,
@@ -4,17 +4,100 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{format_ident, quote};
 use syn::{
-    self, parse_macro_input, Data, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, GenericParam,
-    LitInt, TypeParamBound,
+    self, parse_macro_input, Data, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Generics,
+    Ident, LitInt, PredicateType, Type, TypeParamBound, TypePath, WherePredicate,
 };
 
-#[proc_macro_derive(ViewGroup)]
+/// Implements `ViewGroup` for a struct or an enum.
+///
+/// `Drawable` is also implemented if the type has a type parameter bound by `PixelColor`, either
+/// in the parameter list or in a `where` clause. For types that use a concrete color type, specify
+/// the color using the `viewgroup` attribute:
+///
+/// ```rust
+/// # use embedded_graphics::{mono_font::MonoTextStyle, pixelcolor::BinaryColor, text::Text};
+/// # use embedded_layout::ViewGroup;
+/// #[derive(ViewGroup)]
+/// #[viewgroup(color = BinaryColor)]
+/// struct Layout<'txt> {
+///     title: Text<'txt, MonoTextStyle<'static, BinaryColor>>,
+///     body: Text<'txt, MonoTextStyle<'static, BinaryColor>>,
+/// }
+/// ```
+///
+/// If the color parameter is bound by a trait that only implies `PixelColor`, e.g. a trait alias,
+/// name the parameter using `color_param`:
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     pixelcolor::{BinaryColor, PixelColor},
+/// #     primitives::{Circle, PrimitiveStyle, Rectangle, Styled},
+/// # };
+/// # use embedded_layout::ViewGroup;
+/// trait ThemeColor: PixelColor + From<BinaryColor> {}
+///
+/// #[derive(ViewGroup)]
+/// #[viewgroup(color_param = C)]
+/// struct Layout<C: ThemeColor> {
+///     icon: Styled<Circle, PrimitiveStyle<C>>,
+///     frame: Styled<Rectangle, PrimitiveStyle<C>>,
+/// }
+/// ```
+///
+/// The generated code implements `View` for the type, which doesn't require the fields to be
+/// `Clone`. Use `#[viewgroup(transform)]` to implement `embedded-graphics`' `Transform` instead.
+/// `View` is then provided by its blanket implementation, but every field must implement `Clone`.
+///
+/// `ViewGroup::bounds_of` and `ViewGroup::translate_child` access the fields directly, for structs
+/// as well as for every variant of an enum, instead of going through `&dyn View`. This keeps
+/// arranging derived view groups cheap even in tight layout loops.
+///
+/// `ConstLenViewGroup` is also implemented. For structs, its `LEN` associated constant is the number
+/// of fields. For enums, `LEN` is the number of fields of the largest variant, so it is an upper
+/// bound of `ViewGroup::len`, which depends on the current variant.
+#[proc_macro_derive(ViewGroup, attributes(viewgroup))]
 pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
-    let empty_vg_instance = quote!(unsafe { &embedded_layout::view_group::EMPTY_VIEW_GROUP });
-    let empty_vg_instance_mut =
-        quote!(unsafe { &mut embedded_layout::view_group::EMPTY_VIEW_GROUP });
+    let mut color = None;
+    let mut transform = false;
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("viewgroup"))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("color") {
+                if color.is_some() {
+                    return Err(meta.error("the color is already specified"));
+                }
+                color = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else if meta.path.is_ident("color_param") {
+                if color.is_some() {
+                    return Err(meta.error("the color is already specified"));
+                }
+                let param = meta.value()?.parse::<Ident>()?;
+                if !ast.generics.type_params().any(|tp| tp.ident == param) {
+                    return Err(syn::Error::new(
+                        param.span(),
+                        format!("`{}` is not a type parameter", param),
+                    ));
+                }
+                color = Some(Type::Verbatim(quote!(#param)));
+                Ok(())
+            } else if meta.path.is_ident("transform") {
+                transform = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported viewgroup attribute"))
+            }
+        });
+
+        if let Err(error) = result {
+            return error.to_compile_error().into();
+        }
+    }
 
     let (
         field_count_impl,
@@ -54,13 +137,13 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
             let index = field_names
                 .iter()
                 .enumerate()
-                .map(|(i, f)| quote!(#i => &self.#f,))
+                .map(|(i, f)| quote!(#i => Some(&self.#f),))
                 .collect::<Vec<_>>();
 
             let index_mut = field_names
                 .iter()
                 .enumerate()
-                .map(|(i, f)| quote!(#i => &mut self.#f,))
+                .map(|(i, f)| quote!(#i => Some(&mut self.#f),))
                 .collect::<Vec<_>>();
 
             let bounds_of = field_names
@@ -82,14 +165,14 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
             let index_impl = quote! {
                 match index {
                     #(#index)*
-                    _ => #empty_vg_instance
+                    _ => None,
                 }
             };
 
             let index_mut_impl = quote! {
                 match index {
                     #(#index_mut)*
-                    _ => #empty_vg_instance_mut
+                    _ => None,
                 }
             };
 
@@ -175,13 +258,13 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
                         let fields_index = field_idents
                             .iter()
                             .enumerate()
-                            .map(|(i, f)| quote!(#i => #f,))
+                            .map(|(i, f)| quote!(#i => Some(#f),))
                             .collect::<Vec<_>>();
                         let enum_index = quote! {
                             Self::#variant_name { #(#field_idents,)* } => {
                                 match index {
                                     #(#fields_index)*
-                                    _ => #empty_vg_instance,
+                                    _ => None,
                                 }
                             }
                         };
@@ -189,7 +272,7 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
                             Self::#variant_name { #(#field_idents,)* } => {
                                 match index {
                                     #(#fields_index)*
-                                    _ => #empty_vg_instance_mut,
+                                    _ => None,
                                 }
                             }
                         };
@@ -265,13 +348,13 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
                         let fields_index = field_idents
                             .iter()
                             .enumerate()
-                            .map(|(i, f)| quote!(#i => #f,))
+                            .map(|(i, f)| quote!(#i => Some(#f),))
                             .collect::<Vec<_>>();
                         let enum_index = quote! {
                             Self::#variant_name(#(#field_idents),*) => {
                                 match index {
                                     #(#fields_index)*
-                                    _ => #empty_vg_instance,
+                                    _ => None,
                                 }
                             }
                         };
@@ -280,7 +363,7 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
                             Self::#variant_name(#(#field_idents),*) => {
                                 match index {
                                     #(#fields_index)*
-                                    _ => #empty_vg_instance_mut,
+                                    _ => None,
                                 }
                             }
                         };
@@ -336,15 +419,11 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
                             Self::#variant_name => Self::#variant_name,
                         };
                         let enum_index = quote! {
-                            Self::#variant_name => {
-                                #empty_vg_instance
-                            }
+                            Self::#variant_name => None,
                         };
 
                         let enum_mut_index = quote! {
-                            Self::#variant_name => {
-                                #empty_vg_instance_mut
-                            }
+                            Self::#variant_name => None,
                         };
                         let enum_draw = quote! {
                             Self::#variant_name => {}
@@ -440,6 +519,37 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
 
     let name = &ast.ident;
 
+    let gen_translate = if transform {
+        quote! {
+            impl #impl_generics embedded_graphics::transform::Transform for #name #ty_generics #where_clause {
+                #[inline]
+                fn translate(&self, by: embedded_graphics::geometry::Point) -> Self {
+                    #translate_impl
+                }
+
+                #[inline]
+                fn translate_mut(&mut self, by: embedded_graphics::geometry::Point) -> &mut Self {
+                    embedded_layout::view_group::ViewGroupHelper::translate(self, by);
+                    self
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics embedded_layout::View for #name #ty_generics #where_clause {
+                #[inline]
+                fn translate_impl(&mut self, by: embedded_graphics::geometry::Point) {
+                    embedded_layout::view_group::ViewGroupHelper::translate(self, by);
+                }
+
+                #[inline]
+                fn bounds(&self) -> embedded_graphics::primitives::Rectangle {
+                    embedded_layout::view_group::ViewGroupHelper::bounds(self)
+                }
+            }
+        }
+    };
+
     let gen_view_group = quote! {
         impl #impl_generics embedded_layout::view_group::ViewGroup for #name #ty_generics #where_clause {
             #[inline]
@@ -449,11 +559,27 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
 
             #[inline]
             fn at(&self, index: usize) -> &dyn embedded_layout::View {
-                #index_impl
+                match self.try_at(index) {
+                    Some(view) => view,
+                    None => panic!("ViewGroup index out of bounds: {}", index),
+                }
             }
 
             #[inline]
             fn at_mut(&mut self, index: usize) -> &mut dyn embedded_layout::View {
+                match self.try_at_mut(index) {
+                    Some(view) => view,
+                    None => panic!("ViewGroup index out of bounds: {}", index),
+                }
+            }
+
+            #[inline]
+            fn try_at(&self, index: usize) -> Option<&dyn embedded_layout::View> {
+                #index_impl
+            }
+
+            #[inline]
+            fn try_at_mut(&mut self, index: usize) -> Option<&mut dyn embedded_layout::View> {
                 #index_mut_impl
             }
 
@@ -464,24 +590,13 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
             }
 
             #[inline]
-            fn translate_child(&mut self, index: usize, by: Point) {
+            fn translate_child(&mut self, index: usize, by: embedded_graphics::geometry::Point) {
                 use embedded_layout::View;
                 #translate_child_impl
             }
         }
 
-        impl #impl_generics embedded_graphics::transform::Transform for #name #ty_generics #where_clause {
-            #[inline]
-            fn translate(&self, by: Point) -> Self {
-                #translate_impl
-            }
-
-            #[inline]
-            fn translate_mut(&mut self, by: Point) -> &mut Self {
-                embedded_layout::view_group::ViewGroupHelper::translate(self, by);
-                self
-            }
-        }
+        #gen_translate
 
         impl #impl_generics embedded_graphics::geometry::Dimensions for #name #ty_generics #where_clause {
             #[inline]
@@ -491,17 +606,30 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
         }
     };
 
-    let pixelcolor = ast.generics.params.iter().find_map(|p| {
-        if let GenericParam::Type(tp) = p {
-            if tp.bounds.iter().any(is_type_param("PixelColor")) {
-                Some(tp.ident.clone())
-            } else {
-                None
-            }
-        } else {
-            None
+    let const_len_impl = match &ast.data {
+        Data::Enum(data) => {
+            // The largest variant determines the number of views an enum can contain.
+            let max_field_count = data
+                .variants
+                .iter()
+                .map(|variant| variant.fields.len())
+                .max()
+                .unwrap_or(0);
+
+            quote!(#max_field_count)
         }
-    });
+        _ => quote!(#field_count_impl),
+    };
+
+    let gen_const_len = quote! {
+        impl #impl_generics embedded_layout::view_group::ConstLenViewGroup for #name #ty_generics #where_clause {
+            const LEN: usize = #const_len_impl;
+        }
+    };
+
+    let pixelcolor = color
+        .map(|color| quote!(#color))
+        .or_else(|| find_color_param(&ast.generics).map(|ident| quote!(#ident)));
 
     let gen_drawable_impl = if let Some(pixelcolor) = pixelcolor {
         quote! {
@@ -523,12 +651,39 @@ pub fn derive_viewgroup(input: TokenStream) -> TokenStream {
 
     let generated = quote! {
         #gen_view_group
+        #gen_const_len
         #gen_drawable_impl
     };
 
     TokenStream::from(generated)
 }
 
+/// Returns the first type parameter that is bound by `PixelColor`, either in the parameter list
+/// or in the `where` clause.
+fn find_color_param(generics: &Generics) -> Option<&Ident> {
+    let is_pixel_color = is_type_param("PixelColor");
+
+    generics
+        .type_params()
+        .find(|param| {
+            param.bounds.iter().any(&is_pixel_color)
+                || generics.where_clause.iter().any(|where_clause| {
+                    where_clause
+                        .predicates
+                        .iter()
+                        .any(|predicate| match predicate {
+                            WherePredicate::Type(PredicateType {
+                                bounded_ty: Type::Path(TypePath { qself: None, path }),
+                                bounds,
+                                ..
+                            }) => path.is_ident(&param.ident) && bounds.iter().any(&is_pixel_color),
+                            _ => false,
+                        })
+                })
+        })
+        .map(|param| &param.ident)
+}
+
 fn is_type_param(ident: &'static str) -> impl Fn(&TypeParamBound) -> bool {
     move |f: &TypeParamBound| -> bool {
         if let TypeParamBound::Trait(tpb) = f {
@@ -0,0 +1,165 @@
+//! Custom alignments.
+
+/// Defines a new alignment type from a single expression.
+///
+/// A custom alignment needs to implement [`Alignment`], [`HorizontalAlignment`] or
+/// [`VerticalAlignment`], and [`SecondaryAlignment`] to be usable everywhere the built-in
+/// alignments are. This macro declares a unit struct and implements all of these traits for it.
+///
+/// The expression has access to the bounds of the aligned object, the bounds of the reference and
+/// an additional offset, and returns the distance the object needs to be moved by along the
+/// alignment's axis. The names of these three values are given in closure-like syntax.
+///
+/// When used as the secondary alignment of a [`LinearLayout`], the first view is aligned to the
+/// bounds of the layout, and every other view is aligned to the previous one. The layout is as
+/// wide (or as tall) as its widest (or tallest) view, the same way as with [`horizontal::Left`],
+/// so the alignment should keep the views inside of that area.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{custom_alignment, prelude::*};
+///
+/// custom_alignment! {
+///     /// Align the center of the object to the first quarter of the reference.
+///     pub struct FirstQuarter: horizontal = |object, reference, offset| {
+///         reference.top_left.x + reference.size.width as i32 / 4 - object.center().x + offset
+///     }
+/// }
+///
+/// let reference = Rectangle::new(Point::zero(), Size::new(40, 10));
+/// let rect = Rectangle::new(Point::zero(), Size::new(5, 5))
+///     .align_to(&reference, FirstQuarter, vertical::Top);
+///
+/// assert_eq!(Point::new(8, 0), rect.top_left);
+/// ```
+///
+/// [`Alignment`]: crate::align::Alignment
+/// [`HorizontalAlignment`]: crate::align::HorizontalAlignment
+/// [`VerticalAlignment`]: crate::align::VerticalAlignment
+/// [`SecondaryAlignment`]: crate::layout::linear::SecondaryAlignment
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`horizontal::Left`]: crate::align::horizontal::Left
+#[macro_export]
+macro_rules! custom_alignment {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident: horizontal =
+            |$object:ident, $reference:ident, $offset:ident| $body:expr
+    ) => {
+        $crate::custom_alignment!(@impl
+            $(#[$meta])* $vis struct $name, HorizontalAlignment,
+            |$object, $reference, $offset| $body,
+            |prev, view| $crate::__private::Size::new(
+                prev.width.max(view.width),
+                prev.height + view.height,
+            )
+        );
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident: vertical =
+            |$object:ident, $reference:ident, $offset:ident| $body:expr
+    ) => {
+        $crate::custom_alignment!(@impl
+            $(#[$meta])* $vis struct $name, VerticalAlignment,
+            |$object, $reference, $offset| $body,
+            |prev, view| $crate::__private::Size::new(
+                prev.width + view.width,
+                prev.height.max(view.height),
+            )
+        );
+    };
+
+    (@impl
+        $(#[$meta:meta])* $vis:vis struct $name:ident, $axis:ident,
+        |$object:ident, $reference:ident, $offset:ident| $body:expr,
+        |$prev:ident, $view:ident| $measure:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Default)]
+        $vis struct $name;
+
+        impl $crate::align::$axis for $name {}
+
+        impl $crate::align::Alignment for $name {
+            #[inline]
+            fn align_with_offset(
+                &self,
+                $object: $crate::__private::Rectangle,
+                $reference: $crate::__private::Rectangle,
+                $offset: i32,
+            ) -> i32 {
+                $body
+            }
+        }
+
+        impl $crate::layout::linear::SecondaryAlignment for $name {
+            type First = $name;
+
+            #[inline]
+            fn measure(
+                $prev: $crate::__private::Size,
+                $view: $crate::__private::Size,
+            ) -> $crate::__private::Size {
+                $measure
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        geometry::AnchorPoint,
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    custom_alignment! {
+        /// Align the top edge of the object one pixel above the bottom edge of the reference.
+        struct Overhang: vertical = |object, reference, offset| {
+            reference.anchor_point(AnchorPoint::BottomLeft).y - object.top_left.y - 1 + offset
+        }
+    }
+
+    custom_alignment! {
+        struct Indent: horizontal = |object, reference, offset| {
+            reference.top_left.x + 2 - object.top_left.x + offset
+        }
+    }
+
+    custom_alignment! {
+        struct RightEdges: horizontal = |object, reference, offset| {
+            reference.top_left.x + reference.size.width as i32
+                - (object.top_left.x + object.size.width as i32)
+                + offset
+        }
+    }
+
+    #[test]
+    fn custom_alignment_with_align_to() {
+        let reference = Rectangle::new(Point::zero(), Size::new(10, 10));
+        let rect = Rectangle::new(Point::new(20, 20), Size::new(3, 3))
+            .align_to(&reference, Indent, Overhang);
+
+        assert_eq!(Point::new(2, 8), rect.top_left);
+    }
+
+    #[test]
+    fn custom_alignment_in_layout() {
+        let narrow = Rectangle::new(Point::zero(), Size::new(3, 2));
+        let wide = Rectangle::new(Point::zero(), Size::new(5, 2));
+
+        let layout = LinearLayout::vertical(Chain::new(narrow).append(wide))
+            .with_alignment(RightEdges)
+            .arrange();
+
+        assert_eq!(Size::new(5, 4), layout.size());
+        assert_eq!(Point::new(2, 0), layout.inner().parent.object.top_left);
+        assert_eq!(Point::new(0, 2), layout.inner().object.top_left);
+    }
+}
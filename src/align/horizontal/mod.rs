@@ -1,7 +1,7 @@
 //! Horizontal alignment options
 //!
 //! Horizontal alignment types must implement [`HorizontalAlignment`].
-use crate::align::{Alignment, HorizontalAlignment};
+use crate::align::{Alignment, HorizontalAlignment, RoundingMode};
 use embedded_graphics::{geometry::AnchorPoint, primitives::Rectangle};
 
 /// Keep the objects' horizontal alignment unchanged
@@ -19,7 +19,7 @@ impl Alignment for NoAlignment {
 /// Center the objects horizontally
 ///
 /// *Note:* in certain cases it's not possible to center objects perfectly because of
-///         the integer coordinates used.
+///         the integer coordinates used. Use [`CenterRounded`] to select the rounding direction.
 #[derive(Copy, Clone, Default)]
 pub struct Center;
 impl HorizontalAlignment for Center {}
@@ -32,6 +32,46 @@ impl Alignment for Center {
     }
 }
 
+/// Center the objects horizontally, rounding in the given direction
+///
+/// If the object can't be centered perfectly, [`RoundingMode`] selects whether the object is
+/// placed closer to the left edge of the reference, or further from it. Unlike [`Center`], the
+/// direction doesn't depend on the parity of the sizes.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{align::RoundingMode, prelude::*};
+///
+/// let reference = Rectangle::new(Point::zero(), Size::new(10, 10));
+/// let object = Rectangle::new(Point::zero(), Size::new(3, 3));
+///
+/// let floor = object.align_to(
+///     &reference,
+///     horizontal::CenterRounded(RoundingMode::Floor),
+///     vertical::CenterRounded(RoundingMode::Floor),
+/// );
+/// let ceil = object.align_to(
+///     &reference,
+///     horizontal::CenterRounded(RoundingMode::Ceil),
+///     vertical::CenterRounded(RoundingMode::Ceil),
+/// );
+///
+/// assert_eq!(Point::new(3, 3), floor.top_left);
+/// assert_eq!(Point::new(4, 4), ceil.top_left);
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct CenterRounded(pub RoundingMode);
+impl HorizontalAlignment for CenterRounded {}
+
+impl Alignment for CenterRounded {
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        self.0.center_x(object, reference) + offset
+    }
+}
+
 /// Align the left edge of the object to the left edge of the reference
 #[derive(Copy, Clone, Default)]
 pub struct Left;
@@ -92,6 +132,27 @@ impl Alignment for RightToLeft {
     }
 }
 
+/// Align the left edge of the object to the left edge of the reference, and make the object as
+/// wide as the reference, if possible
+///
+/// In a vertical [`LinearLayout`], views that implement [`ResizableView`] are resized to the width
+/// of the layout. Other views, and alignment operations that can't resize objects, like
+/// [`Align::align_to`], align the left edges of the objects, same as [`Left`].
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`ResizableView`]: crate::layout::ResizableView
+/// [`Align::align_to`]: crate::align::Align::align_to
+#[derive(Copy, Clone, Default)]
+pub struct Stretch;
+impl HorizontalAlignment for Stretch {}
+
+impl Alignment for Stretch {
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        Left.align_with_offset(object, reference, offset)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -5,11 +5,11 @@
 //!
 //! The list of currently supported alignments:
 //!  - [`horizontal`]
-//!    - `NoAlignment`, `Left`, `Center`, `Right`
+//!    - `NoAlignment`, `Left`, `Center`, `CenterRounded`, `Right`, `Stretch`
 //!    - `LeftToRight`
 //!    - `RightToLeft`
 //!  - [`vertical`]
-//!    - `NoAlignment`, `Top`, `Center`, `Bottom`
+//!    - `NoAlignment`, `Top`, `Center`, `CenterRounded`, `Bottom`, `Baseline`, `Stretch`
 //!    - `TopToBottom`
 //!    - `BottomToTop`
 //!
@@ -18,16 +18,34 @@
 //! and two alignment parameters. The second [`View`] will not be translated by the alignment
 //! operation.
 //!
+//! Any alignment can be moved by a fixed number of pixels using [`WithOffset`], or rounded to a grid
+//! using [`SnapToGrid`]. New alignments can be defined using the [`custom_alignment!`] macro.
+//!
+//! [`custom_alignment!`]: crate::custom_alignment
 //! [`horizontal`]: crate::align::horizontal
 //! [`vertical`]: crate::align::vertical
 //! [`align_*`]: crate::align::Align
 //! [`align_to`]: crate::align::Align::align_to
 //! [`align_to_mut`]: crate::align::Align::align_to_mut
-use crate::prelude::*;
-use embedded_graphics::{prelude::Point, primitives::Rectangle};
+use crate::{prelude::*, view_group::ViewGroup};
+use embedded_graphics::{
+    geometry::AnchorPoint,
+    prelude::{Dimensions, Point, Size},
+    primitives::Rectangle,
+};
 
+mod custom;
 pub mod horizontal;
+mod placement;
+mod rounding;
+mod snap_to_grid;
 pub mod vertical;
+mod with_offset;
+
+pub use placement::place_non_overlapping;
+pub use rounding::RoundingMode;
+pub use snap_to_grid::SnapToGrid;
+pub use with_offset::WithOffset;
 
 /// This trait enables alignment operations for [`View`] objects
 ///
@@ -51,6 +69,171 @@ pub trait Align {
     where
         H: HorizontalAlignment,
         V: VerticalAlignment;
+
+    /// Return the object aligned to a point using the alignment parameters as rules
+    ///
+    /// The point is treated as a zero sized reference view, so `horizontal::Left` places the left
+    /// edge of the object at the point, and `horizontal::Center` centers the object on it.
+    ///
+    /// # Example
+    ///
+    /// Center a label above a data point of a graph:
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::prelude::*;
+    ///
+    /// let data_point = Point::new(40, 20);
+    /// let label = Rectangle::new(Point::zero(), Size::new(11, 5))
+    ///     .align_to_point(data_point, horizontal::Center, vertical::BottomToTop);
+    ///
+    /// assert_eq!(Rectangle::new(Point::new(35, 15), Size::new(11, 5)), label);
+    /// ```
+    fn align_to_point<H, V>(self, point: Point, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Align the object to a point using the alignment parameters as rules
+    ///
+    /// The point is treated as a zero sized reference view.
+    fn align_to_point_mut<H, V>(&mut self, point: Point, horizontal: H, vertical: V) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Return the object aligned to an other one, using the anchor point of the object
+    ///
+    /// Views that have an [anchor point] are aligned as if they were a zero sized view at their
+    /// anchor point, so `horizontal::Left` places the anchor on the left edge of the reference.
+    /// Other views are aligned using their bounds, the same way as by [`Align::align_to`].
+    ///
+    /// [anchor point]: crate::View::anchor
+    fn align_anchor_to<H, V>(self, reference: &impl View, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Align the object to an other one, using the anchor point of the object
+    ///
+    /// See [`Align::align_anchor_to`].
+    fn align_anchor_to_mut<H, V>(
+        &mut self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Return the object aligned to an other one, kept inside a container
+    ///
+    /// The object is aligned the same way as by [`Align::align_to`], and then moved by the
+    /// smallest amount that keeps it inside `container`. If the object is larger than the
+    /// container, its top left corner is placed at the top left corner of the container.
+    ///
+    /// # Example
+    ///
+    /// Place a tooltip above a point, without letting it leave the display:
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::prelude::*;
+    ///
+    /// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+    /// let cursor = Rectangle::new(Point::new(120, 30), Size::new(3, 3));
+    ///
+    /// let tooltip = Rectangle::new(Point::zero(), Size::new(30, 10)).align_to_clamped(
+    ///     &cursor,
+    ///     horizontal::Center,
+    ///     vertical::BottomToTop,
+    ///     &display_area,
+    /// );
+    ///
+    /// assert_eq!(Point::new(98, 20), tooltip.top_left);
+    /// ```
+    fn align_to_clamped<H, V>(
+        self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+        container: &impl Dimensions,
+    ) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Align the object to an other one, and keep it inside a container
+    ///
+    /// See [`Align::align_to_clamped`].
+    fn align_to_clamped_mut<H, V>(
+        &mut self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+        container: &impl Dimensions,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment;
+
+    /// Return the object centered in an area, e.g. the bounding box of a display
+    ///
+    /// This is a shorthand for aligning the object using `horizontal::Center` and
+    /// `vertical::Center`, but `area` can be anything that has a bounding box, including draw
+    /// targets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{
+    /// #     mock_display::MockDisplay,
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     primitives::Rectangle,
+    /// # };
+    /// use embedded_layout::prelude::*;
+    ///
+    /// let display: MockDisplay<BinaryColor> = MockDisplay::new();
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(10, 10)).center_in(&display);
+    ///
+    /// assert_eq!(Point::new(27, 27), rect.top_left);
+    /// ```
+    fn center_in(self, area: &impl Dimensions) -> Self;
+
+    /// Center the object in an area, e.g. the bounding box of a display
+    fn center_in_mut(&mut self, area: &impl Dimensions) -> &mut Self;
+
+    /// Return the object placed at one of the nine anchor points of an area
+    ///
+    /// The anchor point of the object is moved to the same anchor point of `area`, so
+    /// `AnchorPoint::BottomRight` places the object into the bottom right corner of the area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{
+    /// #     mock_display::MockDisplay,
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     primitives::Rectangle,
+    /// # };
+    /// use embedded_graphics::geometry::AnchorPoint;
+    /// use embedded_layout::prelude::*;
+    ///
+    /// let display: MockDisplay<BinaryColor> = MockDisplay::new();
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(10, 10))
+    ///     .anchor_in(&display, AnchorPoint::BottomRight);
+    ///
+    /// assert_eq!(Point::new(54, 54), rect.top_left);
+    /// ```
+    fn anchor_in(self, area: &impl Dimensions, anchor: AnchorPoint) -> Self;
+
+    /// Place the object at one of the nine anchor points of an area
+    fn anchor_in_mut(&mut self, area: &impl Dimensions, anchor: AnchorPoint) -> &mut Self;
 }
 
 impl<T> Align for T
@@ -81,6 +264,195 @@ where
 
         self.translate_mut(Point::new(h, v))
     }
+
+    #[inline]
+    fn align_to_point<H, V>(mut self, point: Point, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_to_point_mut(point, horizontal, vertical);
+        self
+    }
+
+    #[inline]
+    fn align_to_point_mut<H, V>(&mut self, point: Point, horizontal: H, vertical: V) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        let reference = Rectangle::new(point, Size::zero());
+
+        self.align_to_mut(&reference, horizontal, vertical)
+    }
+
+    #[inline]
+    fn align_anchor_to<H, V>(mut self, reference: &impl View, horizontal: H, vertical: V) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_anchor_to_mut(reference, horizontal, vertical);
+        self
+    }
+
+    #[inline]
+    fn align_anchor_to_mut<H, V>(
+        &mut self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        let object = match self.anchor() {
+            Some(anchor) => Rectangle::new(anchor, Size::zero()),
+            None => self.bounds(),
+        };
+        let reference = reference.bounds();
+
+        let by = Point::new(
+            horizontal.align(object, reference),
+            vertical.align(object, reference),
+        );
+
+        self.translate_mut(by)
+    }
+
+    #[inline]
+    fn align_to_clamped<H, V>(
+        mut self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+        container: &impl Dimensions,
+    ) -> Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_to_clamped_mut(reference, horizontal, vertical, container);
+        self
+    }
+
+    #[inline]
+    fn align_to_clamped_mut<H, V>(
+        &mut self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+        container: &impl Dimensions,
+    ) -> &mut Self
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        self.align_to_mut(reference, horizontal, vertical);
+
+        let bounds = self.bounds();
+        let top_left = clamped_top_left(bounds, container.bounding_box());
+
+        self.translate_mut(top_left - bounds.top_left)
+    }
+
+    #[inline]
+    fn center_in(self, area: &impl Dimensions) -> Self {
+        self.anchor_in(area, AnchorPoint::Center)
+    }
+
+    #[inline]
+    fn center_in_mut(&mut self, area: &impl Dimensions) -> &mut Self {
+        self.anchor_in_mut(area, AnchorPoint::Center)
+    }
+
+    #[inline]
+    fn anchor_in(mut self, area: &impl Dimensions, anchor: AnchorPoint) -> Self {
+        self.anchor_in_mut(area, anchor);
+        self
+    }
+
+    #[inline]
+    fn anchor_in_mut(&mut self, area: &impl Dimensions, anchor: AnchorPoint) -> &mut Self {
+        let by = area.bounding_box().anchor_point(anchor) - self.bounds().anchor_point(anchor);
+
+        self.translate_mut(by)
+    }
+}
+
+/// Returns the top left corner of `bounds`, moved by the smallest amount that keeps `bounds` inside
+/// `container`.
+pub(crate) fn clamped_top_left(bounds: Rectangle, container: Rectangle) -> Point {
+    let clamp = |start: i32, size: u32, container_start: i32, container_size: u32| {
+        let max = container_start + container_size as i32 - size as i32;
+        start.min(max).max(container_start)
+    };
+
+    Point::new(
+        clamp(
+            bounds.top_left.x,
+            bounds.size.width,
+            container.top_left.x,
+            container.size.width,
+        ),
+        clamp(
+            bounds.top_left.y,
+            bounds.size.height,
+            container.top_left.y,
+            container.size.height,
+        ),
+    )
+}
+
+/// Align every view of a [`ViewGroup`] to the same reference
+///
+/// Each view is aligned independently, as if [`Align::align_to_mut`] was called on every view of
+/// the group. This is different from aligning the view group itself, which moves the views
+/// together and keeps their relative positions.
+///
+/// # Example
+///
+/// Place a set of status icons into the top right corner of the display:
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{align::align_group, prelude::*, view_group::ViewGroup};
+///
+/// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+/// let mut icons = [
+///     Rectangle::new(Point::zero(), Size::new(8, 8)),
+///     Rectangle::new(Point::new(20, 20), Size::new(6, 6)),
+/// ];
+/// let mut icons = Views::new(&mut icons);
+///
+/// align_group(&mut icons, &display_area, horizontal::Right, vertical::Top);
+///
+/// assert_eq!(Point::new(120, 0), icons.bounds_of(0).top_left);
+/// assert_eq!(Point::new(122, 0), icons.bounds_of(1).top_left);
+/// ```
+///
+/// [`ViewGroup`]: crate::view_group::ViewGroup
+#[inline]
+pub fn align_group<H, V>(
+    views: &mut impl ViewGroup,
+    reference: &impl View,
+    horizontal: H,
+    vertical: V,
+) where
+    H: HorizontalAlignment,
+    V: VerticalAlignment,
+{
+    let reference_bounds = reference.bounds();
+
+    for i in 0..views.len() {
+        let bounds = views.bounds_of(i);
+
+        let h = horizontal.align(bounds, reference_bounds);
+        let v = vertical.align(bounds, reference_bounds);
+
+        views.translate_child(i, Point::new(h, v));
+    }
 }
 
 /// Base trait for alignment operations
@@ -123,3 +495,100 @@ pub trait HorizontalAlignment: Alignment {}
 ///
 /// [`vertical`]: crate::align::vertical
 pub trait VerticalAlignment: Alignment {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn views_are_aligned_independently() {
+        let reference = Rectangle::new(Point::new(10, 10), Size::new(10, 10));
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut views = Chain::new(rect)
+            .append(rect.translate(Point::new(30, 5)))
+            .append(Rectangle::new(Point::zero(), Size::new(4, 6)));
+
+        align_group(&mut views, &reference, horizontal::Center, vertical::Bottom);
+
+        assert_eq!(Point::new(14, 18), views.parent.parent.object.top_left);
+        assert_eq!(Point::new(14, 18), views.parent.object.top_left);
+        assert_eq!(Point::new(13, 14), views.object.top_left);
+    }
+
+    #[test]
+    fn views_are_aligned_to_point() {
+        let rect = Rectangle::new(Point::new(3, 3), Size::new(4, 3));
+        let point = Point::new(10, 20);
+
+        assert_eq!(
+            Point::new(10, 20),
+            rect.align_to_point(point, horizontal::Left, vertical::Top)
+                .top_left
+        );
+        assert_eq!(
+            Point::new(7, 18),
+            rect.align_to_point(point, horizontal::Right, vertical::Bottom)
+                .top_left
+        );
+        assert_eq!(
+            Point::new(9, 19),
+            rect.align_to_point(point, horizontal::Center, vertical::Center)
+                .top_left
+        );
+    }
+
+    #[test]
+    fn views_are_anchored_in_area() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(11, 9));
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 3));
+
+        assert_eq!(Point::new(14, 13), rect.center_in(&area).top_left);
+        assert_eq!(
+            rect.align_to(&area, horizontal::Center, vertical::Center),
+            rect.center_in(&area)
+        );
+        assert_eq!(
+            Point::new(10, 16),
+            rect.anchor_in(&area, AnchorPoint::BottomLeft).top_left
+        );
+        assert_eq!(
+            Point::new(18, 10),
+            rect.anchor_in(&area, AnchorPoint::TopRight).top_left
+        );
+    }
+
+    #[test]
+    fn clamped_views_stay_inside_container() {
+        let container = Rectangle::new(Point::new(10, 10), Size::new(20, 20));
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 4));
+
+        let corner = Rectangle::new(Point::new(8, 28), Size::new(1, 1));
+        assert_eq!(
+            Point::new(10, 26),
+            rect.align_to_clamped(
+                &corner,
+                horizontal::RightToLeft,
+                vertical::TopToBottom,
+                &container
+            )
+            .top_left
+        );
+
+        // Views that already fit are not moved
+        let inside = Rectangle::new(Point::new(15, 15), Size::new(5, 5));
+        assert_eq!(
+            rect.align_to(&inside, horizontal::Center, vertical::Center),
+            rect.align_to_clamped(&inside, horizontal::Center, vertical::Center, &container)
+        );
+
+        // Views larger than the container are placed at its top left corner
+        let large = Rectangle::new(Point::zero(), Size::new(30, 30));
+        assert_eq!(
+            container.top_left,
+            large
+                .align_to_clamped(&inside, horizontal::Right, vertical::Bottom, &container)
+                .top_left
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use embedded_graphics::{
+    prelude::{Dimensions, Point},
+    primitives::Rectangle,
+};
+
+use crate::{align::clamped_top_left, view_group::ViewGroup};
+
+/// Moves the views of a [`ViewGroup`] so that they don't overlap each other
+///
+/// The current positions of the views are their preferred positions, so align the views first,
+/// e.g. place every label next to its data point. The views are then placed one by one, in order.
+/// Every view is moved into `container`, and if it overlaps a view that was already placed, it is
+/// moved next to one of these views, to the free position that is the closest to its preferred
+/// position. Earlier views have a higher priority to stay where they are.
+///
+/// Returns `false` if some views couldn't be placed without overlapping others. These views are
+/// left at their preferred positions, moved into the container.
+///
+/// This is a simple greedy solver that doesn't allocate. Its running time grows with the cube of
+/// the number of views, so it's meant for a handful of labels, not for large view groups.
+///
+/// # Example
+///
+/// Place the labels of two nearby data points of a chart:
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{align::place_non_overlapping, prelude::*, view_group::ViewGroup};
+///
+/// let chart_area = Rectangle::new(Point::zero(), Size::new(64, 32));
+/// let label = Rectangle::new(Point::zero(), Size::new(12, 5));
+///
+/// let mut labels = [
+///     label.align_to_point(Point::new(20, 10), horizontal::Center, vertical::BottomToTop),
+///     label.align_to_point(Point::new(24, 12), horizontal::Center, vertical::BottomToTop),
+/// ];
+/// let mut labels = Views::new(&mut labels);
+///
+/// assert!(place_non_overlapping(&mut labels, &chart_area));
+///
+/// assert_eq!(Point::new(15, 5), labels.bounds_of(0).top_left);
+/// assert_eq!(Point::new(19, 10), labels.bounds_of(1).top_left);
+/// ```
+#[inline]
+pub fn place_non_overlapping(view_group: &mut impl ViewGroup, container: &impl Dimensions) -> bool {
+    let container = container.bounding_box();
+    let mut all_placed = true;
+
+    for idx in 0..view_group.len() {
+        let preferred = view_group.bounds_of(idx);
+        let size = preferred.size;
+
+        let fits = |top_left: Point| {
+            let bounds = Rectangle::new(top_left, size);
+            (0..idx).all(|placed| !overlaps(bounds, view_group.bounds_of(placed)))
+        };
+        let distance = |top_left: Point| {
+            let diff = top_left - preferred.top_left;
+            diff.x.unsigned_abs() + diff.y.unsigned_abs()
+        };
+
+        let start = clamped_top_left(preferred, container);
+        let target = if fits(start) {
+            Some(start)
+        } else {
+            // Candidate positions touch one of the already placed views.
+            (0..idx)
+                .flat_map(|placed| {
+                    let other = view_group.bounds_of(placed);
+                    let x = preferred.top_left.x;
+                    let y = preferred.top_left.y;
+                    [
+                        Point::new(other.top_left.x - size.width as i32, y),
+                        Point::new(other.top_left.x + other.size.width as i32, y),
+                        Point::new(x, other.top_left.y - size.height as i32),
+                        Point::new(x, other.top_left.y + other.size.height as i32),
+                    ]
+                })
+                .map(|top_left| clamped_top_left(Rectangle::new(top_left, size), container))
+                .filter(|&top_left| fits(top_left))
+                .min_by_key(|&top_left| distance(top_left))
+        };
+
+        let top_left = target.unwrap_or_else(|| {
+            all_placed = false;
+            start
+        });
+        view_group.translate_child(idx, top_left - preferred.top_left);
+    }
+
+    all_placed
+}
+
+/// Returns `true` if the two rectangles share at least one pixel.
+fn overlaps(a: Rectangle, b: Rectangle) -> bool {
+    !a.intersection(&b).is_zero_sized()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn views_keep_preferred_position_if_possible() {
+        let container = Rectangle::new(Point::zero(), Size::new(20, 20));
+        let mut views = [
+            Rectangle::new(Point::new(2, 2), Size::new(4, 4)),
+            Rectangle::new(Point::new(10, 2), Size::new(4, 4)),
+        ];
+
+        assert!(place_non_overlapping(
+            &mut Views::new(&mut views),
+            &container
+        ));
+        assert_eq!(Point::new(2, 2), views[0].top_left);
+        assert_eq!(Point::new(10, 2), views[1].top_left);
+    }
+
+    #[test]
+    fn overlapping_views_are_moved_within_container() {
+        let container = Rectangle::new(Point::zero(), Size::new(10, 8));
+        let rect = Rectangle::new(Point::new(3, 0), Size::new(4, 4));
+        let mut views = [rect, rect];
+
+        assert!(place_non_overlapping(
+            &mut Views::new(&mut views),
+            &container
+        ));
+        assert_eq!(Point::new(3, 0), views[0].top_left);
+        // Moving left or right is blocked by the container, so it's moved down
+        assert_eq!(Point::new(3, 4), views[1].top_left);
+
+        // There is no room for a third view
+        let mut views = [rect, rect, rect];
+        assert!(!place_non_overlapping(
+            &mut Views::new(&mut views),
+            &container
+        ));
+        assert_eq!(Point::new(3, 0), views[2].top_left);
+    }
+}
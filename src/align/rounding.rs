@@ -0,0 +1,127 @@
+use embedded_graphics::primitives::Rectangle;
+
+/// Rounding direction of centering alignments
+///
+/// An object can only be centered perfectly if the difference of its size and the size of the
+/// reference is even. Otherwise, the object needs to be placed half a pixel off, and the rounding
+/// mode selects which way. `Floor` moves the object towards the left or top, `Ceil` moves it
+/// towards the right or bottom.
+///
+/// [`horizontal::Center`] and [`vertical::Center`] round the same way as `embedded-graphics`
+/// anchor points do, which depends on the parity of the sizes. Use [`horizontal::CenterRounded`]
+/// or [`vertical::CenterRounded`] to select the rounding direction.
+///
+/// [`horizontal::Center`]: crate::align::horizontal::Center
+/// [`vertical::Center`]: crate::align::vertical::Center
+/// [`horizontal::CenterRounded`]: crate::align::horizontal::CenterRounded
+/// [`vertical::CenterRounded`]: crate::align::vertical::CenterRounded
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Round towards the left or top
+    Floor,
+
+    /// Round towards the right or bottom
+    Ceil,
+
+    /// Round to the nearest even offset from the left or top edge of the reference
+    NearestEven,
+}
+
+impl Default for RoundingMode {
+    #[inline]
+    fn default() -> Self {
+        Self::Floor
+    }
+}
+
+impl RoundingMode {
+    /// Returns `value / 2`, rounded according to the rounding mode.
+    pub(crate) fn half(self, value: i32) -> i32 {
+        let floor = value.div_euclid(2);
+        if value.rem_euclid(2) == 0 {
+            return floor;
+        }
+
+        match self {
+            Self::Floor => floor,
+            Self::Ceil => floor + 1,
+            Self::NearestEven if floor % 2 == 0 => floor,
+            Self::NearestEven => floor + 1,
+        }
+    }
+
+    /// Returns the offset that centers an object on the reference, along one axis.
+    pub(crate) fn center(
+        self,
+        object_start: i32,
+        object_size: u32,
+        reference_start: i32,
+        reference_size: u32,
+    ) -> i32 {
+        reference_start - object_start + self.half(reference_size as i32 - object_size as i32)
+    }
+
+    /// Returns the horizontal offset that centers `object` on `reference`.
+    pub(crate) fn center_x(self, object: Rectangle, reference: Rectangle) -> i32 {
+        self.center(
+            object.top_left.x,
+            object.size.width,
+            reference.top_left.x,
+            reference.size.width,
+        )
+    }
+
+    /// Returns the vertical offset that centers `object` on `reference`.
+    pub(crate) fn center_y(self, object: Rectangle, reference: Rectangle) -> i32 {
+        self.center(
+            object.top_left.y,
+            object.size.height,
+            reference.top_left.y,
+            reference.size.height,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{align::RoundingMode, layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    #[test]
+    fn rounding_direction_does_not_depend_on_parity() {
+        let object = Rectangle::new(Point::zero(), Size::new(3, 4));
+
+        for (width, floor, ceil, even) in [(10, 3, 4, 4), (12, 4, 5, 4), (2, -1, 0, 0)] {
+            let reference = Rectangle::new(Point::zero(), Size::new(width, 1));
+            let x = |mode| {
+                object
+                    .align_to(
+                        &reference,
+                        horizontal::CenterRounded(mode),
+                        vertical::NoAlignment,
+                    )
+                    .top_left
+                    .x
+            };
+
+            assert_eq!(floor, x(RoundingMode::Floor));
+            assert_eq!(ceil, x(RoundingMode::Ceil));
+            assert_eq!(even, x(RoundingMode::NearestEven));
+        }
+    }
+
+    #[test]
+    fn rounded_center_in_linear_layout() {
+        let layout = LinearLayout::horizontal(
+            Chain::new(Rectangle::new(Point::zero(), Size::new(2, 5)))
+                .append(Rectangle::new(Point::zero(), Size::new(2, 2))),
+        )
+        .with_alignment(vertical::CenterRounded(RoundingMode::Ceil))
+        .arrange();
+
+        assert_eq!(Point::new(2, 2), layout.inner().object.top_left);
+    }
+}
@@ -0,0 +1,131 @@
+use embedded_graphics::{prelude::Size, primitives::Rectangle};
+
+use crate::{
+    align::{Alignment, HorizontalAlignment, VerticalAlignment},
+    layout::linear::SecondaryAlignment,
+};
+
+/// Rounds the result of an alignment to a multiple of a number of pixels
+///
+/// `SnapToGrid` can be used everywhere the wrapped alignment can be used. For example,
+/// `SnapToGrid(horizontal::Center, 6)` centers the object, and then moves it to the nearest
+/// position that is a multiple of 6px away from its original position. Objects that start on a
+/// grid line, e.g. at `(0, 0)`, stay on the grid, which keeps them aligned to character cells or
+/// tiles. If the result is exactly between two grid lines, the object is moved to the left or up.
+///
+/// A grid size of 0 or 1 doesn't change the result of the wrapped alignment.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{align::SnapToGrid, prelude::*};
+///
+/// // A 21x8 character display with 6x8 character cells
+/// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+/// let label = Rectangle::new(Point::zero(), Size::new(30, 8)).align_to(
+///     &display_area,
+///     SnapToGrid(horizontal::Center, 6),
+///     SnapToGrid(vertical::Center, 8),
+/// );
+///
+/// assert_eq!(Point::new(48, 24), label.top_left);
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct SnapToGrid<A>(pub A, pub u32);
+
+impl<A> Alignment for SnapToGrid<A>
+where
+    A: Alignment,
+{
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        let translation = self.0.align_with_offset(object, reference, offset);
+
+        if self.1 < 2 {
+            return translation;
+        }
+
+        let grid = self.1 as i64;
+        let cells = (translation as i64 + (grid - 1) / 2).div_euclid(grid);
+
+        (cells * grid) as i32
+    }
+}
+
+impl<A> HorizontalAlignment for SnapToGrid<A> where A: HorizontalAlignment {}
+
+impl<A> VerticalAlignment for SnapToGrid<A> where A: VerticalAlignment {}
+
+impl<A> SecondaryAlignment for SnapToGrid<A>
+where
+    A: SecondaryAlignment,
+{
+    type First = SnapToGrid<A::First>;
+
+    #[inline]
+    fn measure(prev: Size, view_size: Size) -> Size {
+        A::measure(prev, view_size)
+    }
+
+    #[inline]
+    fn aligns_baselines() -> bool {
+        A::aligns_baselines()
+    }
+
+    #[inline]
+    fn stretches() -> bool {
+        A::stretches()
+    }
+
+    #[inline]
+    fn first(&self) -> Self::First {
+        SnapToGrid(self.0.first(), self.1)
+    }
+
+    #[inline]
+    fn following(&self) -> Self {
+        SnapToGrid(self.0.following(), self.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{align::WithOffset, prelude::*};
+    use embedded_graphics::prelude::Point;
+
+    #[test]
+    fn translation_is_rounded_to_nearest_grid_line() {
+        let reference = Rectangle::new(Point::zero(), Size::new(20, 20));
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 4));
+
+        // Right: 16px, Bottom: 16px, offsets move the results between grid lines
+        let snapped = rect.align_to(
+            &reference,
+            SnapToGrid(WithOffset(horizontal::Right, -3), 5),
+            SnapToGrid(WithOffset(vertical::Bottom, -4), 5),
+        );
+        assert_eq!(Point::new(15, 10), snapped.top_left);
+
+        let unchanged = rect.align_to(
+            &reference,
+            SnapToGrid(horizontal::Right, 0),
+            SnapToGrid(vertical::Bottom, 1),
+        );
+        assert_eq!(Point::new(16, 16), unchanged.top_left);
+    }
+
+    #[test]
+    fn negative_translation_is_snapped() {
+        let reference = Rectangle::new(Point::zero(), Size::new(4, 4));
+        let rect = Rectangle::new(Point::new(13, 13), Size::new(2, 2));
+
+        let snapped = rect.align_to(
+            &reference,
+            SnapToGrid(horizontal::Left, 4),
+            SnapToGrid(vertical::Top, 6),
+        );
+        assert_eq!(Point::new(1, 1), snapped.top_left);
+    }
+}
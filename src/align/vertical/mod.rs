@@ -1,7 +1,7 @@
 //! Vertical alignment options
 //!
 //! Vertical alignment types must implement [`VerticalAlignment`].
-use crate::align::{Alignment, VerticalAlignment};
+use crate::align::{Alignment, RoundingMode, VerticalAlignment};
 use embedded_graphics::{geometry::AnchorPoint, primitives::Rectangle};
 
 /// Keep the objects' vertical alignment unchanged
@@ -19,7 +19,7 @@ impl Alignment for NoAlignment {
 /// Center the objects vertically
 ///
 /// *Note:* in certain cases it's not possible to center objects perfectly because of
-///         the integer cordinates used.
+///         the integer cordinates used. Use [`CenterRounded`] to select the rounding direction.
 #[derive(Copy, Clone, Default)]
 pub struct Center;
 impl VerticalAlignment for Center {}
@@ -32,6 +32,46 @@ impl Alignment for Center {
     }
 }
 
+/// Center the objects vertically, rounding in the given direction
+///
+/// If the object can't be centered perfectly, [`RoundingMode`] selects whether the object is
+/// placed closer to the top edge of the reference, or further from it. Unlike [`Center`], the
+/// direction doesn't depend on the parity of the sizes.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{align::RoundingMode, prelude::*};
+///
+/// let reference = Rectangle::new(Point::zero(), Size::new(10, 10));
+/// let object = Rectangle::new(Point::zero(), Size::new(3, 3));
+///
+/// let floor = object.align_to(
+///     &reference,
+///     horizontal::CenterRounded(RoundingMode::Floor),
+///     vertical::CenterRounded(RoundingMode::Floor),
+/// );
+/// let ceil = object.align_to(
+///     &reference,
+///     horizontal::CenterRounded(RoundingMode::Ceil),
+///     vertical::CenterRounded(RoundingMode::Ceil),
+/// );
+///
+/// assert_eq!(Point::new(3, 3), floor.top_left);
+/// assert_eq!(Point::new(4, 4), ceil.top_left);
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct CenterRounded(pub RoundingMode);
+impl VerticalAlignment for CenterRounded {}
+
+impl Alignment for CenterRounded {
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        self.0.center_y(object, reference) + offset
+    }
+}
+
 /// Align the top edge of the object to the top edge of the reference
 #[derive(Copy, Clone, Default)]
 pub struct Top;
@@ -92,6 +132,50 @@ impl Alignment for BottomToTop {
     }
 }
 
+/// Align the baseline of the object to the baseline of the reference
+///
+/// In a horizontal [`LinearLayout`], the views are placed so that their baselines line up. This
+/// makes texts of different fonts look like they are written on the same line. The baseline of a
+/// view is given by [`View::baseline`], views without a baseline are aligned by their bottom edge.
+///
+/// Alignment operations that only know the bounding boxes of the objects, like [`Align::align_to`],
+/// align the bottom edges of the objects, same as [`Bottom`].
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`View::baseline`]: crate::View::baseline
+/// [`Align::align_to`]: crate::align::Align::align_to
+#[derive(Copy, Clone, Default)]
+pub struct Baseline;
+impl VerticalAlignment for Baseline {}
+
+impl Alignment for Baseline {
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        Bottom.align_with_offset(object, reference, offset)
+    }
+}
+
+/// Align the top edge of the object to the top edge of the reference, and make the object as
+/// high as the reference, if possible
+///
+/// In a horizontal [`LinearLayout`], views that implement [`ResizableView`] are resized to the
+/// height of the layout. Other views, and alignment operations that can't resize objects, like
+/// [`Align::align_to`], align the top edges of the objects, same as [`Top`].
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`ResizableView`]: crate::layout::ResizableView
+/// [`Align::align_to`]: crate::align::Align::align_to
+#[derive(Copy, Clone, Default)]
+pub struct Stretch;
+impl VerticalAlignment for Stretch {}
+
+impl Alignment for Stretch {
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        Top.align_with_offset(object, reference, offset)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
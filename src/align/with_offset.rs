@@ -0,0 +1,114 @@
+use embedded_graphics::{prelude::Size, primitives::Rectangle};
+
+use crate::{
+    align::{Alignment, HorizontalAlignment, VerticalAlignment},
+    layout::linear::SecondaryAlignment,
+};
+
+/// Moves the result of an alignment by a fixed number of pixels
+///
+/// `WithOffset` can be used everywhere the wrapped alignment can be used. For example,
+/// `WithOffset(horizontal::Left, 3)` aligns the left edge of the object 3px to the right of the
+/// left edge of the reference. Negative offsets move the object to the left or up.
+///
+/// As the secondary alignment of a [`LinearLayout`], the offset is applied to the first view, and
+/// the other views are aligned to it as usual. This moves every view by the offset, relative to
+/// the position of the layout.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{align::WithOffset, prelude::*};
+///
+/// let reference = Rectangle::new(Point::zero(), Size::new(40, 10));
+/// let rect = Rectangle::new(Point::zero(), Size::new(5, 5)).align_to(
+///     &reference,
+///     WithOffset(horizontal::Left, 3),
+///     WithOffset(vertical::Bottom, -1),
+/// );
+///
+/// assert_eq!(Point::new(3, 4), rect.top_left);
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+#[derive(Copy, Clone, Default)]
+pub struct WithOffset<A>(pub A, pub i32);
+
+impl<A> Alignment for WithOffset<A>
+where
+    A: Alignment,
+{
+    #[inline]
+    fn align_with_offset(&self, object: Rectangle, reference: Rectangle, offset: i32) -> i32 {
+        self.0.align_with_offset(object, reference, offset + self.1)
+    }
+}
+
+impl<A> HorizontalAlignment for WithOffset<A> where A: HorizontalAlignment {}
+
+impl<A> VerticalAlignment for WithOffset<A> where A: VerticalAlignment {}
+
+impl<A> SecondaryAlignment for WithOffset<A>
+where
+    A: SecondaryAlignment,
+{
+    type First = WithOffset<A::First>;
+
+    #[inline]
+    fn measure(prev: Size, view_size: Size) -> Size {
+        A::measure(prev, view_size)
+    }
+
+    #[inline]
+    fn aligns_baselines() -> bool {
+        A::aligns_baselines()
+    }
+
+    #[inline]
+    fn stretches() -> bool {
+        A::stretches()
+    }
+
+    #[inline]
+    fn first(&self) -> Self::First {
+        WithOffset(self.0.first(), self.1)
+    }
+
+    #[inline]
+    fn following(&self) -> Self {
+        WithOffset(self.0.following(), 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::prelude::Point;
+
+    #[test]
+    fn offset_is_added() {
+        let reference = Rectangle::new(Point::zero(), Size::new(10, 10));
+        let rect = Rectangle::new(Point::new(2, 2), Size::new(4, 4)).align_to(
+            &reference,
+            WithOffset(horizontal::Center, 1),
+            WithOffset(WithOffset(vertical::Top, 2), 3),
+        );
+
+        assert_eq!(Point::new(4, 5), rect.top_left);
+    }
+
+    #[test]
+    fn offset_moves_views_in_layout() {
+        let narrow = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let wide = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        let layout = LinearLayout::vertical(Chain::new(narrow).append(wide))
+            .with_alignment(WithOffset(horizontal::Right, 2))
+            .arrange();
+
+        assert_eq!(Point::new(4, 0), layout.inner().parent.object.top_left);
+        assert_eq!(Point::new(2, 2), layout.inner().object.top_left);
+    }
+}
@@ -0,0 +1,279 @@
+//! Compile-time layout
+//!
+//! Layouts and alignments work on views, using traits, so they can't run at compile time. For
+//! fully static screens the positions of the views only depend on their sizes. The `const`
+//! functions in this module compute these positions from sizes, so they can be stored in
+//! constants and cost nothing at runtime.
+//!
+//! The results match the results of [`LinearLayout`] using [`FixedMargin`] spacing, and of the
+//! alignments in [`align`], for views that are not empty.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{prelude::*, primitives::Rectangle};
+//! use embedded_layout::layout::const_layout::{self, Placement};
+//!
+//! const DISPLAY: Rectangle = Rectangle::new(Point::zero(), Size::new(128, 64));
+//!
+//! const MENU: [Rectangle; 3] = const_layout::align_all(
+//!     const_layout::vertical(
+//!         [Size::new(60, 9), Size::new(48, 9), Size::new(36, 9)],
+//!         2,
+//!         Placement::Center,
+//!     ),
+//!     DISPLAY,
+//!     Placement::Center,
+//!     Placement::Center,
+//! );
+//!
+//! assert_eq!(Rectangle::new(Point::new(34, 16), Size::new(60, 9)), MENU[0]);
+//! assert_eq!(Rectangle::new(Point::new(40, 27), Size::new(48, 9)), MENU[1]);
+//! assert_eq!(Rectangle::new(Point::new(46, 38), Size::new(36, 9)), MENU[2]);
+//! ```
+//!
+//! [`LinearLayout`]: crate::layout::linear::LinearLayout
+//! [`FixedMargin`]: crate::layout::linear::FixedMargin
+//! [`align`]: crate::align
+
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+/// Position of an object along an axis, relative to a reference.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Placement {
+    /// Align the left or top edges.
+    Start,
+
+    /// Align the centers.
+    Center,
+
+    /// Align the right or bottom edges.
+    End,
+}
+
+/// Returns the offset that places an object of length `object` in a reference of length
+/// `reference`.
+const fn offset(object: u32, reference: u32, placement: Placement) -> i32 {
+    // Matches the anchor points of `embedded-graphics`, which treat empty sides as 1px long.
+    let object = if object == 0 { 0 } else { object as i32 - 1 };
+    let reference = if reference == 0 {
+        0
+    } else {
+        reference as i32 - 1
+    };
+
+    match placement {
+        Placement::Start => 0,
+        Placement::Center => reference / 2 - object / 2,
+        Placement::End => reference - object,
+    }
+}
+
+/// Places a rectangle of the given size in `area`.
+#[inline]
+pub const fn align(
+    size: Size,
+    area: Rectangle,
+    horizontal: Placement,
+    vertical: Placement,
+) -> Rectangle {
+    Rectangle::new(
+        Point::new(
+            area.top_left.x + offset(size.width, area.size.width, horizontal),
+            area.top_left.y + offset(size.height, area.size.height, vertical),
+        ),
+        size,
+    )
+}
+
+/// Returns the smallest rectangle that contains every rectangle of `rects`.
+///
+/// Returns a zero sized rectangle at `(0, 0)` if `rects` is empty.
+#[inline]
+pub const fn bounds<const N: usize>(rects: &[Rectangle; N]) -> Rectangle {
+    if N == 0 {
+        return Rectangle::zero();
+    }
+
+    let (mut left, mut top) = (i32::MAX, i32::MAX);
+    let (mut right, mut bottom) = (i32::MIN, i32::MIN);
+
+    let mut i = 0;
+    while i < N {
+        let rect = rects[i];
+        let (rect_right, rect_bottom) = (
+            rect.top_left.x + rect.size.width as i32,
+            rect.top_left.y + rect.size.height as i32,
+        );
+
+        if rect.top_left.x < left {
+            left = rect.top_left.x;
+        }
+        if rect.top_left.y < top {
+            top = rect.top_left.y;
+        }
+        if rect_right > right {
+            right = rect_right;
+        }
+        if rect_bottom > bottom {
+            bottom = rect_bottom;
+        }
+
+        i += 1;
+    }
+
+    Rectangle::new(
+        Point::new(left, top),
+        Size::new((right - left) as u32, (bottom - top) as u32),
+    )
+}
+
+/// Moves a group of rectangles so that their bounds are placed in `area`.
+///
+/// The rectangles keep their positions relative to each other.
+#[inline]
+pub const fn align_all<const N: usize>(
+    mut rects: [Rectangle; N],
+    area: Rectangle,
+    horizontal: Placement,
+    vertical: Placement,
+) -> [Rectangle; N] {
+    let current = bounds(&rects);
+    let target = align(current.size, area, horizontal, vertical);
+    let (dx, dy) = (
+        target.top_left.x - current.top_left.x,
+        target.top_left.y - current.top_left.y,
+    );
+
+    let mut i = 0;
+    while i < N {
+        rects[i].top_left.x += dx;
+        rects[i].top_left.y += dy;
+        i += 1;
+    }
+
+    rects
+}
+
+/// Places rectangles of the given sizes below each other, starting at `(0, 0)`.
+///
+/// `spacing` is the number of pixels between two rectangles. The rectangles are aligned
+/// horizontally to the widest one.
+#[inline]
+pub const fn vertical<const N: usize>(
+    sizes: [Size; N],
+    spacing: u32,
+    alignment: Placement,
+) -> [Rectangle; N] {
+    let mut width = 0;
+    let mut i = 0;
+    while i < N {
+        if sizes[i].width > width {
+            width = sizes[i].width;
+        }
+        i += 1;
+    }
+
+    let mut rects = [Rectangle::zero(); N];
+    let mut y = 0;
+    let mut i = 0;
+    while i < N {
+        rects[i] = Rectangle::new(
+            Point::new(offset(sizes[i].width, width, alignment), y),
+            sizes[i],
+        );
+        y += (sizes[i].height + spacing) as i32;
+        i += 1;
+    }
+
+    rects
+}
+
+/// Places rectangles of the given sizes next to each other, starting at `(0, 0)`.
+///
+/// `spacing` is the number of pixels between two rectangles. The rectangles are aligned
+/// vertically to the tallest one.
+#[inline]
+pub const fn horizontal<const N: usize>(
+    sizes: [Size; N],
+    spacing: u32,
+    alignment: Placement,
+) -> [Rectangle; N] {
+    let mut height = 0;
+    let mut i = 0;
+    while i < N {
+        if sizes[i].height > height {
+            height = sizes[i].height;
+        }
+        i += 1;
+    }
+
+    let mut rects = [Rectangle::zero(); N];
+    let mut x = 0;
+    let mut i = 0;
+    while i < N {
+        rects[i] = Rectangle::new(
+            Point::new(x, offset(sizes[i].height, height, alignment)),
+            sizes[i],
+        );
+        x += (sizes[i].width + spacing) as i32;
+        i += 1;
+    }
+
+    rects
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::{FixedMargin, LinearLayout},
+        prelude::*,
+    };
+
+    #[test]
+    fn results_match_linear_layout() {
+        let sizes = [Size::new(5, 2), Size::new(2, 3), Size::new(4, 4)];
+        let mut rects = sizes.map(|size| Rectangle::new(Point::zero(), size));
+
+        let layout = LinearLayout::vertical(Views::new(&mut rects))
+            .with_alignment(horizontal::Center)
+            .with_spacing(FixedMargin(1))
+            .arrange();
+        let expected = vertical(sizes, 1, Placement::Center);
+        for (idx, rect) in expected.iter().enumerate() {
+            assert_eq!(layout.bounds_of(idx), *rect);
+        }
+
+        let mut rects = sizes.map(|size| Rectangle::new(Point::zero(), size));
+        let layout = LinearLayout::horizontal(Views::new(&mut rects))
+            .with_spacing(FixedMargin(2))
+            .arrange();
+        let expected = horizontal(sizes, 2, Placement::End);
+        for (idx, rect) in expected.iter().enumerate() {
+            assert_eq!(layout.bounds_of(idx), *rect);
+        }
+    }
+
+    #[test]
+    fn group_is_aligned_in_area() {
+        let rects = [
+            Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+            Rectangle::new(Point::new(4, 2), Size::new(1, 3)),
+        ];
+        let area = Rectangle::new(Point::new(10, 10), Size::new(10, 10));
+
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(4, 4)),
+            bounds(&rects)
+        );
+
+        let aligned = align_all(rects, area, Placement::End, Placement::Start);
+        assert_eq!(Point::new(16, 10), aligned[0].top_left);
+        assert_eq!(Point::new(19, 11), aligned[1].top_left);
+        assert_eq!(Rectangle::zero(), bounds(&[]));
+    }
+}
@@ -0,0 +1,160 @@
+use embedded_graphics::prelude::Size;
+
+use crate::View;
+
+/// The space available to a view in a layout.
+///
+/// A `None` limit means the view can be arbitrarily large along that axis.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Constraints {
+    /// The maximum width of the view.
+    pub max_width: Option<u32>,
+
+    /// The maximum height of the view.
+    pub max_height: Option<u32>,
+}
+
+impl Constraints {
+    /// Constraints that don't limit the size of the view.
+    #[inline]
+    pub const fn unbounded() -> Self {
+        Self {
+            max_width: None,
+            max_height: None,
+        }
+    }
+
+    /// Constraints that limit the view to the given size.
+    #[inline]
+    pub const fn max_size(size: Size) -> Self {
+        Self {
+            max_width: Some(size.width),
+            max_height: Some(size.height),
+        }
+    }
+
+    /// Limits the width of the view.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_width(self, max_width: u32) -> Self {
+        Self {
+            max_width: Some(max_width),
+            ..self
+        }
+    }
+
+    /// Limits the height of the view.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_height(self, max_height: u32) -> Self {
+        Self {
+            max_height: Some(max_height),
+            ..self
+        }
+    }
+
+    /// Returns `true` if neither the width nor the height is limited.
+    #[inline]
+    pub const fn is_unbounded(&self) -> bool {
+        self.max_width.is_none() && self.max_height.is_none()
+    }
+
+    /// Clamps `size` to the constraints.
+    #[inline]
+    pub fn constrain(&self, size: Size) -> Size {
+        Size::new(
+            self.max_width.map_or(size.width, |max| size.width.min(max)),
+            self.max_height
+                .map_or(size.height, |max| size.height.min(max)),
+        )
+    }
+}
+
+impl Default for Constraints {
+    #[inline]
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+/// A [`View`] whose size depends on the space available to it.
+///
+/// Views like wrapped text need to know how wide they can be before they can tell how high they
+/// are. Layouts that have [`Constraints`] measure these views before arranging them, so that the
+/// views can adapt, e.g. re-flow their text, to the available space.
+///
+/// To let layouts find the implementation, also override [`View::as_measurable_mut`] to return
+/// `Some(self)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{
+///     layout::{linear::LinearLayout, Constraints, MeasurableView},
+///     prelude::*,
+/// };
+///
+/// /// A row of `len` 1px wide characters that wraps to the available width.
+/// struct Paragraph {
+///     bounds: Rectangle,
+///     len: u32,
+/// }
+///
+/// impl View for Paragraph {
+///     fn translate_impl(&mut self, by: Point) {
+///         self.bounds.top_left += by;
+///     }
+///
+///     fn bounds(&self) -> Rectangle {
+///         self.bounds
+///     }
+///
+///     fn as_measurable_mut(&mut self) -> Option<&mut dyn MeasurableView> {
+///         Some(self)
+///     }
+/// }
+///
+/// impl MeasurableView for Paragraph {
+///     fn measure(&mut self, constraints: Constraints) -> Size {
+///         let width = constraints.max_width.unwrap_or(self.len).min(self.len).max(1);
+///         let lines = (self.len + width - 1) / width;
+///
+///         self.bounds.size = Size::new(width, lines);
+///         self.bounds.size
+///     }
+/// }
+///
+/// let layout = LinearLayout::vertical(Chain::new(Paragraph {
+///     bounds: Rectangle::new(Point::zero(), Size::new(10, 1)),
+///     len: 10,
+/// }))
+/// .with_constraints(Constraints::unbounded().with_max_width(4))
+/// .arrange();
+///
+/// assert_eq!(Size::new(4, 3), layout.size());
+/// ```
+pub trait MeasurableView: View {
+    /// Adapts the view to the given constraints and returns its new size.
+    fn measure(&mut self, constraints: Constraints) -> Size;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constrain_clamps_limited_axes() {
+        let size = Size::new(10, 20);
+
+        assert_eq!(size, Constraints::unbounded().constrain(size));
+        assert_eq!(
+            Size::new(5, 20),
+            Constraints::unbounded().with_max_width(5).constrain(size)
+        );
+        assert_eq!(
+            Size::new(10, 15),
+            Constraints::max_size(Size::new(30, 15)).constrain(size)
+        );
+    }
+}
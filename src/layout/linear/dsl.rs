@@ -0,0 +1,181 @@
+//! Declarative description of nested linear layouts.
+
+/// Builds and arranges nested [`LinearLayout`]s from a declarative description.
+///
+/// A layout is described by its orientation (`horizontal` or `vertical`), optional settings in
+/// parentheses, and a list of children in square brackets. A child is either a view, or a nested
+/// layout using the same syntax. The children of a layout are collected into an object chain.
+///
+/// The supported settings are:
+///  - `alignment`: the [secondary alignment], passed to `with_alignment`
+///  - `spacing`: the [element spacing], passed to `with_spacing`
+///
+/// Every layout is arranged, starting with the innermost ones, so the macro returns an arranged
+/// `LinearLayout`, positioned at `(0, 0)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::Rectangle,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout, layout::linear::FixedMargin, prelude::*};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let icon = Rectangle::new(Point::zero(), Size::new(9, 9));
+///
+/// let screen = layout! {
+///     vertical(alignment = horizontal::Center, spacing = FixedMargin(2)) [
+///         Text::new("Settings", Point::zero(), text_style),
+///         horizontal(spacing = FixedMargin(3)) [
+///             icon,
+///             Text::new("Wi-Fi", Point::zero(), text_style),
+///         ],
+///         horizontal(spacing = FixedMargin(3)) [
+///             icon,
+///             Text::new("Bluetooth", Point::zero(), text_style),
+///         ],
+///     ]
+/// };
+///
+/// // The second row is the widest, the other views are centered above it.
+/// assert_eq!(Size::new(66, 31), screen.size());
+/// ```
+///
+/// This is equivalent to the following code:
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::Rectangle,
+/// #     text::Text,
+/// # };
+/// # use embedded_layout::{layout::linear::{FixedMargin, LinearLayout}, prelude::*};
+/// # let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// # let icon = Rectangle::new(Point::zero(), Size::new(9, 9));
+/// let screen = LinearLayout::vertical(
+///     Chain::new(Text::new("Settings", Point::zero(), text_style))
+///         .append(
+///             LinearLayout::horizontal(
+///                 Chain::new(icon).append(Text::new("Wi-Fi", Point::zero(), text_style)),
+///             )
+///             .with_spacing(FixedMargin(3))
+///             .arrange(),
+///         )
+///         .append(
+///             LinearLayout::horizontal(
+///                 Chain::new(icon).append(Text::new("Bluetooth", Point::zero(), text_style)),
+///             )
+///             .with_spacing(FixedMargin(3))
+///             .arrange(),
+///         ),
+/// )
+/// .with_alignment(horizontal::Center)
+/// .with_spacing(FixedMargin(2))
+/// .arrange();
+/// # assert_eq!(Size::new(66, 31), screen.size());
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [secondary alignment]: crate::layout::linear::SecondaryAlignment
+/// [element spacing]: crate::layout::linear::ElementSpacing
+#[macro_export]
+macro_rules! layout {
+    (horizontal $( ( $($option:tt)* ) )? [ $($children:tt)* ]) => {
+        $crate::layout!(@options
+            $crate::layout::linear::LinearLayout::horizontal(
+                $crate::layout!(@children [] $($children)*)
+            );
+            $($($option)*)?
+        )
+        .arrange()
+    };
+
+    (vertical $( ( $($option:tt)* ) )? [ $($children:tt)* ]) => {
+        $crate::layout!(@options
+            $crate::layout::linear::LinearLayout::vertical(
+                $crate::layout!(@children [] $($children)*)
+            );
+            $($($option)*)?
+        )
+        .arrange()
+    };
+
+    (@options $layout:expr;) => {
+        $layout
+    };
+
+    (@options $layout:expr; alignment = $alignment:expr $(, $($rest:tt)*)?) => {
+        $crate::layout!(@options $layout.with_alignment($alignment); $($($rest)*)?)
+    };
+
+    (@options $layout:expr; spacing = $spacing:expr $(, $($rest:tt)*)?) => {
+        $crate::layout!(@options $layout.with_spacing($spacing); $($($rest)*)?)
+    };
+
+    (@children [$($done:expr),+]) => {
+        $crate::chain_views![$($done),+]
+    };
+
+    (@children [$($done:expr),*]
+        horizontal $( ( $($option:tt)* ) )? [ $($children:tt)* ] $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@children
+            [$($done,)* $crate::layout!(horizontal $( ( $($option)* ) )? [ $($children)* ])]
+            $($($rest)*)?
+        )
+    };
+
+    (@children [$($done:expr),*]
+        vertical $( ( $($option:tt)* ) )? [ $($children:tt)* ] $(, $($rest:tt)*)?
+    ) => {
+        $crate::layout!(@children
+            [$($done,)* $crate::layout!(vertical $( ( $($option)* ) )? [ $($children)* ])]
+            $($($rest)*)?
+        )
+    };
+
+    (@children [$($done:expr),*] $view:expr $(, $($rest:tt)*)?) => {
+        $crate::layout!(@children [$($done,)* $view] $($($rest)*)?)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{layout::linear::FixedMargin, prelude::*};
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    #[test]
+    fn nested_layouts_are_arranged() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let layout = layout! {
+            horizontal(alignment = vertical::Top) [
+                rect,
+                vertical(spacing = FixedMargin(1)) [rect, rect],
+                rect
+            ]
+        };
+
+        let views = layout.inner();
+        assert_eq!(Point::new(0, 0), views.parent.parent.object.top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(2, 0), Size::new(2, 5)),
+            views.parent.object.bounds()
+        );
+        assert_eq!(
+            Point::new(2, 3),
+            views.parent.object.inner().object.top_left
+        );
+        assert_eq!(Point::new(4, 0), views.object.top_left);
+    }
+}
@@ -0,0 +1,591 @@
+use crate::{
+    align::{horizontal, vertical},
+    layout::linear::{
+        spacing::{ElementSpacing, ElementSpacingDyn},
+        Horizontal, LinearLayout, Orientation, SecondaryAlignmentDyn, Vertical,
+    },
+    view_group::{DrawableView, DrawableViewGroup, EmptyViewGroup, ViewGroup},
+    View,
+};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+/// The direction in which a [`LinearLayoutDyn`] places its views
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Place views left to right
+    Horizontal,
+    /// Place views top to bottom
+    Vertical,
+}
+
+impl Direction {
+    /// Returns the other direction.
+    #[inline]
+    #[must_use]
+    pub const fn flipped(self) -> Self {
+        match self {
+            Direction::Horizontal => Direction::Vertical,
+            Direction::Vertical => Direction::Horizontal,
+        }
+    }
+
+    /// Returns the default secondary alignment of layouts in this direction.
+    const fn default_alignment(self) -> SecondaryAlignmentDyn {
+        match self {
+            Direction::Horizontal => SecondaryAlignmentDyn::End,
+            Direction::Vertical => SecondaryAlignmentDyn::Start,
+        }
+    }
+}
+
+/// `LinearLayoutDyn`
+///
+/// A [`LinearLayout`] that is configured at runtime. The secondary alignment and the element
+/// spacing are stored as values instead of type parameters, so changing them doesn't change the
+/// type of the layout. This makes it possible to store a configured layout in a struct, at the
+/// cost of selecting the alignment and spacing implementations when the views are arranged.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_layout::prelude::*;
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::layout::linear::{
+///     spacing::ElementSpacingDyn, LinearLayoutDyn, SecondaryAlignmentDyn,
+/// };
+///
+/// type Label<'a> = Text<'a, MonoTextStyle<'a, BinaryColor>>;
+///
+/// struct Menu<'a> {
+///     layout: LinearLayoutDyn<chain! { Label<'a>, Label<'a> }>,
+/// }
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let mut menu = Menu {
+///     layout: LinearLayoutDyn::vertical(
+///         Chain::new(Text::new("Start", Point::zero(), text_style))
+///             .append(Text::new("Settings", Point::zero(), text_style)),
+///     )
+///     .with_spacing(ElementSpacingDyn::FixedMargin(2)),
+/// };
+///
+/// menu.layout.set_alignment(SecondaryAlignmentDyn::Center);
+/// menu.layout.arrange_in_place();
+///
+/// assert_eq!(Point::new(9, 0), menu.layout.inner().parent.object.bounds().top_left);
+/// ```
+pub struct LinearLayoutDyn<VG> {
+    position: Point,
+    direction: Direction,
+    alignment: SecondaryAlignmentDyn,
+    spacing: ElementSpacingDyn,
+    right_to_left: bool,
+    views: VG,
+}
+
+impl<VG> LinearLayoutDyn<VG>
+where
+    VG: ViewGroup,
+{
+    /// Create a new [`LinearLayoutDyn`] that places views in the given direction
+    ///
+    /// The default secondary alignment is the same as for [`LinearLayout::horizontal`] and
+    /// [`LinearLayout::vertical`]: [`SecondaryAlignmentDyn::End`] for horizontal layouts and
+    /// [`SecondaryAlignmentDyn::Start`] for vertical layouts.
+    #[inline]
+    #[must_use]
+    pub fn new(direction: Direction, views: VG) -> Self {
+        Self {
+            position: Point::zero(),
+            direction,
+            alignment: direction.default_alignment(),
+            spacing: ElementSpacingDyn::Tight,
+            right_to_left: false,
+            views,
+        }
+    }
+
+    /// Create a new [`LinearLayoutDyn`] that places views left to right
+    #[inline]
+    #[must_use]
+    pub fn horizontal(views: VG) -> Self {
+        Self::new(Direction::Horizontal, views)
+    }
+
+    /// Create a new [`LinearLayoutDyn`] that places views top to bottom
+    #[inline]
+    #[must_use]
+    pub fn vertical(views: VG) -> Self {
+        Self::new(Direction::Vertical, views)
+    }
+
+    /// Change the direction in which the views are placed.
+    ///
+    /// The secondary alignment is kept, so a layout that centers its views keeps centering them
+    /// after the direction is changed.
+    #[inline]
+    #[must_use]
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.set_direction(direction);
+        self
+    }
+
+    /// Change the secondary alignment for this [`LinearLayoutDyn`] object.
+    #[inline]
+    #[must_use]
+    pub fn with_alignment(mut self, alignment: SecondaryAlignmentDyn) -> Self {
+        self.set_alignment(alignment);
+        self
+    }
+
+    /// Change the element spacing
+    ///
+    /// For available values and their properties, see [spacing]
+    ///
+    /// [spacing]: crate::layout::linear::spacing
+    #[inline]
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: ElementSpacingDyn) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    /// Mirror the arrangement horizontally, for right-to-left user interfaces.
+    ///
+    /// See [`LinearLayout::with_right_to_left`].
+    #[inline]
+    #[must_use]
+    pub fn with_right_to_left(mut self, right_to_left: bool) -> Self {
+        self.set_right_to_left(right_to_left);
+        self
+    }
+
+    /// Change the direction in which the views are placed, in place.
+    ///
+    /// The views are not moved until they are arranged again.
+    ///
+    /// # Example
+    ///
+    /// Switch between portrait and landscape arrangements of the same views.
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::{Direction, LinearLayoutDyn};
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+    /// let mut layout = LinearLayoutDyn::new(Direction::Vertical, Chain::new(rect).append(rect));
+    ///
+    /// layout.arrange_in_place();
+    /// assert_eq!(Size::new(10, 10), layout.size());
+    ///
+    /// let landscape = layout.direction().flipped();
+    /// layout.set_direction(landscape);
+    /// layout.arrange_in_place();
+    /// assert_eq!(Size::new(20, 5), layout.size());
+    /// ```
+    #[inline]
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    /// Change the secondary alignment in place.
+    ///
+    /// The views are not moved until they are arranged again.
+    #[inline]
+    pub fn set_alignment(&mut self, alignment: SecondaryAlignmentDyn) {
+        self.alignment = alignment;
+    }
+
+    /// Change the element spacing in place.
+    ///
+    /// The views are not moved until they are arranged again.
+    #[inline]
+    pub fn set_spacing(&mut self, spacing: ElementSpacingDyn) {
+        self.spacing = spacing;
+    }
+
+    /// Enable or disable mirroring the arrangement, in place.
+    ///
+    /// The views are not moved until they are arranged again.
+    #[inline]
+    pub fn set_right_to_left(&mut self, right_to_left: bool) {
+        self.right_to_left = right_to_left;
+    }
+
+    /// Returns the direction in which the views are placed.
+    #[inline]
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the secondary alignment of the layout.
+    #[inline]
+    pub fn alignment(&self) -> SecondaryAlignmentDyn {
+        self.alignment
+    }
+
+    /// Returns the element spacing of the layout.
+    #[inline]
+    pub fn spacing(&self) -> ElementSpacingDyn {
+        self.spacing
+    }
+
+    /// Returns `true` if the arrangement is mirrored for right-to-left user interfaces.
+    #[inline]
+    pub fn is_right_to_left(&self) -> bool {
+        self.right_to_left
+    }
+
+    /// Returns a reference to the contained views.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.views
+    }
+
+    /// Returns a mutable reference to the contained views.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.views
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> VG {
+        self.views
+    }
+
+    /// Arranges the views according to the layout properties and returns the arranged layout.
+    ///
+    /// Use [`into_inner`] to get the arranged views.
+    ///
+    /// [`into_inner`]: LinearLayoutDyn::into_inner
+    #[inline]
+    #[must_use]
+    pub fn arrange(self) -> Self {
+        self.arrange_with(|_, _| {})
+    }
+
+    /// Arrange the views and report the final bounds of each view.
+    ///
+    /// See [`LinearLayout::arrange_with`].
+    #[inline]
+    #[must_use]
+    pub fn arrange_with(mut self, mut f: impl FnMut(usize, Rectangle)) -> Self {
+        self.arrange_in_place_with(&mut f);
+        self
+    }
+
+    /// Arrange the views in place, starting at the layout's position.
+    ///
+    /// Unlike [`arrange`], this method doesn't take ownership of the layout, so it can be used to
+    /// arrange the views again after the layout has been reconfigured.
+    ///
+    /// [`arrange`]: LinearLayoutDyn::arrange
+    #[inline]
+    pub fn arrange_in_place(&mut self) {
+        self.arrange_in_place_with(&mut |_, _| {});
+    }
+
+    fn arrange_in_place_with(&mut self, f: &mut impl FnMut(usize, Rectangle)) {
+        let spacing = self.spacing;
+
+        match self.direction {
+            Direction::Horizontal => match self.alignment {
+                SecondaryAlignmentDyn::Start => self.arrange_as(
+                    Horizontal {
+                        secondary: vertical::Top,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::Center => self.arrange_as(
+                    Horizontal {
+                        secondary: vertical::Center,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::End => self.arrange_as(
+                    Horizontal {
+                        secondary: vertical::Bottom,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::Stretch => self.arrange_as(
+                    Horizontal {
+                        secondary: vertical::Stretch,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::StartToEnd => self.arrange_as(
+                    Horizontal {
+                        secondary: vertical::TopToBottom,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::EndToStart => self.arrange_as(
+                    Horizontal {
+                        secondary: vertical::BottomToTop,
+                        spacing,
+                    },
+                    f,
+                ),
+            },
+            Direction::Vertical => match self.alignment {
+                SecondaryAlignmentDyn::Start => self.arrange_as(
+                    Vertical {
+                        secondary: horizontal::Left,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::Center => self.arrange_as(
+                    Vertical {
+                        secondary: horizontal::Center,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::End => self.arrange_as(
+                    Vertical {
+                        secondary: horizontal::Right,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::Stretch => self.arrange_as(
+                    Vertical {
+                        secondary: horizontal::Stretch,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::StartToEnd => self.arrange_as(
+                    Vertical {
+                        secondary: horizontal::LeftToRight,
+                        spacing,
+                    },
+                    f,
+                ),
+                SecondaryAlignmentDyn::EndToStart => self.arrange_as(
+                    Vertical {
+                        secondary: horizontal::RightToLeft,
+                        spacing,
+                    },
+                    f,
+                ),
+            },
+        }
+    }
+
+    /// Arranges the views using the static layout implementation for the given orientation.
+    fn arrange_as(&mut self, direction: impl Orientation, f: &mut impl FnMut(usize, Rectangle)) {
+        LinearLayout {
+            position: self.position,
+            right_to_left: self.right_to_left,
+            ..LinearLayout::new(direction, EmptyViewGroup)
+        }
+        .arrange_in_place_with(&mut self.views, f);
+    }
+}
+
+impl<VG> Clone for LinearLayoutDyn<VG>
+where
+    VG: ViewGroup + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            position: self.position,
+            direction: self.direction,
+            alignment: self.alignment,
+            spacing: self.spacing,
+            right_to_left: self.right_to_left,
+            views: self.views.clone(),
+        }
+    }
+}
+
+impl<VG> View for LinearLayoutDyn<VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        View::translate_impl(&mut self.views, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let size = View::bounds(&self.views).size;
+
+        let size = match self.direction {
+            Direction::Horizontal => Size::new(self.spacing.primary_size(size.width), size.height),
+            Direction::Vertical => Size::new(size.width, self.spacing.primary_size(size.height)),
+        };
+
+        Rectangle::new(self.position, size)
+    }
+}
+
+impl<VG> ViewGroup for LinearLayoutDyn<VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.views.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.views.at_mut(idx)
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views.translate_child(idx, by)
+    }
+}
+
+impl<C, VG> DrawableViewGroup<C> for LinearLayoutDyn<VG>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.views.drawable_at(idx)
+    }
+}
+
+impl<C, VG> Drawable for LinearLayoutDyn<VG>
+where
+    C: PixelColor,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        layout::linear::{
+            spacing::{ElementSpacingDyn, FixedMargin},
+            Direction, LinearLayout, LinearLayoutDyn, SecondaryAlignmentDyn,
+        },
+        prelude::*,
+        view_group::ViewGroup,
+    };
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    fn rects() -> chain! { Rectangle, Rectangle } {
+        Chain::new(Rectangle::new(Point::zero(), Size::new(4, 2)))
+            .append(Rectangle::new(Point::new(5, 5), Size::new(2, 3)))
+    }
+
+    #[test]
+    fn arranges_like_static_layout() {
+        let expected = LinearLayout::horizontal(rects())
+            .with_alignment(vertical::Center)
+            .with_spacing(FixedMargin(3))
+            .arrange();
+
+        let layout = LinearLayoutDyn::horizontal(rects())
+            .with_alignment(SecondaryAlignmentDyn::Center)
+            .with_spacing(ElementSpacingDyn::FixedMargin(3))
+            .arrange();
+
+        assert_eq!(expected.bounds(), layout.bounds());
+        assert_eq!(expected.bounds_of(0), layout.bounds_of(0));
+        assert_eq!(expected.bounds_of(1), layout.bounds_of(1));
+    }
+
+    #[test]
+    fn default_alignment() {
+        let layout = LinearLayoutDyn::horizontal(rects()).arrange();
+        assert_eq!(Point::new(0, 1), layout.bounds_of(0).top_left);
+
+        let layout = LinearLayoutDyn::vertical(rects()).arrange();
+        assert_eq!(Point::new(0, 2), layout.bounds_of(1).top_left);
+    }
+
+    #[test]
+    fn rearrange_after_reconfiguring() {
+        let mut layout = LinearLayoutDyn::vertical(rects()).arrange();
+        assert_eq!(Size::new(4, 5), layout.size());
+
+        layout.set_spacing(ElementSpacingDyn::DistributeFill(10));
+        layout.set_alignment(SecondaryAlignmentDyn::End);
+        layout.arrange_in_place();
+
+        assert_eq!(Size::new(4, 10), layout.size());
+        assert_eq!(Point::new(2, 7), layout.bounds_of(1).top_left);
+    }
+
+    #[test]
+    fn flipping_direction_keeps_alignment() {
+        let mut layout = LinearLayoutDyn::new(Direction::Horizontal, rects())
+            .with_alignment(SecondaryAlignmentDyn::Center)
+            .arrange();
+        assert_eq!(Size::new(6, 3), layout.size());
+
+        layout.set_direction(Direction::Vertical);
+        layout.arrange_in_place();
+
+        assert_eq!(Direction::Vertical, layout.direction());
+        assert_eq!(SecondaryAlignmentDyn::Center, layout.alignment());
+        assert_eq!(Size::new(4, 5), layout.size());
+        assert_eq!(Point::new(1, 2), layout.bounds_of(1).top_left);
+    }
+}
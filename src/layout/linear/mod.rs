@@ -9,6 +9,8 @@
 //!    * pass in your views wrapped in a [`ViewGroup`].
 //!  - Optionally, set [secondary alignment]
 //!  - Optionally, set [element spacing]
+//!  - Optionally, set [constraints] if some views adapt to the available space, and an
+//!    [overflow policy] for views that don't fit it
 //!  - Call [`LinearLayout::arrange`] to finalize view placement
 //!  - Align the layout object to where you want it to be displayed
 //!  - Call `draw` to display the views
@@ -53,43 +55,78 @@
 //! as much space along the secondary alignment as the biggest element, i.e. vertical layouts
 //! will be as wide as the widest view inside them.
 //!
+//! To change the secondary alignment of a single view, wrap it in [`Aligned`].
+//!
 //! # Element spacing
 //!
 //! It's possible to modify how views are placed relative to one another.
 //!  * The default is [`Tight`] which is equivalent to [`FixedMargin(0)`]
 //!  * [`FixedMargin(margin)`]: `margin` px distance between views, where `margin` can be negative to overlap views
+//!  * [`RelativeMargin(percent)`]: `percent`% of the total size of the views between views
 //!  * [`DistributeFill(size)`]: force the primary layout size to `size`, distribute views evenly
+//!  * [`DistributeFillSubpixel(size)`]: like `DistributeFill`, but spreads rounding errors evenly
+//!  * [`SpaceAround(size)`]: like `DistributeFill`, but also leave half-sized gaps at the edges
+//!  * [`SpaceEvenly(size)`]: like `DistributeFill`, but also leave equal gaps at the edges
+//!  * [`DistributeFillWithEdges(size)`]: same as `SpaceEvenly`
+//!
+//! # Runtime configuration
+//!
+//! Changing the alignment or spacing of a [`LinearLayout`] changes its type. If the configuration
+//! is only known at runtime, or the layout needs to be stored in a struct, use [`LinearLayoutDyn`]
+//! which takes a [`SecondaryAlignmentDyn`] and an [`ElementSpacingDyn`] instead. The [`Direction`]
+//! of a [`LinearLayoutDyn`] can also be changed, for example to switch between portrait and
+//! landscape arrangements of the same views.
 //!
 //! [`View`]: crate::View
+//! [`Aligned`]: crate::views::Aligned
 //! [`ViewGroup`]: crate::view_group::ViewGroup
 //! [secondary alignment]: LinearLayout::with_alignment
 //! [element spacing]: LinearLayout::with_spacing
+//! [constraints]: LinearLayout::with_constraints
+//! [overflow policy]: LinearLayout::with_overflow
 //! [`Tight`]: crate::layout::linear::spacing::Tight
 //! [`FixedMargin(0)`]: crate::layout::linear::spacing::FixedMargin
 //! [`FixedMargin(margin)`]: crate::layout::linear::spacing::FixedMargin
+//! [`RelativeMargin(percent)`]: crate::layout::linear::spacing::RelativeMargin
 //! [`DistributeFill(size)`]: crate::layout::linear::spacing::DistributeFill
+//! [`DistributeFillSubpixel(size)`]: crate::layout::linear::spacing::DistributeFillSubpixel
+//! [`SpaceAround(size)`]: crate::layout::linear::spacing::SpaceAround
+//! [`SpaceEvenly(size)`]: crate::layout::linear::spacing::SpaceEvenly
+//! [`ElementSpacingDyn`]: crate::layout::linear::spacing::ElementSpacingDyn
+//! [`DistributeFillWithEdges(size)`]: crate::layout::linear::spacing::DistributeFillWithEdges
 //! [`vertical::Bottom`]: crate::align::vertical::Bottom
 //! [`horizontal::Left`]: crate::align::horizontal::Left
 
 use crate::{
     align::{horizontal, vertical},
     align::{HorizontalAlignment, VerticalAlignment},
-    view_group::{EmptyViewGroup, ViewGroup},
+    layout::{Constraints, MeasurableView},
+    prelude::RectExt,
+    view_group::{
+        envelope_views, is_hidden, DrawableView, DrawableViewGroup, EmptyViewGroup, ViewGroup,
+        ViewGroupHelper,
+    },
     View,
 };
 
+mod dsl;
+mod dynamic;
 mod orientation;
+mod overflow;
 mod secondary_alignment;
 pub mod spacing;
 
+pub use dynamic::{Direction, LinearLayoutDyn};
 use embedded_graphics::{
-    draw_target::DrawTarget,
-    prelude::{PixelColor, Point},
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::AnchorPoint,
+    prelude::{PixelColor, Point, Size},
     primitives::Rectangle,
     Drawable,
 };
 pub use orientation::{Horizontal, Orientation, Vertical};
-pub use secondary_alignment::SecondaryAlignment;
+pub use overflow::Overflow;
+pub use secondary_alignment::{SecondaryAlignment, SecondaryAlignmentDyn};
 pub use spacing::{ElementSpacing, FixedMargin};
 
 use spacing::Tight;
@@ -99,13 +136,21 @@ use spacing::Tight;
 /// [`LinearLayout`] is used to arrange views along the horizontal or vertical axis.
 ///
 /// For more information and examples see the [module level documentation](crate::layout::linear).
-pub struct LinearLayout<LD, VG> {
+pub struct LinearLayout<LD, VG, P = ()> {
     position: Point,
     direction: LD,
+    constraints: Constraints,
+    dirty: Option<DirtyViews>,
+    parent: P,
+    right_to_left: bool,
+    overflow: Overflow,
+    secondary_size: Option<u32>,
+    min_length: u32,
+    equal_sizes: bool,
     views: VG,
 }
 
-impl<LD, VG> LinearLayout<LD, VG> {
+impl<LD, VG, P> LinearLayout<LD, VG, P> {
     /// Returns a reference to the contained views.
     #[inline]
     pub fn inner(&self) -> &VG {
@@ -117,6 +162,342 @@ impl<LD, VG> LinearLayout<LD, VG> {
     pub fn inner_mut(&mut self) -> &mut VG {
         &mut self.views
     }
+
+    /// Sets the space available to the views.
+    ///
+    /// Before the views are arranged, every view that implements [`MeasurableView`] is measured
+    /// with these constraints, so that it can adapt to the available space. Views are not measured
+    /// if the layout has no constraints, which is the default.
+    #[inline]
+    #[must_use]
+    pub fn with_constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Returns the space available to the views.
+    #[inline]
+    pub fn constraints(&self) -> Constraints {
+        self.constraints
+    }
+
+    /// Aligns the layout to `reference` every time it is arranged.
+    ///
+    /// This makes the align-after-arrange step a part of the layout: [`arrange`], [`rearrange`]
+    /// and the other arranging methods place the arranged layout using the given alignments. If
+    /// the size of the layout changes, for example because a text got longer, rearranging it also
+    /// keeps it in its place, e.g. centered on the display.
+    ///
+    /// The alignments are stored in the layout, so their values, e.g. the offset of a
+    /// [`WithOffset`] alignment, are used every time the layout is arranged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+    /// let rect = Rectangle::new(Point::zero(), Size::new(10, 10));
+    ///
+    /// let mut layout = LinearLayout::horizontal(Chain::new(rect).append(rect))
+    ///     .with_parent_alignment(&display_area, horizontal::Center, vertical::Center)
+    ///     .arrange();
+    ///
+    /// assert_eq!(Point::new(54, 27), layout.bounds().top_left);
+    ///
+    /// // The layout stays centered when it grows.
+    /// layout.inner_mut().object.size.width = 30;
+    /// layout.rearrange();
+    ///
+    /// assert_eq!(Point::new(44, 27), layout.bounds().top_left);
+    /// ```
+    ///
+    /// [`arrange`]: LinearLayout::arrange
+    /// [`rearrange`]: LinearLayout::rearrange
+    /// [`WithOffset`]: crate::align::WithOffset
+    #[inline]
+    #[must_use]
+    pub fn with_parent_alignment<H, V>(
+        self,
+        reference: &impl View,
+        horizontal: H,
+        vertical: V,
+    ) -> LinearLayout<LD, VG, ParentAlignment<H, V>>
+    where
+        H: HorizontalAlignment,
+        V: VerticalAlignment,
+    {
+        let reference = reference.bounds();
+
+        self.convert(
+            |direction| direction,
+            |_| ParentAlignment {
+                reference,
+                horizontal,
+                vertical,
+            },
+        )
+    }
+
+    /// Mirrors the arrangement horizontally, for right-to-left user interfaces.
+    ///
+    /// The views are arranged as usual, and then mirrored within the layout. A horizontal layout
+    /// places its first view on the right, and horizontal secondary alignments of a vertical
+    /// layout are flipped, e.g. [`horizontal::Left`] aligns the views to the right edge. This
+    /// makes it possible to flip the direction of a localized user interface at runtime, without
+    /// duplicating the layout definitions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+    /// let wide = Rectangle::new(Point::zero(), Size::new(10, 5));
+    ///
+    /// let layout = LinearLayout::horizontal(Chain::new(rect).append(wide))
+    ///     .with_right_to_left(true)
+    ///     .arrange();
+    ///
+    /// assert_eq!(Point::new(10, 0), layout.inner().parent.object.top_left);
+    /// assert_eq!(Point::new(0, 0), layout.inner().object.top_left);
+    /// ```
+    ///
+    /// [`horizontal::Left`]: crate::align::horizontal::Left
+    #[inline]
+    #[must_use]
+    pub fn with_right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
+    /// Returns `true` if the arrangement is mirrored for right-to-left user interfaces.
+    #[inline]
+    pub fn is_right_to_left(&self) -> bool {
+        self.right_to_left
+    }
+
+    /// Sets how views that don't fit the available space are handled.
+    ///
+    /// The available space is set using [`with_constraints`]. See [`Overflow`] for the options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::{
+    ///     linear::{FixedMargin, LinearLayout, Overflow},
+    ///     Constraints,
+    /// };
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(4, 4));
+    ///
+    /// let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+    ///     .with_spacing(FixedMargin(2))
+    ///     .with_constraints(Constraints::unbounded().with_max_width(12))
+    ///     .with_overflow(Overflow::Shrink)
+    ///     .arrange();
+    ///
+    /// assert_eq!(Size::new(12, 4), layout.size());
+    /// ```
+    ///
+    /// [`with_constraints`]: LinearLayout::with_constraints
+    #[inline]
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns how views that don't fit the available space are handled.
+    #[inline]
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Sets the size of the layout along its secondary axis.
+    ///
+    /// By default, a horizontal layout is as high as its highest view, and a vertical layout is
+    /// as wide as its widest view. A fixed secondary size, e.g. the width of the display, gives the
+    /// secondary alignment a meaningful reference: views are aligned within the given size, and
+    /// [`Stretch`] resizes views to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+    ///
+    /// let layout = LinearLayout::vertical(Chain::new(rect).append(rect))
+    ///     .with_alignment(horizontal::Center)
+    ///     .with_secondary_size(40)
+    ///     .arrange();
+    ///
+    /// assert_eq!(Size::new(40, 10), layout.size());
+    /// assert_eq!(Point::new(15, 5), layout.inner().object.top_left);
+    /// ```
+    ///
+    /// [`Stretch`]: crate::align::horizontal::Stretch
+    #[inline]
+    #[must_use]
+    pub fn with_secondary_size(mut self, size: u32) -> Self {
+        self.secondary_size = Some(size);
+        self
+    }
+
+    /// Returns the fixed size of the layout along its secondary axis, if set.
+    #[inline]
+    pub fn secondary_size(&self) -> Option<u32> {
+        self.secondary_size
+    }
+
+    /// Sets the minimum size of the layout along its primary axis.
+    ///
+    /// The size of the layout is padded up to `min_length` if its views are smaller. The views are
+    /// arranged as usual, only the reported size of the layout changes. This keeps aligning the
+    /// whole layout, e.g. centering it on the display, consistent as its content changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(10, 5));
+    ///
+    /// let layout = LinearLayout::horizontal(Chain::new(rect).append(rect))
+    ///     .with_min_length(30)
+    ///     .arrange();
+    ///
+    /// assert_eq!(Size::new(30, 5), layout.size());
+    /// assert_eq!(Point::new(10, 0), layout.inner().object.top_left);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_min_length(mut self, min_length: u32) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Returns the minimum size of the layout along its primary axis.
+    #[inline]
+    pub fn min_length(&self) -> u32 {
+        self.min_length
+    }
+
+    /// Makes every view as large as the largest view along the primary axis.
+    ///
+    /// Before the views are arranged, the layout finds the largest view along its primary axis,
+    /// and resizes every view that implements [`ResizableView`] to that size. This turns views
+    /// with different content, e.g. buttons with labels of different lengths, into evenly sized
+    /// rows or columns. Views that can't be resized keep their size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+    /// use embedded_layout::{
+    ///     layout::linear::LinearLayout, view_group::ViewGroup, views::ProgressBar,
+    /// };
+    ///
+    /// let short = ProgressBar::horizontal(Size::new(10, 3), BinaryColor::On);
+    /// let long = ProgressBar::horizontal(Size::new(25, 3), BinaryColor::On);
+    ///
+    /// let layout = LinearLayout::horizontal(Chain::new(short).append(long).append(short))
+    ///     .with_equal_sizes(true)
+    ///     .arrange();
+    ///
+    /// assert_eq!(Size::new(25, 3), layout.bounds_of(0).size);
+    /// assert_eq!(Size::new(75, 3), layout.size());
+    /// ```
+    ///
+    /// [`ResizableView`]: crate::layout::ResizableView
+    #[inline]
+    #[must_use]
+    pub fn with_equal_sizes(mut self, equal_sizes: bool) -> Self {
+        self.equal_sizes = equal_sizes;
+        self
+    }
+
+    /// Returns `true` if views are resized to the size of the largest view.
+    #[inline]
+    pub fn has_equal_sizes(&self) -> bool {
+        self.equal_sizes
+    }
+
+    /// Moves the views and the properties of the layout into a layout with a different orientation
+    /// or parent alignment type.
+    fn convert<LD2, P2>(
+        self,
+        direction: impl FnOnce(LD) -> LD2,
+        parent: impl FnOnce(P) -> P2,
+    ) -> LinearLayout<LD2, VG, P2> {
+        LinearLayout {
+            position: self.position,
+            direction: direction(self.direction),
+            constraints: self.constraints,
+            dirty: self.dirty,
+            parent: parent(self.parent),
+            right_to_left: self.right_to_left,
+            overflow: self.overflow,
+            secondary_size: self.secondary_size,
+            min_length: self.min_length,
+            equal_sizes: self.equal_sizes,
+            views: self.views,
+        }
+    }
+
+    /// Returns a layout with the same properties that arranges a view group passed to it.
+    ///
+    /// Arranging the views of `self` using this layout avoids borrowing `self` twice. The returned
+    /// layout is not aligned to the parent area.
+    fn detached(&self) -> LinearLayout<LD, EmptyViewGroup>
+    where
+        LD: Copy,
+    {
+        LinearLayout {
+            position: self.position,
+            direction: self.direction,
+            constraints: self.constraints,
+            dirty: None,
+            parent: (),
+            right_to_left: self.right_to_left,
+            overflow: self.overflow,
+            secondary_size: self.secondary_size,
+            min_length: self.min_length,
+            equal_sizes: self.equal_sizes,
+            views: EmptyViewGroup,
+        }
+    }
+}
+
+impl<LD, VG> LinearLayout<LD, VG> {
+    /// Creates a layout with the default properties.
+    fn new(direction: LD, views: VG) -> Self {
+        Self {
+            position: Point::zero(),
+            direction,
+            constraints: Constraints::unbounded(),
+            dirty: None,
+            parent: (),
+            right_to_left: false,
+            overflow: Overflow::Visible,
+            secondary_size: None,
+            min_length: 0,
+            equal_sizes: false,
+            views,
+        }
+    }
 }
 
 impl<VG> LinearLayout<Horizontal<vertical::Bottom, Tight>, VG>
@@ -127,11 +508,7 @@ where
     #[inline]
     #[must_use]
     pub fn horizontal(views: VG) -> Self {
-        Self {
-            position: Point::new(0, 0),
-            direction: Horizontal::default(),
-            views,
-        }
+        Self::new(Horizontal::default(), views)
     }
 }
 
@@ -143,15 +520,11 @@ where
     #[inline]
     #[must_use]
     pub fn vertical(views: VG) -> Self {
-        Self {
-            position: Point::new(0, 0),
-            direction: Vertical::default(),
-            views,
-        }
+        Self::new(Vertical::default(), views)
     }
 }
 
-impl<S, ELS, VG> LinearLayout<Horizontal<S, ELS>, VG>
+impl<S, ELS, VG, P> LinearLayout<Horizontal<S, ELS>, VG, P>
 where
     S: SecondaryAlignment + VerticalAlignment,
     ELS: ElementSpacing,
@@ -164,15 +537,14 @@ where
     /// [`LinearLayout::horizontal`]: crate::layout::linear::LinearLayout::horizontal
     /// [`vertical`]: crate::align::vertical
     #[inline]
-    pub fn with_alignment<Sec>(self, alignment: Sec) -> LinearLayout<Horizontal<Sec, ELS>, VG>
+    pub fn with_alignment<Sec>(self, alignment: Sec) -> LinearLayout<Horizontal<Sec, ELS>, VG, P>
     where
         Sec: SecondaryAlignment + VerticalAlignment,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_secondary_alignment(alignment),
-            views: self.views,
-        }
+        self.convert(
+            |direction| direction.with_secondary_alignment(alignment),
+            |parent| parent,
+        )
     }
 
     /// Change the element spacing
@@ -181,19 +553,15 @@ where
     ///
     /// [spacing]: crate::layout::linear::spacing
     #[inline]
-    pub fn with_spacing<ES>(self, spacing: ES) -> LinearLayout<Horizontal<S, ES>, VG>
+    pub fn with_spacing<ES>(self, spacing: ES) -> LinearLayout<Horizontal<S, ES>, VG, P>
     where
         ES: ElementSpacing,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_spacing(spacing),
-            views: self.views,
-        }
+        self.convert(|direction| direction.with_spacing(spacing), |parent| parent)
     }
 }
 
-impl<S, ELS, VG> LinearLayout<Vertical<S, ELS>, VG>
+impl<S, ELS, VG, P> LinearLayout<Vertical<S, ELS>, VG, P>
 where
     S: SecondaryAlignment + HorizontalAlignment,
     ELS: ElementSpacing,
@@ -206,15 +574,14 @@ where
     /// [`LinearLayout::vertical`]: crate::layout::linear::LinearLayout::vertical
     /// [`horizontal`]: crate::align::horizontal
     #[inline]
-    pub fn with_alignment<Sec>(self, alignment: Sec) -> LinearLayout<Vertical<Sec, ELS>, VG>
+    pub fn with_alignment<Sec>(self, alignment: Sec) -> LinearLayout<Vertical<Sec, ELS>, VG, P>
     where
         Sec: SecondaryAlignment + HorizontalAlignment,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_secondary_alignment(alignment),
-            views: self.views,
-        }
+        self.convert(
+            |direction| direction.with_secondary_alignment(alignment),
+            |parent| parent,
+        )
     }
 
     /// Change the element spacing
@@ -223,36 +590,43 @@ where
     ///
     /// [spacing]: crate::layout::linear::spacing
     #[inline]
-    pub fn with_spacing<ES>(self, spacing: ES) -> LinearLayout<Vertical<S, ES>, VG>
+    pub fn with_spacing<ES>(self, spacing: ES) -> LinearLayout<Vertical<S, ES>, VG, P>
     where
         ES: ElementSpacing,
     {
-        LinearLayout {
-            position: self.position,
-            direction: self.direction.with_spacing(spacing),
-            views: self.views,
-        }
+        self.convert(|direction| direction.with_spacing(spacing), |parent| parent)
     }
 }
 
-impl<LD, VG> Clone for LinearLayout<LD, VG>
+impl<LD, VG, P> Clone for LinearLayout<LD, VG, P>
 where
     LD: Orientation,
     VG: ViewGroup + Clone,
+    P: AlignToParent,
 {
+    #[inline]
     fn clone(&self) -> Self {
         Self {
             position: self.position,
             direction: self.direction,
+            constraints: self.constraints,
+            dirty: self.dirty,
+            parent: self.parent,
+            right_to_left: self.right_to_left,
+            overflow: self.overflow,
+            secondary_size: self.secondary_size,
+            min_length: self.min_length,
+            equal_sizes: self.equal_sizes,
             views: self.views.clone(),
         }
     }
 }
 
-impl<LD, VG> LinearLayout<LD, VG>
+impl<LD, VG, P> LinearLayout<LD, VG, P>
 where
     LD: Orientation,
     VG: ViewGroup,
+    P: AlignToParent,
 {
     /// Consume the layout object and return the wrapped [`ViewGroup`].
     ///
@@ -300,55 +674,959 @@ where
         self.views
     }
 
-    /// Arrange the views according to the layout properties and return the views as a [`ViewGroup`].
-    #[inline]
-    #[must_use]
-    pub fn arrange(mut self) -> Self {
-        // Place first child to the layout's position.
-        self.views
-            .translate_child(0, self.position - self.views.bounds_of(0).top_left);
+    /// Returns the number of views in the layout.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns `true` if the layout contains no views.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Returns the bounding box of the view at position `idx`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+    /// let layout = LinearLayout::vertical(Chain::new(rect).append(rect)).arrange();
+    ///
+    /// assert_eq!(2, layout.len());
+    /// assert_eq!(Point::new(0, 5), layout.bounds_of(1).top_left);
+    /// ```
+    #[inline]
+    pub fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(idx)
+    }
+
+    /// Returns a shared reference to the view at position `idx`, or `None` if `idx` is out of
+    /// bounds.
+    #[inline]
+    pub fn view(&self, idx: usize) -> Option<&dyn View> {
+        self.views.try_at(idx)
+    }
+
+    /// Returns an exclusive reference to the view at position `idx`, or `None` if `idx` is out of
+    /// bounds.
+    ///
+    /// Call [`rearrange`] after changing the size of the view.
+    ///
+    /// [`rearrange`]: LinearLayout::rearrange
+    #[inline]
+    pub fn view_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.try_at_mut(idx)
+    }
+
+    /// Returns the number of views, from the first one, that fit the available space.
+    ///
+    /// If the [overflow policy] is [`Overflow::Truncate`], these are the views that are drawn.
+    /// Returns the number of views if the layout has no [constraints] along its primary axis, or
+    /// if the overflow policy is [`Overflow::Visible`] or [`Overflow::Shrink`], which draw every
+    /// view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::{
+    ///     linear::{LinearLayout, Overflow},
+    ///     Constraints,
+    /// };
+    ///
+    /// let mut items = [Rectangle::new(Point::zero(), Size::new(20, 9)); 10];
+    ///
+    /// let list = LinearLayout::vertical(Views::new(&mut items))
+    ///     .with_constraints(Constraints::unbounded().with_max_height(32))
+    ///     .with_overflow(Overflow::Truncate)
+    ///     .arrange();
+    ///
+    /// assert_eq!(3, list.visible_len());
+    /// ```
+    ///
+    /// [overflow policy]: LinearLayout::with_overflow
+    /// [constraints]: LinearLayout::with_constraints
+    #[inline]
+    pub fn visible_len(&self) -> usize {
+        if let Overflow::Visible | Overflow::Shrink = self.overflow {
+            return self.views.len();
+        }
+
+        let available = match available_primary::<LD>(self.constraints) {
+            Some(available) => available as i32,
+            None => return self.views.len(),
+        };
+        let start = primary_coordinate::<LD>(self.position);
+
+        (0..self.views.len())
+            .take_while(|&i| {
+                let bounds = self.views.bounds_of(i);
+                let (size, _) = LD::destructure_size(bounds.size);
+                let view_start = primary_coordinate::<LD>(bounds.top_left) - start;
+
+                size == 0 || (view_start >= 0 && view_start + size as i32 <= available)
+            })
+            .count()
+    }
+
+    /// Returns the size of the layout along its primary axis that is drawn, if the overflow policy
+    /// hides some views.
+    fn visible_primary_size(&self) -> Option<u32> {
+        let available = available_primary::<LD>(self.constraints)?;
+
+        match self.overflow {
+            Overflow::Clip => Some(available),
+            Overflow::Truncate => {
+                let visible = self.visible_len();
+                if visible == self.views.len() {
+                    return None;
+                }
+
+                // Views are placed in order, so the visible views are in front of the others.
+                let end = (0..visible)
+                    .map(|i| {
+                        let bounds = self.views.bounds_of(i);
+                        let (size, _) = LD::destructure_size(bounds.size);
+                        primary_coordinate::<LD>(bounds.top_left) + size as i32
+                    })
+                    .max()
+                    .unwrap_or(0);
+
+                Some((end - primary_coordinate::<LD>(self.position)).max(0) as u32)
+            }
+            Overflow::Visible | Overflow::Shrink => None,
+        }
+    }
+
+    /// Arrange the views according to the layout properties and return the views as a [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn arrange(self) -> Self {
+        self.arrange_with(|_, _| {})
+    }
+
+    /// Arrange the views and report the final bounds of each view.
+    ///
+    /// `f` is called with the index and the bounds of every view after it has been placed. This
+    /// can be used to store the positions of the views, for example to hit-test touch input,
+    /// without computing the bounds of the views again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{
+    /// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     primitives::Rectangle,
+    /// #     text::Text,
+    /// # };
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+    ///
+    /// let mut buttons = [Rectangle::zero(); 2];
+    /// let _ = LinearLayout::vertical(
+    ///     Chain::new(Text::new("Start", Point::zero(), text_style))
+    ///         .append(Text::new("Stop", Point::zero(), text_style)),
+    /// )
+    /// .arrange_with(|idx, bounds| buttons[idx] = bounds);
+    ///
+    /// let touch = Point::new(3, 12);
+    /// let pressed = buttons.iter().position(|button| button.contains(touch));
+    /// assert_eq!(Some(1), pressed);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn arrange_with(mut self, mut f: impl FnMut(usize, Rectangle)) -> Self {
+        if P::IS_ALIGNED {
+            // The final position depends on the size of the arranged layout.
+            self.rearrange();
+        }
+
+        self.detached()
+            .arrange_in_place_with(&mut self.views, &mut f);
+
+        self
+    }
+
+    /// Arrange the views again, keeping the layout at its current position.
+    ///
+    /// Use this method to update an arranged layout after the size of some of its views changed,
+    /// for example after changing the text of a label. Nested layouts are not rearranged
+    /// automatically: rearrange the innermost changed layout first, then its parents.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::{layout::linear::LinearLayout, view_group::ViewGroup};
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+    ///
+    /// let mut layout = LinearLayout::horizontal(
+    ///     Chain::new(LinearLayout::vertical(Chain::new(rect).append(rect)).arrange())
+    ///         .append(rect),
+    /// )
+    /// .arrange()
+    /// .translate(Point::new(10, 10));
+    ///
+    /// // Make the first view of the nested layout wider.
+    /// let inner = &mut layout.inner_mut().parent.object;
+    /// inner.inner_mut().parent.object.size.width = 8;
+    ///
+    /// inner.rearrange();
+    /// layout.rearrange();
+    ///
+    /// assert_eq!(Point::new(10, 10), layout.bounds().top_left);
+    /// assert_eq!(Point::new(18, 15), layout.bounds_of(1).top_left);
+    /// ```
+    #[inline]
+    pub fn rearrange(&mut self) {
+        self.detached().arrange_in_place(&mut self.views);
+
+        self.align_to_parent();
+    }
+
+    /// Arrange the views again like [`rearrange`], and report the area that changed.
+    ///
+    /// The changed area contains the previous and the new bounds of every view that was moved or
+    /// resized by the layout. Applications that drive slow displays can use it to redraw only the
+    /// affected part of the screen.
+    ///
+    /// Views that changed their size on their own, e.g. a `Text` whose text was changed, are only
+    /// reported if they were moved. Use [`mark_dirty`] and [`arrange_dirty`] to also include
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+    ///
+    /// let mut layout = LinearLayout::vertical(Chain::new(rect).append(rect)).arrange();
+    /// assert_eq!(None, layout.rearrange_tracked().changed);
+    ///
+    /// layout.inner_mut().parent.object.size.height = 3;
+    ///
+    /// let result = layout.rearrange_tracked();
+    /// assert_eq!(Rectangle::new(Point::zero(), Size::new(5, 8)), result.bounds);
+    /// assert_eq!(
+    ///     Some(Rectangle::new(Point::new(0, 3), Size::new(5, 7))),
+    ///     result.changed
+    /// );
+    /// ```
+    ///
+    /// [`rearrange`]: LinearLayout::rearrange
+    /// [`mark_dirty`]: LinearLayout::mark_dirty
+    /// [`arrange_dirty`]: LinearLayout::arrange_dirty
+    #[inline]
+    pub fn rearrange_tracked(&mut self) -> ArrangeResult {
+        let before = self.bounds();
+        let mut changed: Option<Rectangle> = None;
+
+        self.detached().arrange_in_place_tracked(
+            &mut self.views,
+            &mut |_, _| {},
+            Some(&mut |bounds| {
+                changed = Some(changed.map_or(bounds, |changed| changed.enveloping(&bounds)));
+            }),
+        );
+
+        if self.align_to_parent() {
+            // Every view was moved, the intermediate positions were never drawn.
+            changed = Some(before.enveloping(&self.bounds()));
+        }
+
+        ArrangeResult {
+            bounds: self.bounds(),
+            changed,
+        }
+    }
+
+    /// Marks the view at `idx` as changed.
+    ///
+    /// Call this method before changing the view, so that the area it covers now is included in
+    /// the region returned by [`arrange_dirty`].
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `idx` is out of bounds.
+    ///
+    /// [`arrange_dirty`]: LinearLayout::arrange_dirty
+    #[inline]
+    pub fn mark_dirty(&mut self, idx: usize) {
+        let bounds = self.views.bounds_of(idx);
+
+        self.dirty
+            .get_or_insert_with(|| DirtyViews::new(bounds))
+            .mark(idx, bounds);
+    }
+
+    /// Returns `true` if some views were marked as changed since the last call to
+    /// [`arrange_dirty`].
+    ///
+    /// [`arrange_dirty`]: LinearLayout::arrange_dirty
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.is_some()
+    }
+
+    /// Arranges the views again if some of them were marked as changed, and returns the area
+    /// that needs to be redrawn.
+    ///
+    /// The returned rectangle contains the previous bounds of the changed views and the previous
+    /// and new bounds of every view that was moved. If no views were marked using
+    /// [`mark_dirty`], the views are not arranged and `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::layout::linear::LinearLayout;
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+    ///
+    /// let mut layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+    ///     .arrange();
+    /// assert_eq!(None, layout.arrange_dirty());
+    ///
+    /// // Make the second view narrower. The first view doesn't need to be redrawn.
+    /// layout.mark_dirty(1);
+    /// layout.inner_mut().parent.object.size.width = 3;
+    ///
+    /// assert_eq!(
+    ///     Some(Rectangle::new(Point::new(5, 0), Size::new(10, 5))),
+    ///     layout.arrange_dirty()
+    /// );
+    /// ```
+    ///
+    /// [`mark_dirty`]: LinearLayout::mark_dirty
+    #[inline]
+    pub fn arrange_dirty(&mut self) -> Option<Rectangle> {
+        let dirty = self.dirty.take()?;
+        let before = self.bounds();
+
+        // Changed views are redrawn even if they were not moved.
+        let mut redraw = dirty.region;
+        let mut changed = dirty.region;
+
+        self.detached().arrange_in_place_tracked(
+            &mut self.views,
+            &mut |idx, bounds| {
+                if dirty.contains(idx) {
+                    redraw = redraw.enveloping(&bounds);
+                }
+            },
+            Some(&mut |bounds| changed = changed.enveloping(&bounds)),
+        );
+
+        if self.align_to_parent() {
+            // Every view was moved, the intermediate positions were never drawn.
+            return Some(dirty.region.enveloping(&before.enveloping(&self.bounds())));
+        }
+
+        Some(redraw.enveloping(&changed))
+    }
+
+    /// Moves the layout to its place in the parent area, if one was set using
+    /// [`with_parent_alignment`]. Returns `true` if the layout was moved.
+    ///
+    /// [`with_parent_alignment`]: LinearLayout::with_parent_alignment
+    fn align_to_parent(&mut self) -> bool {
+        let by = match self.parent.offset(self.bounds()) {
+            Some(by) if by != Point::zero() => by,
+            _ => return false,
+        };
+
+        self.translate_impl(by);
+        true
+    }
+
+    /// Returns the index of the selected view, if any.
+    ///
+    /// See [`Selectable`] for views that can be selected.
+    ///
+    /// [`Selectable`]: crate::views::Selectable
+    #[inline]
+    pub fn selected(&self) -> Option<usize> {
+        ViewGroupHelper::selected(&self.views)
+    }
+
+    /// Selects the next selectable view, wrapping around after the last one, and returns its
+    /// index.
+    ///
+    /// If no view is selected, the first selectable view is selected. Returns `None` if the layout
+    /// has no selectable views.
+    #[inline]
+    pub fn select_next(&mut self) -> Option<usize> {
+        ViewGroupHelper::select_next(&mut self.views)
+    }
+
+    /// Selects the previous selectable view, wrapping around before the first one, and returns
+    /// its index.
+    ///
+    /// If no view is selected, the last selectable view is selected. Returns `None` if the layout
+    /// has no selectable views.
+    #[inline]
+    pub fn select_prev(&mut self) -> Option<usize> {
+        ViewGroupHelper::select_prev(&mut self.views)
+    }
+
+    /// Arrange a [`ViewGroup`] according to the layout properties, starting at the layout's
+    /// position.
+    ///
+    /// Unlike [`arrange`], this method doesn't take ownership of the views. This is useful if the
+    /// views are stored elsewhere, for example in a struct that is updated and arranged
+    /// repeatedly. The layout's own views are not used, so the layout can be created with an
+    /// [`EmptyViewGroup`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_layout::prelude::*;
+    /// # use embedded_graphics::{
+    /// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     text::Text,
+    /// # };
+    /// use embedded_layout::{
+    ///     layout::linear::{spacing::FixedMargin, Vertical, LinearLayout},
+    ///     view_group::EmptyViewGroup,
+    /// };
+    ///
+    /// type Label<'a> = Text<'a, MonoTextStyle<'a, BinaryColor>>;
+    ///
+    /// struct Screen<'a> {
+    ///     layout: LinearLayout<Vertical<horizontal::Left, FixedMargin>, EmptyViewGroup>,
+    ///     texts: chain! { Label<'a>, Label<'a> },
+    /// }
+    ///
+    /// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+    ///
+    /// let mut screen = Screen {
+    ///     layout: LinearLayout::vertical(EmptyViewGroup).with_spacing(FixedMargin(2)),
+    ///     texts: Chain::new(Text::new("Hello,", Point::zero(), text_style))
+    ///         .append(Text::new("World!", Point::zero(), text_style)),
+    /// };
+    ///
+    /// screen.layout.arrange_in_place(&mut screen.texts);
+    ///
+    /// assert_eq!(Point::new(0, 11), screen.texts.object.bounds().top_left);
+    /// ```
+    ///
+    /// [`arrange`]: LinearLayout::arrange
+    #[inline]
+    pub fn arrange_in_place(&self, view_group: &mut impl ViewGroup) {
+        self.arrange_in_place_with(view_group, &mut |_, _| {});
+    }
+
+    fn arrange_in_place_with(
+        &self,
+        view_group: &mut impl ViewGroup,
+        f: &mut impl FnMut(usize, Rectangle),
+    ) {
+        self.arrange_in_place_tracked(view_group, f, None);
+    }
+
+    /// Arranges the views like [`LinearLayout::arrange_in_place`].
+    ///
+    /// `changed` is called with the previous and the new bounds of every view that is moved or
+    /// resized.
+    fn arrange_in_place_tracked(
+        &self,
+        view_group: &mut impl ViewGroup,
+        f: &mut impl FnMut(usize, Rectangle),
+        mut changed: Option<&mut dyn FnMut(Rectangle)>,
+    ) {
+        if ViewGroup::is_empty(view_group) {
+            return;
+        }
+
+        // Place first child to the layout's position.
+        let first = view_group.bounds_of(0);
+        let by = self.position - first.top_left;
+        view_group.translate_child(0, by);
+
+        if self.right_to_left {
+            // The first view is moved again when the views are mirrored.
+            self.arrange_view_group_tracked(view_group, f, changed, Some(first));
+            return;
+        }
+
+        if let Some(changed) = changed.as_mut().filter(|_| by != Point::zero()) {
+            changed(first);
+            changed(first.translate(by));
+        }
+
+        self.arrange_view_group_tracked(view_group, f, changed, None);
+    }
+
+    /// Arrange a [`ViewGroup`] according to the layout properties.
+    ///
+    /// The first view is not moved along the primary axis, the others are placed relative to it.
+    /// Use [`arrange_in_place`] to also move the views to the layout's position.
+    ///
+    /// [`arrange_in_place`]: LinearLayout::arrange_in_place
+    #[inline]
+    pub fn arrange_view_group(&self, view_group: &mut impl ViewGroup) {
+        self.arrange_view_group_with(view_group, &mut |_, _| {});
+    }
+
+    fn arrange_view_group_with(
+        &self,
+        view_group: &mut impl ViewGroup,
+        f: &mut impl FnMut(usize, Rectangle),
+    ) {
+        self.arrange_view_group_tracked(view_group, f, None, None);
+    }
+
+    /// Arranges the views like [`LinearLayout::arrange_view_group`].
+    ///
+    /// In right-to-left mode, `first_before` replaces the bounds of the first view that are
+    /// reported as changed, if the first view was moved before arranging the views.
+    fn arrange_view_group_tracked(
+        &self,
+        view_group: &mut impl ViewGroup,
+        f: &mut impl FnMut(usize, Rectangle),
+        mut changed: Option<&mut dyn FnMut(Rectangle)>,
+        first_before: Option<Rectangle>,
+    ) {
+        let tracking = changed.is_some();
+        let mut report = |before: Rectangle, after: Rectangle| {
+            if let Some(changed) = changed.as_mut().filter(|_| before != after) {
+                changed(before);
+                changed(after);
+            }
+        };
+
+        let view_count = view_group.len();
+        if view_count == 0 {
+            return;
+        }
+
+        // Let views adapt to the available space before they are measured.
+        if !self.constraints.is_unbounded() {
+            for i in 0..view_count {
+                if let Some(view) = view_group
+                    .try_at_mut(i)
+                    .and_then(|view| view.as_measurable_mut())
+                {
+                    if tracking {
+                        let before = view.bounds();
+                        view.measure(self.constraints);
+                        report(before, view.bounds());
+                    } else {
+                        view.measure(self.constraints);
+                    }
+                }
+            }
+        }
+
+        // Resize views to the primary size of the largest view.
+        if self.equal_sizes {
+            let largest = (0..view_count)
+                .map(|i| LD::destructure_size(view_group.bounds_of(i).size).0)
+                .max()
+                .unwrap_or(0);
+
+            for i in 0..view_count {
+                if let Some(view) = view_group
+                    .try_at_mut(i)
+                    .and_then(|view| view.as_resizable_mut())
+                {
+                    let before = view.bounds();
+                    let (_, secondary_size) = LD::destructure_size(before.size);
+                    view.resize(LD::create_size(largest, secondary_size));
+                    report(before, view.bounds());
+                }
+            }
+        }
+
+        // measure
+        let mut cache = BoundsCache::new();
+        let bounds = cache.measure(view_group, 0);
+        let position = bounds.top_left;
+        let mut size = bounds.size();
+        for i in 1..view_count {
+            let current_el_size = cache.measure(view_group, i).size();
+            size = LD::Secondary::measure(size, current_el_size);
+        }
+        if let Some(secondary_size) = self.secondary_size {
+            let (primary_size, _) = LD::destructure_size(size);
+            size = LD::create_size(primary_size, secondary_size);
+        }
+
+        // Resize views to the secondary size of the layout.
+        let stretch = LD::Secondary::stretches();
+        let (_, secondary_size) = LD::destructure_size(size);
+        for i in 0..view_count {
+            if let Some(view) = view_group
+                .try_at_mut(i)
+                .and_then(|view| view.as_resizable_mut())
+                .filter(|view| stretch || view.fills_layout())
+            {
+                let before = view.bounds();
+                let (primary_size, _) = LD::destructure_size(before.size);
+                view.resize(LD::create_size(primary_size, secondary_size));
+                let after = cache.measure(view_group, i);
+                report(before, after);
+            }
+        }
+
+        // The distance of the common baseline from the top of the layout.
+        let common_baseline = if LD::Secondary::aligns_baselines() {
+            (0..view_count)
+                .filter_map(|i| {
+                    let bounds = cache.get(view_group, i);
+                    view_group
+                        .try_at(i)
+                        .map(|view| baseline_offset(view, bounds))
+                })
+                .max()
+        } else {
+            None
+        };
+
+        // arrange
+        let layout_bounds = Rectangle::new(position, size);
+        let overflow = self.shrink_overflow(view_group, &cache, layout_bounds);
+        // Hidden views collapse: they are not counted and get no spacing around them.
+        let visible_count = visible_count(view_group, &cache);
+        let mut visible = 0;
+        let mut bounds = layout_bounds;
+        for i in 0..view_count {
+            let view_bounds = cache.get(view_group, i);
+            if is_hidden(view_bounds) {
+                let end = if visible == 0 {
+                    layout_bounds.top_left
+                } else {
+                    let (primary_size, _) = LD::destructure_size(bounds.size);
+                    bounds.top_left + LD::create_point(primary_size as i32, 0)
+                };
+                let by = end - view_bounds.top_left;
+                view_group.translate_child(i, by);
+
+                let final_bounds = view_bounds.translate(by);
+                if !self.right_to_left {
+                    report(view_bounds, final_bounds);
+                    f(i, final_bounds);
+                }
+                continue;
+            }
+
+            let mut offset =
+                self.direction
+                    .compute_offset(view_bounds, size, bounds, visible, visible_count);
+            if overflow > 0 && visible > 0 {
+                // Distribute the overflow evenly between the gaps.
+                let gaps = visible_count as i64 - 1;
+                let n = visible as i64;
+                let shrink = overflow * n / gaps - overflow * (n - 1) / gaps;
+                offset += LD::create_point(-(shrink as i32), 0);
+            }
+            visible += 1;
+            view_group.translate_child(i, offset);
+            bounds = Rectangle::new(view_bounds.top_left + offset, view_bounds.size);
+
+            // The next view is placed relative to where this view would be without the override,
+            // so that overriding one view's alignment doesn't affect the others.
+            let secondary_override = view_group.try_at(i).and_then(|view| {
+                view.secondary_alignment_override(layout_bounds)
+                    .or_else(|| {
+                        common_baseline.map(|offset| {
+                            layout_bounds.top_left.y + offset
+                                - baseline_offset(view, bounds)
+                                - bounds.top_left.y
+                        })
+                    })
+            });
+            let final_bounds = if let Some(secondary) = secondary_override {
+                let by = LD::create_point(0, secondary);
+                view_group.translate_child(i, by);
+                bounds.translate(by)
+            } else {
+                bounds
+            };
+            if !self.right_to_left {
+                report(view_bounds, final_bounds);
+                f(i, final_bounds);
+            }
+        }
+
+        if self.right_to_left {
+            // Mirror the views within the horizontal span of the layout.
+            let views_size = (1..view_count)
+                .fold(view_group.bounds_of(0), |envelope, i| {
+                    envelope_views(envelope, view_group.bounds_of(i))
+                })
+                .size;
+            let width = self.layout_size(views_size, view_count).width as i32;
+            let mirror_x = 2 * layout_bounds.top_left.x + width;
+
+            for i in 0..view_count {
+                let bounds = view_group.bounds_of(i);
+                let by = Point::new(
+                    mirror_x - bounds.size.width as i32 - 2 * bounds.top_left.x,
+                    0,
+                );
+                view_group.translate_child(i, by);
+
+                let final_bounds = bounds.translate(by);
+                let before = first_before
+                    .filter(|_| i == 0)
+                    .unwrap_or_else(|| cache.get(view_group, i));
+                report(before, final_bounds);
+                f(i, final_bounds);
+            }
+        }
+    }
+
+    /// Returns the size of the layout that contains `view_count` views of the given total size.
+    ///
+    /// The spacing doesn't add to the size of an empty layout.
+    fn layout_size(&self, views_size: Size, view_count: usize) -> Size {
+        let (primary_size, secondary_size) = if view_count == 0 {
+            LD::destructure_size(views_size)
+        } else {
+            LD::destructure_size(self.direction.layout_size(views_size))
+        };
+
+        LD::create_size(
+            primary_size.max(self.min_length),
+            self.secondary_size.unwrap_or(secondary_size),
+        )
+    }
+
+    /// Returns the number of pixels the views need to be moved closer together to fit the
+    /// available space, if the overflow policy is [`Overflow::Shrink`].
+    fn shrink_overflow(
+        &self,
+        view_group: &impl ViewGroup,
+        cache: &BoundsCache,
+        layout_bounds: Rectangle,
+    ) -> i64 {
+        let view_count = visible_count(view_group, cache);
+        let available = match available_primary::<LD>(self.constraints) {
+            Some(available) if self.overflow == Overflow::Shrink && view_count > 1 => available,
+            _ => return 0,
+        };
+
+        // Place the views without moving them to find out where the last one would end.
+        let mut bounds = layout_bounds;
+        let mut end = primary_coordinate::<LD>(layout_bounds.top_left);
+        let visible_bounds = (0..view_group.len())
+            .map(|i| cache.get(view_group, i))
+            .filter(|bounds| !is_hidden(*bounds));
+        for (i, view_bounds) in visible_bounds.enumerate() {
+            let offset = self.direction.compute_offset(
+                view_bounds,
+                layout_bounds.size,
+                bounds,
+                i,
+                view_count,
+            );
+            bounds = Rectangle::new(view_bounds.top_left + offset, view_bounds.size);
+
+            let (view_size, _) = LD::destructure_size(bounds.size);
+            end = end.max(primary_coordinate::<LD>(bounds.top_left) + view_size as i32);
+        }
+
+        let used = end - primary_coordinate::<LD>(layout_bounds.top_left);
+
+        (used as i64 - available as i64).max(0)
+    }
+}
+
+/// Returns the number of views that are not hidden.
+fn visible_count(view_group: &impl ViewGroup, cache: &BoundsCache) -> usize {
+    (0..view_group.len())
+        .filter(|&i| !is_hidden(cache.get(view_group, i)))
+        .count()
+}
+
+/// Returns the maximum size of the layout along its primary axis, or `None` if it's unlimited.
+fn available_primary<LD: Orientation>(constraints: Constraints) -> Option<u32> {
+    let horizontal = LD::create_size(1, 0).width == 1;
+
+    if horizontal {
+        constraints.max_width
+    } else {
+        constraints.max_height
+    }
+}
+
+/// Returns the coordinate of `point` along the primary axis of the layout.
+fn primary_coordinate<LD: Orientation>(point: Point) -> i32 {
+    let unit = LD::create_point(1, 0);
+
+    point.x * unit.x + point.y * unit.y
+}
+
+/// Returns the distance of the baseline of `view` from its top edge.
+///
+/// Views without a baseline are aligned by their bottom edge.
+fn baseline_offset(view: &dyn View, bounds: Rectangle) -> i32 {
+    let baseline = view
+        .baseline()
+        .unwrap_or_else(|| bounds.anchor_point(AnchorPoint::BottomRight).y);
+
+    baseline - bounds.top_left.y
+}
+
+/// The result of [`LinearLayout::rearrange_tracked`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ArrangeResult {
+    /// The bounds of the layout after arranging.
+    pub bounds: Rectangle,
+
+    /// The area covered by the views before and after they were moved or resized, or `None` if
+    /// no views were moved or resized.
+    pub changed: Option<Rectangle>,
+}
+
+/// The alignment of a [`LinearLayout`] within its parent area.
+///
+/// Layouts that are not aligned to a parent area use `()`. See
+/// [`LinearLayout::with_parent_alignment`].
+pub trait AlignToParent: Copy {
+    /// `true` if arranging the layout moves it to its place in the parent area.
+    const IS_ALIGNED: bool;
+
+    /// Returns the translation that moves a layout with the given bounds to its place in the
+    /// parent area, or `None` if the layout is not aligned to a parent area.
+    fn offset(&self, bounds: Rectangle) -> Option<Point>;
+}
+
+impl AlignToParent for () {
+    const IS_ALIGNED: bool = false;
+
+    #[inline]
+    fn offset(&self, _bounds: Rectangle) -> Option<Point> {
+        None
+    }
+}
+
+/// Aligns a [`LinearLayout`] to a reference area, see [`LinearLayout::with_parent_alignment`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParentAlignment<H, V> {
+    reference: Rectangle,
+    horizontal: H,
+    vertical: V,
+}
+
+impl<H, V> AlignToParent for ParentAlignment<H, V>
+where
+    H: HorizontalAlignment,
+    V: VerticalAlignment,
+{
+    const IS_ALIGNED: bool = true;
+
+    #[inline]
+    fn offset(&self, bounds: Rectangle) -> Option<Point> {
+        Some(Point::new(
+            self.horizontal.align(bounds, self.reference),
+            self.vertical.align(bounds, self.reference),
+        ))
+    }
+}
+
+/// The views of a [`LinearLayout`] that were marked as changed.
+#[derive(Clone, Copy)]
+struct DirtyViews {
+    /// The area covered by the marked views when they were marked.
+    region: Rectangle,
+
+    /// One bit for each of the first 32 views.
+    views: u32,
+
+    /// Set if a view after the first 32 was marked.
+    overflow: bool,
+}
+
+impl DirtyViews {
+    fn new(bounds: Rectangle) -> Self {
+        Self {
+            region: bounds,
+            views: 0,
+            overflow: false,
+        }
+    }
 
-        // We can't use `self` because we borrow parts of it mutably.
-        LinearLayout {
-            position: Point::zero(),
-            direction: self.direction,
-            views: EmptyViewGroup,
+    fn mark(&mut self, idx: usize, bounds: Rectangle) {
+        self.region = self.region.enveloping(&bounds);
+        if idx < 32 {
+            self.views |= 1 << idx;
+        } else {
+            self.overflow = true;
         }
-        .arrange_view_group(&mut self.views);
+    }
 
-        self
+    /// Returns `true` if the view at `idx` may have been marked.
+    fn contains(&self, idx: usize) -> bool {
+        if idx < 32 {
+            self.views & (1 << idx) != 0
+        } else {
+            self.overflow
+        }
     }
+}
 
-    /// Arrange a [`ViewGroup`] according to the layout properties.
-    #[inline]
-    pub fn arrange_view_group(&self, view_group: &mut impl ViewGroup) {
-        let view_count = view_group.len();
+/// The number of view bounds [`LinearLayout::arrange_view_group`] keeps around between measuring
+/// and placing views.
+const BOUNDS_CACHE_SIZE: usize = 8;
 
-        // measure
-        let bounds = view_group.bounds_of(0);
-        let position = bounds.top_left;
-        let mut size = bounds.size();
-        for i in 1..view_count {
-            let current_el_size = view_group.bounds_of(i).size();
-            size = LD::Secondary::measure(size, current_el_size);
+/// Stores the bounds of the first few views of a view group, so that they don't need to be
+/// computed again when placing the views.
+///
+/// Computing the bounds of some views (e.g. `Text` or nested layouts) is expensive.
+struct BoundsCache {
+    bounds: [Rectangle; BOUNDS_CACHE_SIZE],
+}
+
+impl BoundsCache {
+    fn new() -> Self {
+        Self {
+            bounds: [Rectangle::zero(); BOUNDS_CACHE_SIZE],
         }
+    }
 
-        // arrange
-        let mut bounds = Rectangle::new(position, size);
-        for i in 0..view_count {
-            let offset =
-                self.direction
-                    .compute_offset(view_group.bounds_of(i), size, bounds, i, view_count);
-            view_group.translate_child(i, offset);
-            bounds = view_group.bounds_of(i);
+    /// Computes and caches the bounds of the view at `idx`.
+    fn measure(&mut self, view_group: &impl ViewGroup, idx: usize) -> Rectangle {
+        let bounds = view_group.bounds_of(idx);
+        if let Some(cached) = self.bounds.get_mut(idx) {
+            *cached = bounds;
+        }
+        bounds
+    }
+
+    /// Returns the bounds of the view at `idx`, computing them if they were not cached.
+    fn get(&self, view_group: &impl ViewGroup, idx: usize) -> Rectangle {
+        match self.bounds.get(idx) {
+            Some(bounds) => *bounds,
+            None => view_group.bounds_of(idx),
         }
     }
 }
 
-impl<LD, VG> View for LinearLayout<LD, VG>
+impl<LD, VG, P> View for LinearLayout<LD, VG, P>
 where
     LD: Orientation,
     VG: ViewGroup,
+    P: AlignToParent,
 {
     #[inline]
     fn translate_impl(&mut self, by: Point) {
@@ -359,17 +1637,45 @@ where
     #[inline]
     fn bounds(&self) -> Rectangle {
         let bounds = View::bounds(&self.views);
-        let top_left = bounds.top_left;
-        let correction = self.position - top_left;
+        let (primary_size, secondary_size) =
+            LD::destructure_size(self.layout_size(bounds.size, self.views.len()));
+        let primary_size = match self.visible_primary_size() {
+            Some(visible) => primary_size.min(visible),
+            None => primary_size,
+        };
+
+        Rectangle::new(self.position, LD::create_size(primary_size, secondary_size))
+    }
+
+    #[inline]
+    fn as_measurable_mut(&mut self) -> Option<&mut dyn MeasurableView> {
+        Some(self)
+    }
+}
+
+impl<LD, VG, P> MeasurableView for LinearLayout<LD, VG, P>
+where
+    LD: Orientation,
+    VG: ViewGroup,
+    P: AlignToParent,
+{
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        LinearLayout {
+            constraints,
+            ..self.detached()
+        }
+        .arrange_in_place(&mut self.views);
 
-        bounds.translate(correction)
+        self.size()
     }
 }
 
-impl<LD, VG> ViewGroup for LinearLayout<LD, VG>
+impl<LD, VG, P> ViewGroup for LinearLayout<LD, VG, P>
 where
     LD: Orientation,
     VG: ViewGroup,
+    P: AlignToParent,
 {
     #[inline]
     fn len(&self) -> usize {
@@ -386,6 +1692,16 @@ where
         self.views.at_mut(idx)
     }
 
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.try_at_mut(idx)
+    }
+
     #[inline]
     fn bounds_of(&self, idx: usize) -> Rectangle {
         self.views.bounds_of(idx)
@@ -397,11 +1713,25 @@ where
     }
 }
 
-impl<C, LD, VG> Drawable for LinearLayout<LD, VG>
+impl<C, LD, VG, P> DrawableViewGroup<C> for LinearLayout<LD, VG, P>
+where
+    C: PixelColor,
+    LD: Orientation,
+    VG: DrawableViewGroup<C>,
+    P: AlignToParent,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.views.drawable_at(idx)
+    }
+}
+
+impl<C, LD, VG, P> Drawable for LinearLayout<LD, VG, P>
 where
     C: PixelColor,
     LD: Orientation,
     VG: ViewGroup + Drawable<Color = C>,
+    P: AlignToParent,
 {
     type Color = C;
     type Output = ();
@@ -411,7 +1741,14 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        self.views.draw(display)?;
+        match self.visible_primary_size() {
+            Some(_) => {
+                self.views.draw(&mut display.clipped(&self.bounds()))?;
+            }
+            None => {
+                self.views.draw(display)?;
+            }
+        }
         Ok(())
     }
 }
@@ -419,13 +1756,20 @@ where
 #[cfg(test)]
 mod test {
     use crate::{
-        layout::linear::{
-            spacing::{DistributeFill, FixedMargin},
-            LinearLayout,
+        align::WithOffset,
+        layout::{
+            linear::{
+                spacing::{DistributeFill, FixedMargin},
+                LinearLayout, Overflow,
+            },
+            Constraints, MeasurableView, ResizableView,
         },
         object_chain::Chain,
         prelude::*,
+        view_group::EmptyViewGroup,
+        views::{Aligned, Divider},
     };
+    use core::cell::Cell;
     use embedded_graphics::{
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
@@ -814,6 +2158,259 @@ mod test {
         );
     }
 
+    #[test]
+    fn arrange_measures_views_once() {
+        struct CountingView<'a> {
+            bounds: Rectangle,
+            bounds_calls: &'a Cell<usize>,
+        }
+
+        impl View for CountingView<'_> {
+            fn translate_impl(&mut self, by: Point) {
+                self.bounds.top_left += by;
+            }
+
+            fn bounds(&self) -> Rectangle {
+                self.bounds_calls.set(self.bounds_calls.get() + 1);
+                self.bounds
+            }
+        }
+
+        let bounds_calls = Cell::new(0);
+        let mut views = [
+            CountingView {
+                bounds: Rectangle::new(Point::new(3, 2), Size::new(4, 5)),
+                bounds_calls: &bounds_calls,
+            },
+            CountingView {
+                bounds: Rectangle::new(Point::new(-2, 7), Size::new(2, 1)),
+                bounds_calls: &bounds_calls,
+            },
+            CountingView {
+                bounds: Rectangle::new(Point::new(0, 0), Size::new(8, 3)),
+                bounds_calls: &bounds_calls,
+            },
+        ];
+
+        let layout =
+            LinearLayout::vertical(Views::new(&mut views)).with_alignment(horizontal::Center);
+        let views = layout.arrange().into_inner();
+
+        // Placing the first view to the layout's position takes one more call.
+        assert_eq!(4, bounds_calls.get());
+
+        assert_eq!(Point::new(2, 0), views[0].bounds.top_left);
+        assert_eq!(Point::new(3, 5), views[1].bounds.top_left);
+        assert_eq!(Point::new(0, 6), views[2].bounds.top_left);
+    }
+
+    /// A row of `len` 1px wide characters that wraps to the available width.
+    struct Paragraph {
+        bounds: Rectangle,
+        len: u32,
+    }
+
+    impl Paragraph {
+        fn new(len: u32) -> Self {
+            Self {
+                bounds: Rectangle::new(Point::zero(), Size::new(len, 1)),
+                len,
+            }
+        }
+    }
+
+    impl View for Paragraph {
+        fn translate_impl(&mut self, by: Point) {
+            self.bounds.top_left += by;
+        }
+
+        fn bounds(&self) -> Rectangle {
+            self.bounds
+        }
+
+        fn as_measurable_mut(&mut self) -> Option<&mut dyn MeasurableView> {
+            Some(self)
+        }
+    }
+
+    impl MeasurableView for Paragraph {
+        fn measure(&mut self, constraints: Constraints) -> Size {
+            let width = constraints
+                .max_width
+                .unwrap_or(self.len)
+                .min(self.len)
+                .max(1);
+
+            self.bounds.size = Size::new(width, (self.len + width - 1) / width);
+            self.bounds.size
+        }
+    }
+
+    #[test]
+    fn views_are_measured_with_constraints() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let layout = LinearLayout::vertical(
+            Chain::new(Paragraph::new(10))
+                .append(rect)
+                .append(Cached::new(Paragraph::new(5))),
+        )
+        .with_constraints(Constraints::unbounded().with_max_width(4))
+        .arrange();
+
+        assert_eq!(Size::new(4, 3), layout.bounds_of(0).size);
+        assert_eq!(Point::new(0, 3), layout.bounds_of(1).top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(0, 5), Size::new(4, 2)),
+            layout.bounds_of(2)
+        );
+        assert_eq!(Size::new(4, 7), layout.size());
+    }
+
+    #[test]
+    fn nested_layouts_pass_constraints() {
+        let layout = LinearLayout::horizontal(
+            Chain::new(Paragraph::new(3))
+                .append(LinearLayout::vertical(Chain::new(Paragraph::new(6)))),
+        )
+        .with_constraints(Constraints::unbounded().with_max_width(2))
+        .arrange();
+
+        assert_eq!(Size::new(2, 2), layout.bounds_of(0).size);
+        assert_eq!(Size::new(2, 3), layout.bounds_of(1).size);
+        assert_eq!(Point::new(2, 0), layout.bounds_of(1).top_left);
+    }
+
+    #[test]
+    fn measuring_keeps_own_constraints() {
+        let own = Constraints::unbounded().with_max_width(3);
+        let mut inner = LinearLayout::vertical(Chain::new(Paragraph::new(6))).with_constraints(own);
+
+        assert_eq!(
+            Size::new(2, 3),
+            inner.measure(Constraints::unbounded().with_max_width(2))
+        );
+        assert_eq!(own, inner.constraints());
+
+        inner.rearrange();
+        assert_eq!(Size::new(3, 2), inner.size());
+    }
+
+    #[test]
+    fn unconstrained_layout_does_not_measure() {
+        let layout = LinearLayout::vertical(Chain::new(Paragraph::new(10))).arrange();
+
+        assert_eq!(Size::new(10, 1), layout.size());
+    }
+
+    struct Button {
+        bounds: Rectangle,
+    }
+
+    impl Button {
+        fn new(width: u32, height: u32) -> Self {
+            Self {
+                bounds: Rectangle::new(Point::zero(), Size::new(width, height)),
+            }
+        }
+    }
+
+    impl View for Button {
+        fn translate_impl(&mut self, by: Point) {
+            self.bounds.top_left += by;
+        }
+
+        fn bounds(&self) -> Rectangle {
+            self.bounds
+        }
+
+        fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+            Some(self)
+        }
+    }
+
+    impl ResizableView for Button {
+        fn resize(&mut self, size: Size) {
+            self.bounds.size = size;
+        }
+    }
+
+    #[test]
+    fn stretch_resizes_views() {
+        let layout = LinearLayout::vertical(
+            Chain::new(Button::new(3, 2))
+                .append(Rectangle::new(Point::zero(), Size::new(4, 1)))
+                .append(Cached::new(Button::new(5, 2)))
+                .append(Rectangle::new(Point::zero(), Size::new(2, 1))),
+        )
+        .with_alignment(horizontal::Stretch)
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(5, 2)),
+            layout.bounds_of(0)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(0, 2), Size::new(4, 1)),
+            layout.bounds_of(1)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(0, 3), Size::new(5, 2)),
+            layout.bounds_of(2)
+        );
+        assert_eq!(Point::new(0, 5), layout.bounds_of(3).top_left);
+        assert_eq!(Size::new(5, 6), layout.size());
+    }
+
+    #[test]
+    fn stretch_resizes_views_vertically() {
+        let layout = LinearLayout::horizontal(
+            Chain::new(Button::new(3, 2)).append(Rectangle::new(Point::zero(), Size::new(4, 5))),
+        )
+        .with_alignment(vertical::Stretch)
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(3, 5)),
+            layout.bounds_of(0)
+        );
+        assert_eq!(Point::new(3, 0), layout.bounds_of(1).top_left);
+    }
+
+    #[test]
+    fn arrange_empty_view_group() {
+        let mut views: [Rectangle; 0] = [];
+
+        let layout = LinearLayout::vertical(Views::new(&mut views))
+            .with_spacing(DistributeFill(20))
+            .arrange();
+
+        assert!(layout.is_empty());
+        assert_eq!(Size::zero(), layout.size());
+
+        let mut views: [Rectangle; 0] = [];
+        LinearLayout::horizontal(EmptyViewGroup).arrange_in_place(&mut Views::new(&mut views));
+    }
+
+    #[test]
+    fn arrange_with_reports_final_bounds() {
+        let rect = Rectangle::new(Point::new(3, 3), Size::new(2, 2));
+        let wide = Rectangle::new(Point::zero(), Size::new(6, 1));
+
+        let mut reported = [Rectangle::zero(); 3];
+        let layout = LinearLayout::vertical(
+            Chain::new(rect)
+                .append(wide)
+                .append(Aligned::new(rect, horizontal::Right)),
+        )
+        .arrange_with(|idx, bounds| reported[idx] = bounds);
+
+        for (idx, bounds) in reported.iter().enumerate() {
+            assert_eq!(layout.bounds_of(idx), *bounds);
+        }
+        assert_eq!(Point::new(4, 3), reported[2].top_left);
+    }
+
     #[test]
     fn layout_size_independent_of_view_location() {
         let rect = Rectangle::new(Point::zero(), Size::new(10, 20));
@@ -833,4 +2430,302 @@ mod test {
 
         assert_eq!(size1, size2);
     }
+
+    #[test]
+    fn rearrange_keeps_position() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut layout = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .with_alignment(horizontal::Right)
+            .arrange()
+            .translate(Point::new(5, 7));
+
+        layout.inner_mut().parent.object.size = Size::new(4, 3);
+        layout.rearrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(5, 7), Size::new(4, 5)),
+            layout.bounds()
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(7, 10), Size::new(2, 2)),
+            layout.bounds_of(1)
+        );
+    }
+
+    #[test]
+    fn right_to_left_mirrors_views() {
+        let narrow = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let wide = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        let mut reported = [Rectangle::zero(); 2];
+        let mut layout = LinearLayout::horizontal(Chain::new(narrow).append(wide))
+            .with_spacing(FixedMargin(1))
+            .with_right_to_left(true)
+            .translate(Point::new(3, 0))
+            .arrange_with(|idx, bounds| reported[idx] = bounds);
+
+        assert_eq!(
+            Rectangle::new(Point::new(3, 0), Size::new(7, 2)),
+            layout.bounds()
+        );
+        assert_eq!(Point::new(8, 0), layout.inner().parent.object.top_left);
+        assert_eq!(Point::new(3, 0), layout.inner().object.top_left);
+        assert_eq!(layout.inner().object, reported[1]);
+        assert_eq!(None, layout.rearrange_tracked().changed);
+
+        let layout = LinearLayout::vertical(Chain::new(narrow).append(wide))
+            .with_alignment(horizontal::Left)
+            .with_right_to_left(true)
+            .arrange();
+
+        assert_eq!(Point::new(2, 0), layout.inner().parent.object.top_left);
+        assert_eq!(Point::new(0, 2), layout.inner().object.top_left);
+    }
+
+    #[test]
+    fn parent_alignment_is_kept_when_rearranging() {
+        let parent = Rectangle::new(Point::new(2, 2), Size::new(20, 20));
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut layout = LinearLayout::horizontal(Chain::new(rect).append(rect))
+            .with_parent_alignment(&parent, horizontal::Right, vertical::Bottom)
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(18, 20), Size::new(4, 2)),
+            layout.bounds()
+        );
+
+        layout.inner_mut().object.size = Size::new(4, 4);
+        let result = layout.rearrange_tracked();
+
+        assert_eq!(
+            Rectangle::new(Point::new(16, 18), Size::new(6, 4)),
+            result.bounds
+        );
+        assert_eq!(
+            Some(Rectangle::new(Point::new(16, 18), Size::new(8, 6))),
+            result.changed
+        );
+    }
+
+    #[test]
+    fn parent_alignment_keeps_alignment_values() {
+        let parent = Rectangle::new(Point::zero(), Size::new(20, 20));
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut layout = LinearLayout::horizontal(Chain::new(rect).append(rect))
+            .with_parent_alignment(
+                &parent,
+                WithOffset(horizontal::Left, 3),
+                WithOffset(vertical::Top, 5),
+            )
+            .arrange();
+
+        assert_eq!(Point::new(3, 5), layout.bounds().top_left);
+
+        layout.inner_mut().object.size = Size::new(4, 4);
+        layout.rearrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(3, 5), Size::new(6, 4)),
+            layout.bounds()
+        );
+    }
+
+    #[test]
+    fn arrange_dirty_reports_changed_area() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut layout = LinearLayout::vertical(Chain::new(rect).append(rect).append(rect))
+            .arrange()
+            .translate(Point::new(1, 1));
+        assert!(!layout.is_dirty());
+
+        // The last view grows without moving.
+        layout.mark_dirty(2);
+        assert!(layout.is_dirty());
+        layout.inner_mut().object.size = Size::new(3, 3);
+
+        assert_eq!(
+            Some(Rectangle::new(Point::new(1, 5), Size::new(3, 3))),
+            layout.arrange_dirty()
+        );
+        assert!(!layout.is_dirty());
+        assert_eq!(None, layout.arrange_dirty());
+    }
+
+    #[test]
+    fn rearrange_tracked_reports_stretched_views() {
+        let mut layout = LinearLayout::vertical(
+            Chain::new(Button::new(3, 2)).append(Rectangle::new(Point::zero(), Size::new(4, 1))),
+        )
+        .with_alignment(horizontal::Stretch)
+        .arrange();
+        assert_eq!(None, layout.rearrange_tracked().changed);
+
+        // The button is resized to the new width of the layout, but not moved.
+        layout.inner_mut().object.size.width = 6;
+
+        let result = layout.rearrange_tracked();
+        assert_eq!(Size::new(6, 3), result.bounds.size);
+        assert_eq!(
+            Some(Rectangle::new(Point::zero(), Size::new(6, 2))),
+            result.changed
+        );
+    }
+
+    #[test]
+    fn views_are_accessible_through_layout() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let mut layout = LinearLayout::horizontal(Chain::new(rect).append(rect)).arrange();
+
+        assert_eq!(2, layout.len());
+        assert!(!layout.is_empty());
+        assert_eq!(
+            Some(layout.bounds_of(1)),
+            layout.view(1).map(|view| view.bounds())
+        );
+        assert!(layout.view(2).is_none());
+
+        layout.view_mut(0).unwrap().translate_impl(Point::new(0, 1));
+        assert_eq!(Point::new(0, 1), layout.bounds_of(0).top_left);
+        assert!(layout.view_mut(2).is_none());
+    }
+
+    #[test]
+    fn shrink_overflow_moves_views_closer() {
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 2));
+
+        let layout = LinearLayout::vertical(Chain::new(rect).append(rect).append(rect))
+            .with_constraints(Constraints::unbounded().with_max_height(5))
+            .with_overflow(Overflow::Shrink)
+            .arrange();
+
+        assert_eq!(Size::new(3, 5), layout.size());
+        assert_eq!(Point::new(0, 2), layout.bounds_of(1).top_left);
+        assert_eq!(Point::new(0, 3), layout.bounds_of(2).top_left);
+
+        // Views that fit are not moved
+        let layout = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .with_constraints(Constraints::unbounded().with_max_height(5))
+            .with_overflow(Overflow::Shrink)
+            .arrange();
+        assert_eq!(Point::new(0, 2), layout.bounds_of(1).top_left);
+    }
+
+    #[test]
+    fn secondary_size_is_the_alignment_reference() {
+        let small = Rectangle::new(Point::zero(), Size::new(3, 2));
+        let large = Rectangle::new(Point::zero(), Size::new(3, 4));
+
+        let layout = LinearLayout::horizontal(
+            Chain::new(small)
+                .append(large)
+                .append(Divider::vertical(BinaryColor::On)),
+        )
+        .with_secondary_size(20)
+        .arrange()
+        .translate(Point::new(0, 5));
+
+        assert_eq!(
+            Rectangle::new(Point::new(0, 5), Size::new(7, 20)),
+            layout.bounds()
+        );
+        assert_eq!(Point::new(0, 23), layout.bounds_of(0).top_left);
+        assert_eq!(Point::new(3, 21), layout.bounds_of(1).top_left);
+        assert_eq!(Size::new(1, 20), layout.bounds_of(2).size);
+    }
+
+    #[test]
+    fn min_length_pads_the_layout() {
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 2));
+
+        let mut layout = LinearLayout::vertical(Chain::new(rect).append(rect))
+            .with_min_length(10)
+            .arrange();
+        assert_eq!(Size::new(3, 10), layout.size());
+
+        // Larger content is not limited
+        layout.inner_mut().object.size.height = 9;
+        layout.rearrange();
+        assert_eq!(Size::new(3, 11), layout.size());
+    }
+
+    #[test]
+    fn equal_sizes_resize_views_to_the_largest() {
+        let mut layout = LinearLayout::vertical(
+            Chain::new(Button::new(5, 4))
+                .append(Rectangle::new(Point::zero(), Size::new(3, 6)))
+                .append(Button::new(8, 2)),
+        )
+        .with_equal_sizes(true)
+        .arrange();
+
+        assert_eq!(Size::new(5, 6), layout.bounds_of(0).size);
+        assert_eq!(
+            Rectangle::new(Point::new(0, 12), Size::new(8, 6)),
+            layout.bounds_of(2)
+        );
+        assert_eq!(Size::new(8, 18), layout.size());
+
+        // Views that can't be resized keep their size
+        layout.inner_mut().parent.object.size.height = 7;
+        layout.rearrange();
+        assert_eq!(Size::new(3, 7), layout.bounds_of(1).size);
+        assert_eq!(Size::new(8, 21), layout.size());
+    }
+
+    #[test]
+    fn truncated_views_are_not_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 1)).into_styled(style);
+
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+            .with_spacing(FixedMargin(1))
+            .with_constraints(Constraints::unbounded().with_max_width(7))
+            .with_overflow(Overflow::Truncate)
+            .arrange();
+
+        assert_eq!(2, layout.visible_len());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+        display.assert_pattern(&["## ##"]);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout
+            .with_overflow(Overflow::Clip)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["## ## #"]);
+    }
+
+    #[test]
+    fn overflowing_bounds_follow_the_overflow_policy() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let layout = |overflow| {
+            LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+                .with_constraints(Constraints::unbounded().with_max_width(12))
+                .with_overflow(overflow)
+                .arrange()
+        };
+
+        let visible = layout(Overflow::Visible);
+        assert_eq!(Size::new(15, 5), visible.size());
+        assert_eq!(3, visible.visible_len());
+
+        let clipped = layout(Overflow::Clip);
+        assert_eq!(Size::new(12, 5), clipped.size());
+        assert_eq!(2, clipped.visible_len());
+
+        let truncated = layout(Overflow::Truncate);
+        assert_eq!(Size::new(10, 5), truncated.size());
+        assert_eq!(2, truncated.visible_len());
+
+        let shrunk = layout(Overflow::Shrink);
+        assert_eq!(Size::new(12, 5), shrunk.size());
+        assert_eq!(3, shrunk.visible_len());
+    }
 }
@@ -22,6 +22,17 @@ pub trait Orientation: Copy + Clone {
     /// Create a `Size` from primary and secondary size values
     fn create_size(primary: u32, secondary: u32) -> Size;
 
+    /// Create a `Point` from primary and secondary coordinate values
+    fn create_point(primary: i32, secondary: i32) -> Point;
+
+    /// Returns the size of the layout, given the size of the envelope of its views.
+    ///
+    /// The default implementation returns `views_size`.
+    #[inline]
+    fn layout_size(&self, views_size: Size) -> Size {
+        views_size
+    }
+
     /// Computes translation for the next view.
     fn compute_offset(
         &self,
@@ -116,6 +127,18 @@ where
         Size::new(primary, secondary)
     }
 
+    #[inline]
+    fn create_point(primary: i32, secondary: i32) -> Point {
+        Point::new(primary, secondary)
+    }
+
+    #[inline]
+    fn layout_size(&self, views_size: Size) -> Size {
+        let (primary, secondary) = Self::destructure_size(views_size);
+
+        Self::create_size(self.spacing.primary_size(primary), secondary)
+    }
+
     #[inline]
     fn compute_offset(
         &self,
@@ -131,7 +154,7 @@ where
             Point::new(
                 self.spacing
                     .align(horizontal::Left, bounds, previous, n, count, primary_size),
-                Secondary::First::default().align(bounds, previous),
+                self.secondary.first().align(bounds, previous),
             )
         } else {
             Point::new(
@@ -143,7 +166,7 @@ where
                     count,
                     primary_size,
                 ),
-                Secondary::default().align(bounds, previous),
+                self.secondary.following().align(bounds, previous),
             )
         }
     }
@@ -217,6 +240,18 @@ where
         Size::new(secondary, primary)
     }
 
+    #[inline]
+    fn create_point(primary: i32, secondary: i32) -> Point {
+        Point::new(secondary, primary)
+    }
+
+    #[inline]
+    fn layout_size(&self, views_size: Size) -> Size {
+        let (primary, secondary) = Self::destructure_size(views_size);
+
+        Self::create_size(self.spacing.primary_size(primary), secondary)
+    }
+
     #[inline]
     fn compute_offset(
         &self,
@@ -230,13 +265,13 @@ where
 
         if n == 0 {
             Point::new(
-                Secondary::First::default().align(bounds, previous),
+                self.secondary.first().align(bounds, previous),
                 self.spacing
                     .align(vertical::Top, bounds, previous, n, count, primary_size),
             )
         } else {
             Point::new(
-                Secondary::default().align(bounds, previous),
+                self.secondary.following().align(bounds, previous),
                 self.spacing.align(
                     vertical::TopToBottom,
                     bounds,
@@ -0,0 +1,46 @@
+//! Overflow handling
+//!
+//! A [`LinearLayout`] with [constraints] has a limited amount of space along its primary axis.
+//! By default, views that don't fit this space are still placed and drawn outside of it.
+//! [`Overflow`] changes how these views are handled.
+//!
+//! [`LinearLayout`]: crate::layout::linear::LinearLayout
+//! [constraints]: crate::layout::linear::LinearLayout::with_constraints
+
+/// How a [`LinearLayout`] handles views that don't fit the available space
+///
+/// The available space is the maximum width of horizontal layouts, or the maximum height of
+/// vertical layouts, set using [`LinearLayout::with_constraints`]. Layouts without constraints
+/// never overflow.
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`LinearLayout::with_constraints`]: crate::layout::linear::LinearLayout::with_constraints
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Overflow {
+    /// Views are placed and drawn as if the space was not limited. This is the default.
+    Visible,
+
+    /// Views are placed as if the space was not limited, but only the part of them that is
+    /// inside the available space is drawn. The bounds of the layout don't extend past the
+    /// available space.
+    Clip,
+
+    /// The spacing between the views is reduced, even below zero, so that the views fit the
+    /// available space. The views may overlap each other.
+    Shrink,
+
+    /// Views that don't fit the available space completely are not drawn, and the bounds of the
+    /// layout end at the last view that is drawn.
+    ///
+    /// Use [`LinearLayout::visible_len`] to find out how many views are shown.
+    ///
+    /// [`LinearLayout::visible_len`]: crate::layout::linear::LinearLayout::visible_len
+    Truncate,
+}
+
+impl Default for Overflow {
+    #[inline]
+    fn default() -> Self {
+        Self::Visible
+    }
+}
@@ -19,6 +19,46 @@ pub trait SecondaryAlignment: Alignment {
     /// I.e. [`horizontal::Left`] returns the maximum width, while [`horizontal::LeftToRight`]
     /// returns the sum of the two widths.
     fn measure(prev: Size, view_size: Size) -> Size;
+
+    /// Returns the secondary alignment of the first view.
+    ///
+    /// The default implementation returns `Self::First::default()`. Alignments that store values,
+    /// like [`WithOffset`], need to pass them on to the alignment of the first view.
+    ///
+    /// [`WithOffset`]: crate::align::WithOffset
+    #[inline]
+    fn first(&self) -> Self::First {
+        Self::First::default()
+    }
+
+    /// Returns the secondary alignment of the views after the first one.
+    ///
+    /// These views are aligned to the previous view. The default implementation returns
+    /// `Self::default()`, so that offsets, like the one of [`WithOffset`], are only applied once.
+    /// Alignments that store settings which apply to every view need to return `*self`.
+    ///
+    /// [`WithOffset`]: crate::align::WithOffset
+    #[inline]
+    fn following(&self) -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the views should be placed so that their baselines line up.
+    ///
+    /// The default implementation returns `false`. See [`vertical::Baseline`].
+    #[inline]
+    fn aligns_baselines() -> bool {
+        false
+    }
+
+    /// Returns `true` if the views should be resized to the secondary size of the layout.
+    ///
+    /// The default implementation returns `false`. See [`horizontal::Stretch`] and
+    /// [`vertical::Stretch`].
+    #[inline]
+    fn stretches() -> bool {
+        false
+    }
 }
 
 fn max_width(prev_size: Size, view_size: Size) -> Size {
@@ -53,6 +93,25 @@ impl SecondaryAlignment for horizontal::Center {
     }
 }
 
+impl SecondaryAlignment for horizontal::CenterRounded {
+    type First = horizontal::CenterRounded;
+
+    #[inline]
+    fn measure(prev_size: Size, view_size: Size) -> Size {
+        max_width(prev_size, view_size)
+    }
+
+    #[inline]
+    fn first(&self) -> Self::First {
+        *self
+    }
+
+    #[inline]
+    fn following(&self) -> Self {
+        *self
+    }
+}
+
 impl SecondaryAlignment for horizontal::Right {
     type First = horizontal::Right;
 
@@ -62,6 +121,20 @@ impl SecondaryAlignment for horizontal::Right {
     }
 }
 
+impl SecondaryAlignment for horizontal::Stretch {
+    type First = horizontal::Stretch;
+
+    #[inline]
+    fn measure(prev_size: Size, view_size: Size) -> Size {
+        max_width(prev_size, view_size)
+    }
+
+    #[inline]
+    fn stretches() -> bool {
+        true
+    }
+}
+
 impl SecondaryAlignment for horizontal::RightToLeft {
     type First = horizontal::Right;
 
@@ -105,6 +178,25 @@ impl SecondaryAlignment for vertical::Center {
     }
 }
 
+impl SecondaryAlignment for vertical::CenterRounded {
+    type First = vertical::CenterRounded;
+
+    #[inline]
+    fn measure(prev_size: Size, view_size: Size) -> Size {
+        max_height(prev_size, view_size)
+    }
+
+    #[inline]
+    fn first(&self) -> Self::First {
+        *self
+    }
+
+    #[inline]
+    fn following(&self) -> Self {
+        *self
+    }
+}
+
 impl SecondaryAlignment for vertical::Bottom {
     type First = vertical::Bottom;
 
@@ -114,6 +206,34 @@ impl SecondaryAlignment for vertical::Bottom {
     }
 }
 
+impl SecondaryAlignment for vertical::Baseline {
+    type First = vertical::Baseline;
+
+    #[inline]
+    fn measure(prev_size: Size, view_size: Size) -> Size {
+        max_height(prev_size, view_size)
+    }
+
+    #[inline]
+    fn aligns_baselines() -> bool {
+        true
+    }
+}
+
+impl SecondaryAlignment for vertical::Stretch {
+    type First = vertical::Stretch;
+
+    #[inline]
+    fn measure(prev_size: Size, view_size: Size) -> Size {
+        max_height(prev_size, view_size)
+    }
+
+    #[inline]
+    fn stretches() -> bool {
+        true
+    }
+}
+
 impl SecondaryAlignment for vertical::TopToBottom {
     type First = vertical::Top;
 
@@ -131,3 +251,25 @@ impl SecondaryAlignment for vertical::BottomToTop {
         cascading(prev_size, view_size)
     }
 }
+
+/// Secondary alignment selected at runtime
+///
+/// The variants don't depend on the orientation of the layout: `Start` means [`horizontal::Left`]
+/// in vertical layouts and [`vertical::Top`] in horizontal layouts. See [`LinearLayoutDyn`].
+///
+/// [`LinearLayoutDyn`]: crate::layout::linear::LinearLayoutDyn
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SecondaryAlignmentDyn {
+    /// Align views to the left or top edge of the layout
+    Start,
+    /// Center views
+    Center,
+    /// Align views to the right or bottom edge of the layout
+    End,
+    /// Resize views to the width or height of the layout, see [`horizontal::Stretch`]
+    Stretch,
+    /// Place every view to the right of or below the previous one
+    StartToEnd,
+    /// Place every view to the left of or above the previous one
+    EndToStart,
+}
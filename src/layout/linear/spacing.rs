@@ -23,6 +23,16 @@ pub trait ElementSpacing: Copy + Clone {
         objects: usize,
         total_size: u32,
     ) -> i32;
+
+    /// Returns the size of the layout along the primary axis, given the space occupied by the
+    /// views.
+    ///
+    /// The default implementation returns `views_size`. Spacings that leave space before the first
+    /// or after the last view need to include that space here.
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        views_size
+    }
 }
 
 /// Lay out objects tightly, leaving no space between them
@@ -101,9 +111,161 @@ impl ElementSpacing for FixedMargin {
     }
 }
 
+/// Lay out objects with an individual margin between each pair of them
+///
+/// The first margin is placed between the first and second views, the second margin between the
+/// second and third views, and so on. If there are more views than margins, the last margin is
+/// used for the remaining gaps. An empty list places the views tightly.
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::Margins, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Two icon + label pairs: tight inside the pairs, wide between them
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(Margins(&[1, 8, 1]));
+/// ```
+#[derive(Copy, Clone)]
+pub struct Margins<'a>(pub &'a [i32]);
+impl ElementSpacing for Margins<'_> {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        _objects: usize,
+        _total_size: u32,
+    ) -> i32 {
+        let offset = match n.checked_sub(1) {
+            Some(gap) => self
+                .0
+                .get(gap)
+                .or_else(|| self.0.last())
+                .copied()
+                .unwrap_or(0),
+            None => 0,
+        };
+        alignment.align_with_offset(view, reference, offset)
+    }
+}
+
+/// Lay out objects with margins computed by a function
+///
+/// The function is called for every view except the first one, with the index of the view, the
+/// number of views and the total size of the views along the layout's primary axis. It returns
+/// the margin to leave in front of the view.
+///
+/// Closures that only capture `Copy` values are `Copy` themselves, so they can be used directly.
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::SpacingFn, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Alternate between 1px and 6px margins
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(SpacingFn(|n, _objects, _total_size| if n % 2 == 1 { 1 } else { 6 }));
+/// ```
+#[derive(Copy, Clone)]
+pub struct SpacingFn<F>(pub F)
+where
+    F: Fn(usize, usize, u32) -> i32 + Copy;
+impl<F> ElementSpacing for SpacingFn<F>
+where
+    F: Fn(usize, usize, u32) -> i32 + Copy,
+{
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        let offset = if n == 0 {
+            0
+        } else {
+            (self.0)(n, objects, total_size)
+        };
+        alignment.align_with_offset(view, reference, offset)
+    }
+}
+
+/// Lay out objects with a margin relative to the size of the layout
+///
+/// The margin between views is the given percentage of the total size of the views along the
+/// layout's primary axis. This way, the spacing scales with the size of the views.
+///
+/// # Example:
+/// ```
+/// use embedded_layout::{
+///     layout::linear::{spacing::RelativeMargin, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Apply a margin of 10% of the total width between objects
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(RelativeMargin(10));
+/// ```
+#[derive(Copy, Clone)]
+pub struct RelativeMargin(pub u32);
+impl ElementSpacing for RelativeMargin {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        _objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        let offset = if n == 0 {
+            0
+        } else {
+            (total_size as u64 * self.0 as u64 / 100) as i32
+        };
+        alignment.align_with_offset(view, reference, offset)
+    }
+}
+
 /// Distribute views to fill a given space
 ///
-/// Forces the layout to be as high or wide as set for this spacing
+/// Forces the layout to be as high or wide as set for this spacing. The first and last views are
+/// placed at the edges of the space, so a single view is simply placed at the start of the layout.
+/// Use [`DistributeFillWithEdges`] to also leave space at the edges.
 ///
 /// # Example:
 /// ```rust
@@ -136,18 +298,557 @@ impl ElementSpacing for DistributeFill {
         objects: usize,
         total_size: u32,
     ) -> i32 {
-        // bit of a mess, but calculate using i32 in case the views don't fit the space
-        let empty_space = self.0 as i32 - total_size as i32;
-        let base = empty_space / (objects - 1) as i32;
-        let remainder = empty_space % (objects - 1) as i32;
+        // There are no gaps to distribute the space into if there's at most one view.
+        let offset = if n == 0 || objects < 2 {
+            0
+        } else {
+            // bit of a mess, but calculate using i32 in case the views don't fit the space
+            let empty_space = self.0 as i32 - total_size as i32;
+            let base = empty_space / (objects - 1) as i32;
+            let remainder = empty_space % (objects - 1) as i32;
 
-        let offset = if n == 0 {
+            if n as i32 <= remainder {
+                base + 1
+            } else {
+                base
+            }
+        };
+        alignment.align_with_offset(view, reference, offset)
+    }
+
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        self.0.max(views_size)
+    }
+}
+
+/// Distribute views to fill a given space, using subpixel positions
+///
+/// Works like [`DistributeFill`], but the positions of the views are calculated using 26.6 fixed
+/// point numbers and are rounded to the nearest pixel separately for every view. Rounding errors
+/// don't accumulate, and if the space can't be divided evenly, the wider gaps are spread evenly
+/// instead of being placed at the start of the layout.
+///
+/// # Example:
+/// ```rust
+/// use embedded_layout::{
+///     layout::linear::{spacing::DistributeFillSubpixel, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Rectangle};
+///
+/// let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+///
+/// // Distribute 10px of empty space into 3 gaps
+/// let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect).append(rect))
+///     .with_spacing(DistributeFillSubpixel(18))
+///     .arrange();
+///
+/// let views = layout.inner();
+/// assert_eq!(5, views.parent.parent.object.top_left.x);
+/// assert_eq!(11, views.parent.object.top_left.x);
+/// assert_eq!(16, views.object.top_left.x);
+/// ```
+#[derive(Copy, Clone)]
+pub struct DistributeFillSubpixel(pub u32);
+impl ElementSpacing for DistributeFillSubpixel {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        // There are no gaps to distribute the space into if there's at most one view.
+        let offset = if n == 0 || objects < 2 {
             0
-        } else if n as i32 <= remainder {
-            base + 1
         } else {
-            base
+            let empty_space = self.0 as i64 - total_size as i64;
+            let gaps = (objects - 1) as i64;
+
+            // The empty space in front of the kth view, rounded to the nearest pixel.
+            let space_before = |k: usize| {
+                let fixed = (empty_space << SUBPIXEL_BITS) * k as i64 / gaps;
+                (fixed + (1 << (SUBPIXEL_BITS - 1))) >> SUBPIXEL_BITS
+            };
+
+            (space_before(n) - space_before(n - 1)) as i32
         };
         alignment.align_with_offset(view, reference, offset)
     }
+
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        self.0.max(views_size)
+    }
+}
+
+/// Distribute views to fill a given space, with half-sized gaps before the first and after the
+/// last view
+///
+/// Every view gets the same amount of space on both of its sides, so the gaps between views are
+/// twice as large as the gaps at the edges. Forces the layout to be as high or wide as set for this
+/// spacing.
+///
+/// If the views don't fit the space, they are placed tightly.
+///
+/// # Example:
+/// ```rust
+/// use embedded_layout::{
+///     layout::linear::{spacing::SpaceAround, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Distribute views in a 64px wide space
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(SpaceAround(64));
+/// ```
+#[derive(Copy, Clone)]
+pub struct SpaceAround(pub u32);
+impl ElementSpacing for SpaceAround {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        // The space before the nth view is `(2 * n + 1) / (2 * objects)` of the empty space.
+        let offset =
+            distributed_offset(self.0.saturating_sub(total_size), 2 * n + 1, 2 * objects, 2);
+        alignment.align_with_offset(view, reference, offset)
+    }
+
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        views_size.max(self.0)
+    }
+}
+
+/// Distribute views to fill a given space, with equal gaps between views and at the edges
+///
+/// Forces the layout to be as high or wide as set for this spacing.
+///
+/// If the views don't fit the space, they are placed tightly.
+///
+/// # Example:
+/// ```rust
+/// use embedded_layout::{
+///     layout::linear::{spacing::SpaceEvenly, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Distribute views in a 64px wide space
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(SpaceEvenly(64));
+/// ```
+#[derive(Copy, Clone)]
+pub struct SpaceEvenly(pub u32);
+impl ElementSpacing for SpaceEvenly {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        // The space before the nth view is `(n + 1) / (objects + 1)` of the empty space.
+        let offset = distributed_offset(self.0.saturating_sub(total_size), n + 1, objects + 1, 1);
+        alignment.align_with_offset(view, reference, offset)
+    }
+
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        views_size.max(self.0)
+    }
+}
+
+/// Distribute views to fill a given space, with gaps before the first and after the last view
+///
+/// This is the counterpart of [`DistributeFill`] that also treats the edges of the space as gaps,
+/// so the gaps between the views and at the edges are equal. This means a single view is centered
+/// in the space. Forces the layout to be as high or wide as set for this spacing.
+///
+/// The views are placed the same way as with [`SpaceEvenly`].
+///
+/// If the views don't fit the space, they are placed tightly.
+///
+/// # Example:
+/// ```rust
+/// use embedded_layout::{
+///     layout::linear::{spacing::DistributeFillWithEdges, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// // Center a single view in a 64px high space
+/// let layout = LinearLayout::vertical(Chain::new(Line::new(Point::zero(), Point::new(0, 5))))
+///     .with_spacing(DistributeFillWithEdges(64))
+///     .arrange();
+///
+/// assert_eq!(64, layout.size().height);
+/// ```
+#[derive(Copy, Clone)]
+pub struct DistributeFillWithEdges(pub u32);
+impl ElementSpacing for DistributeFillWithEdges {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        SpaceEvenly(self.0).align(alignment, view, reference, n, objects, total_size)
+    }
+
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        SpaceEvenly(self.0).primary_size(views_size)
+    }
+}
+
+/// Element spacing selected at runtime
+///
+/// Every variant behaves the same way as the element spacing of the same name. Use this type if
+/// the spacing of a layout is only known at runtime, or if the layout needs to be stored in a
+/// struct with a single, nameable type. See [`LinearLayoutDyn`].
+///
+/// # Example:
+/// ```rust
+/// use embedded_layout::{
+///     layout::linear::{spacing::ElementSpacingDyn, LinearLayout},
+///     prelude::*,
+/// };
+/// use embedded_graphics::{prelude::*, primitives::Line};
+///
+/// let compact = true;
+/// let spacing = if compact {
+///     ElementSpacingDyn::Tight
+/// } else {
+///     ElementSpacingDyn::FixedMargin(3)
+/// };
+///
+/// let _ = LinearLayout::horizontal(
+///         Views::new(&mut [
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///             Line::new(Point::zero(), Point::new(0, 5)),
+///         ])
+///     )
+///     .with_spacing(spacing);
+/// ```
+///
+/// [`LinearLayoutDyn`]: crate::layout::linear::LinearLayoutDyn
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ElementSpacingDyn {
+    /// See [`Tight`]
+    Tight,
+    /// See [`FixedMargin`]
+    FixedMargin(i32),
+    /// See [`RelativeMargin`]
+    RelativeMargin(u32),
+    /// See [`DistributeFill`]
+    DistributeFill(u32),
+    /// See [`DistributeFillSubpixel`]
+    DistributeFillSubpixel(u32),
+    /// See [`DistributeFillWithEdges`]
+    DistributeFillWithEdges(u32),
+    /// See [`SpaceAround`]
+    SpaceAround(u32),
+    /// See [`SpaceEvenly`]
+    SpaceEvenly(u32),
+}
+
+impl Default for ElementSpacingDyn {
+    #[inline]
+    fn default() -> Self {
+        Self::Tight
+    }
+}
+
+impl ElementSpacing for ElementSpacingDyn {
+    #[inline]
+    fn align(
+        &self,
+        alignment: impl Alignment,
+        view: Rectangle,
+        reference: Rectangle,
+        n: usize,
+        objects: usize,
+        total_size: u32,
+    ) -> i32 {
+        match *self {
+            Self::Tight => Tight.align(alignment, view, reference, n, objects, total_size),
+            Self::FixedMargin(margin) => {
+                FixedMargin(margin).align(alignment, view, reference, n, objects, total_size)
+            }
+            Self::RelativeMargin(percent) => {
+                RelativeMargin(percent).align(alignment, view, reference, n, objects, total_size)
+            }
+            Self::DistributeFill(size) => {
+                DistributeFill(size).align(alignment, view, reference, n, objects, total_size)
+            }
+            Self::DistributeFillSubpixel(size) => DistributeFillSubpixel(size)
+                .align(alignment, view, reference, n, objects, total_size),
+            Self::DistributeFillWithEdges(size) => DistributeFillWithEdges(size)
+                .align(alignment, view, reference, n, objects, total_size),
+            Self::SpaceAround(size) => {
+                SpaceAround(size).align(alignment, view, reference, n, objects, total_size)
+            }
+            Self::SpaceEvenly(size) => {
+                SpaceEvenly(size).align(alignment, view, reference, n, objects, total_size)
+            }
+        }
+    }
+
+    #[inline]
+    fn primary_size(&self, views_size: u32) -> u32 {
+        match *self {
+            Self::Tight => Tight.primary_size(views_size),
+            Self::FixedMargin(margin) => FixedMargin(margin).primary_size(views_size),
+            Self::RelativeMargin(percent) => RelativeMargin(percent).primary_size(views_size),
+            Self::DistributeFill(size) => DistributeFill(size).primary_size(views_size),
+            Self::DistributeFillSubpixel(size) => {
+                DistributeFillSubpixel(size).primary_size(views_size)
+            }
+            Self::DistributeFillWithEdges(size) => {
+                DistributeFillWithEdges(size).primary_size(views_size)
+            }
+            Self::SpaceAround(size) => SpaceAround(size).primary_size(views_size),
+            Self::SpaceEvenly(size) => SpaceEvenly(size).primary_size(views_size),
+        }
+    }
+}
+
+/// Number of fractional bits of the fixed point positions used by [`DistributeFillSubpixel`].
+const SUBPIXEL_BITS: u32 = 6;
+
+/// Returns the gap in front of a view, where the empty space in front of the view is
+/// `empty_space * numerator / denominator` and the previous view is `step` numerators behind.
+///
+/// Calculating the gap from the cumulative empty space distributes rounding errors evenly.
+fn distributed_offset(empty_space: u32, numerator: usize, denominator: usize, step: usize) -> i32 {
+    let space_before = |numerator: usize| empty_space as usize * numerator / denominator;
+    let previous = numerator.checked_sub(step).map_or(0, space_before);
+
+    (space_before(numerator) - previous) as i32
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        layout::linear::{
+            spacing::{
+                DistributeFill, DistributeFillSubpixel, DistributeFillWithEdges, ElementSpacingDyn,
+                Margins, RelativeMargin, SpaceAround, SpaceEvenly, SpacingFn,
+            },
+            LinearLayout,
+        },
+        prelude::*,
+    };
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    fn rects() -> chain! { Rectangle, Rectangle } {
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        Chain::new(rect).append(rect)
+    }
+
+    #[test]
+    fn margins_are_applied_in_order() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let layout =
+            LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect).append(rect))
+                .with_spacing(Margins(&[1, 3]))
+                .arrange();
+
+        let views = layout.inner();
+        assert_eq!(Point::new(3, 0), views.parent.parent.object.top_left);
+        assert_eq!(Point::new(8, 0), views.parent.object.top_left);
+        assert_eq!(Point::new(13, 0), views.object.top_left);
+    }
+
+    #[test]
+    fn spacing_function() {
+        let layout =
+            LinearLayout::vertical(rects().append(Rectangle::new(Point::zero(), Size::new(4, 2))))
+                .with_spacing(SpacingFn(|n, objects, total_size| {
+                    assert_eq!(3, objects);
+                    assert_eq!(6, total_size);
+                    n as i32
+                }))
+                .arrange();
+
+        let views = layout.inner();
+        assert_eq!(Point::new(0, 3), views.parent.object.top_left);
+        assert_eq!(Point::new(0, 7), views.object.top_left);
+    }
+
+    #[test]
+    fn relative_margin() {
+        let layout = LinearLayout::horizontal(rects())
+            .with_spacing(RelativeMargin(50))
+            .arrange();
+
+        assert_eq!(Size::new(12, 2), layout.size());
+
+        let views = layout.into_inner();
+        assert_eq!(Point::new(8, 0), views.object.top_left);
+    }
+
+    #[test]
+    fn space_around() {
+        let layout = LinearLayout::horizontal(rects())
+            .with_spacing(SpaceAround(20))
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(20, 2)),
+            layout.bounds()
+        );
+
+        let views = layout.into_inner();
+        assert_eq!(Point::new(3, 0), views.parent.object.top_left);
+        assert_eq!(Point::new(13, 0), views.object.top_left);
+    }
+
+    #[test]
+    fn space_evenly() {
+        let layout = LinearLayout::vertical(rects())
+            .with_spacing(SpaceEvenly(19))
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(4, 19)),
+            layout.bounds()
+        );
+
+        let views = layout.into_inner();
+        assert_eq!(Point::new(0, 5), views.parent.object.top_left);
+        assert_eq!(Point::new(0, 12), views.object.top_left);
+    }
+
+    #[test]
+    fn overflowing_views_are_placed_tightly() {
+        let layout = LinearLayout::horizontal(rects())
+            .with_spacing(SpaceEvenly(5))
+            .arrange();
+
+        assert_eq!(Size::new(8, 2), layout.size());
+
+        let views = layout.into_inner();
+        assert_eq!(Point::new(0, 0), views.parent.object.top_left);
+        assert_eq!(Point::new(4, 0), views.object.top_left);
+    }
+
+    #[test]
+    fn distribute_fill_single_view() {
+        let rect = Rectangle::new(Point::new(3, 3), Size::new(4, 2));
+
+        let layout = LinearLayout::horizontal(Chain::new(rect))
+            .with_spacing(DistributeFill(20))
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(20, 2)),
+            layout.bounds()
+        );
+        assert_eq!(Point::zero(), layout.inner().object.top_left);
+    }
+
+    #[test]
+    fn distribute_fill_with_edges() {
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        let layout = LinearLayout::horizontal(Chain::new(rect))
+            .with_spacing(DistributeFillWithEdges(20))
+            .arrange();
+
+        assert_eq!(Size::new(20, 2), layout.size());
+        assert_eq!(Point::new(8, 0), layout.inner().object.top_left);
+
+        let layout = LinearLayout::horizontal(rects())
+            .with_spacing(DistributeFillWithEdges(20))
+            .arrange();
+
+        let views = layout.into_inner();
+        assert_eq!(Point::new(4, 0), views.parent.object.top_left);
+        assert_eq!(Point::new(12, 0), views.object.top_left);
+    }
+
+    #[test]
+    fn dynamic_spacing_matches_static_spacing() {
+        let layout = LinearLayout::vertical(rects())
+            .with_spacing(ElementSpacingDyn::SpaceEvenly(19))
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(4, 19)),
+            layout.bounds()
+        );
+
+        let views = layout.into_inner();
+        assert_eq!(Point::new(0, 5), views.parent.object.top_left);
+        assert_eq!(Point::new(0, 12), views.object.top_left);
+    }
+
+    #[test]
+    fn distribute_fill_subpixel_spreads_remainder() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let views = || Chain::new(rect).append(rect).append(rect).append(rect);
+
+        let layout = LinearLayout::horizontal(views())
+            .with_spacing(DistributeFill(18))
+            .arrange();
+        let views_fill = layout.into_inner();
+
+        let layout = LinearLayout::horizontal(views())
+            .with_spacing(DistributeFillSubpixel(18))
+            .arrange();
+        assert_eq!(Size::new(18, 2), layout.size());
+        let views_subpixel = layout.into_inner();
+
+        // 10px in 3 gaps: 4, 3, 3 vs. 3, 4, 3
+        assert_eq!(Point::new(6, 0), views_fill.parent.parent.object.top_left);
+        assert_eq!(
+            Point::new(5, 0),
+            views_subpixel.parent.parent.object.top_left
+        );
+        assert_eq!(Point::new(16, 0), views_subpixel.object.top_left);
+
+        // Views that don't fit the space overlap
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+            .with_spacing(DistributeFillSubpixel(5))
+            .arrange();
+        assert_eq!(Point::new(3, 0), layout.inner().object.top_left);
+    }
 }
@@ -0,0 +1,336 @@
+//! List layout
+//!
+//! A list layout places its items below each other, in a space of a given height. Only the items
+//! that fit the space are shown, starting at a scroll position. If some items after the visible
+//! ones don't fit, a "more" indicator view, for example a text that reads "...", is shown below
+//! the last visible item.
+//!
+//! This is similar to a vertical [`LinearLayout`] with [`Overflow::Truncate`], but the list can be
+//! scrolled, and it reserves room for the indicator.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{
+//! #     mock_display::MockDisplay,
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     text::{Baseline, Text},
+//! # };
+//! use embedded_layout::{layout::list::ListLayout, prelude::*};
+//!
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//! let item = |text| Text::with_baseline(text, Point::zero(), text_style, Baseline::Top);
+//!
+//! let mut list = ListLayout::new(
+//!     Chain::new(item("Apple"))
+//!         .append(item("Banana"))
+//!         .append(item("Cherry"))
+//!         .append(item("Date")),
+//!     item("..."),
+//!     32,
+//! )
+//! .arrange();
+//!
+//! // Three items and the indicator don't fit, so only two items are shown.
+//! assert_eq!(0..2, list.visible_range());
+//! assert!(list.is_truncated());
+//!
+//! // Scroll to the end of the list.
+//! assert_eq!(1..4, list.scroll_to(1));
+//! assert!(!list.is_truncated());
+//!
+//! let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+//! list.draw(&mut display).unwrap();
+//! ```
+//!
+//! [`LinearLayout`]: crate::layout::linear::LinearLayout
+//! [`Overflow::Truncate`]: crate::layout::linear::Overflow::Truncate
+
+use core::ops::Range;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{view_group::DrawableViewGroup, view_group::ViewGroup, View};
+
+/// Shows as many items of a [`ViewGroup`] as fit a given height.
+///
+/// For more information and an example, see the [module level documentation](crate::layout::list).
+pub struct ListLayout<VG, M> {
+    items: VG,
+    more: M,
+    position: Point,
+    height: u32,
+    first: usize,
+    visible: Range<usize>,
+}
+
+impl<VG, M> ListLayout<VG, M>
+where
+    VG: ViewGroup,
+    M: View,
+{
+    /// Creates a new list layout with the given items, "more" indicator and height.
+    ///
+    /// The list is placed at `(0, 0)` and shows the items from the first one. Call
+    /// [`ListLayout::arrange`] to place the items.
+    #[inline]
+    #[must_use]
+    pub fn new(items: VG, more: M, height: u32) -> Self {
+        Self {
+            items,
+            more,
+            position: Point::zero(),
+            height,
+            first: 0,
+            visible: 0..0,
+        }
+    }
+
+    /// Arrange the visible items and return the list.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        self.rearrange();
+        self
+    }
+
+    /// Arrange the visible items again, keeping the list at its current position, and return the
+    /// range of visible items.
+    ///
+    /// Call this method after the size of some items changed.
+    #[inline]
+    pub fn rearrange(&mut self) -> Range<usize> {
+        let len = self.items.len();
+        let first = self.first.min(len);
+        let item_height = |items: &VG, idx| items.bounds_of(idx).size.height;
+
+        let total = (first..len).fold(0u32, |total, idx| {
+            total.saturating_add(item_height(&self.items, idx))
+        });
+        let available = if total <= self.height {
+            self.height
+        } else {
+            self.height.saturating_sub(self.more.bounds().size.height)
+        };
+
+        let mut y = self.position.y;
+        let mut end = first;
+        while end < len {
+            let bounds = self.items.bounds_of(end);
+            if y + bounds.size.height as i32 > self.position.y + available as i32 {
+                break;
+            }
+
+            let by = Point::new(self.position.x, y) - bounds.top_left;
+            self.items.translate_child(end, by);
+
+            y += bounds.size.height as i32;
+            end += 1;
+        }
+
+        let more = self.more.bounds().top_left;
+        self.more
+            .translate_impl(Point::new(self.position.x, y) - more);
+
+        self.visible = first..end;
+        self.visible_range()
+    }
+
+    /// Makes the item at position `first` the first visible item, arranges the list, and returns
+    /// the range of visible items.
+    ///
+    /// Indexes past the last item make the last item the first visible one.
+    #[inline]
+    pub fn scroll_to(&mut self, first: usize) -> Range<usize> {
+        self.first = first.min(self.items.len().saturating_sub(1));
+        self.rearrange()
+    }
+
+    /// Returns the index of the first visible item.
+    #[inline]
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Returns the range of items that were visible when the list was last arranged.
+    #[inline]
+    pub fn visible_range(&self) -> Range<usize> {
+        self.visible.clone()
+    }
+
+    /// Returns `true` if some items after the visible ones are not shown, i.e. if the "more"
+    /// indicator is shown.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.visible.end < self.items.len()
+    }
+
+    /// Returns a reference to the items.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.items
+    }
+
+    /// Returns a mutable reference to the items.
+    ///
+    /// Call [`ListLayout::rearrange`] after changing the size of an item.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.items
+    }
+
+    /// Returns a reference to the "more" indicator.
+    #[inline]
+    pub fn indicator(&self) -> &M {
+        &self.more
+    }
+
+    /// Returns a mutable reference to the "more" indicator.
+    #[inline]
+    pub fn indicator_mut(&mut self) -> &mut M {
+        &mut self.more
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> VG {
+        self.items
+    }
+}
+
+impl<VG, M> Clone for ListLayout<VG, M>
+where
+    VG: Clone,
+    M: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            more: self.more.clone(),
+            position: self.position,
+            height: self.height,
+            first: self.first,
+            visible: self.visible.clone(),
+        }
+    }
+}
+
+impl<VG, M> View for ListLayout<VG, M>
+where
+    VG: ViewGroup,
+    M: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        View::translate_impl(&mut self.items, by);
+        self.more.translate_impl(by);
+    }
+
+    /// Returns the bounds of the list: the given height, and the width of the widest visible
+    /// item or indicator.
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let items_width = self
+            .visible
+            .clone()
+            .map(|idx| self.items.bounds_of(idx).size.width)
+            .max()
+            .unwrap_or(0);
+        let width = if self.is_truncated() {
+            items_width.max(self.more.bounds().size.width)
+        } else {
+            items_width
+        };
+
+        Rectangle::new(self.position, Size::new(width, self.height))
+    }
+}
+
+impl<C, VG, M> Drawable for ListLayout<VG, M>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C>,
+    M: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for idx in self.visible.clone() {
+            self.items.drawable_at(idx).draw(display)?;
+        }
+
+        if self.is_truncated() {
+            self.more.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn indicator_is_shown_if_items_are_truncated() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let item = Rectangle::new(Point::zero(), Size::new(3, 1)).into_styled(style);
+        let more = Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style);
+
+        let mut items = [item; 4];
+        let mut list = ListLayout::new(Views::new(&mut items), more, 3).arrange();
+
+        assert_eq!(0..2, list.visible_range());
+        assert_eq!(Size::new(3, 3), list.size());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        list.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "###", //
+            "###", //
+            "#  ", //
+        ]);
+
+        assert_eq!(1..4, list.scroll_to(1));
+        assert!(!list.is_truncated());
+        assert_eq!(Point::new(0, 2), list.inner().bounds_of(3).top_left);
+    }
+
+    #[test]
+    fn translate_moves_the_list() {
+        let item = Rectangle::new(Point::zero(), Size::new(3, 2));
+        let more = Rectangle::new(Point::zero(), Size::new(1, 1));
+
+        let mut list = ListLayout::new(Chain::new(item).append(item), more, 3)
+            .arrange()
+            .translate(Point::new(5, 5));
+        assert_eq!(0..1, list.visible_range());
+        assert_eq!(Point::new(5, 7), list.indicator().top_left);
+
+        list.inner_mut().object.size.height = 1;
+        assert_eq!(0..2, list.rearrange());
+        assert_eq!(Point::new(5, 7), list.inner().object.top_left);
+    }
+}
@@ -44,4 +44,18 @@
 //! [`ViewGroup`]: crate::view_group::ViewGroup
 //! [`LinearLayout`]: crate::layout::linear::LinearLayout
 
+pub mod const_layout;
+mod constraints;
 pub mod linear;
+pub mod list;
+pub mod paged;
+pub mod radial;
+pub mod relative;
+mod resizable;
+pub mod split;
+pub mod status_bar;
+pub mod tabs;
+pub mod virtual_list;
+
+pub use constraints::{Constraints, MeasurableView};
+pub use resizable::ResizableView;
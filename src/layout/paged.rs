@@ -0,0 +1,273 @@
+//! Paged layout
+//!
+//! A paged layout shows one [`View`] of a [`ViewGroup`] at a time. Each view is a page, usually a
+//! layout that fills the whole screen. All pages are placed at the same position, and only the
+//! active page is drawn. This is a common pattern for watch faces and for dashboards that don't
+//! fit on a single screen.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{
+//! #     mock_display::MockDisplay,
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     text::Text,
+//! # };
+//! use embedded_layout::{
+//!     layout::{linear::LinearLayout, paged::PagedLayout},
+//!     prelude::*,
+//! };
+//!
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let temperature = LinearLayout::vertical(
+//!     Chain::new(Text::new("Temperature", Point::zero(), text_style))
+//!         .append(Text::new("21 C", Point::zero(), text_style)),
+//! )
+//! .arrange();
+//! let humidity = LinearLayout::vertical(
+//!     Chain::new(Text::new("Humidity", Point::zero(), text_style))
+//!         .append(Text::new("45 %", Point::zero(), text_style)),
+//! )
+//! .arrange();
+//!
+//! let mut pages = PagedLayout::new(Chain::new(temperature).append(humidity));
+//!
+//! // Switch to the humidity page, e.g. when a button is pressed.
+//! pages.next_page();
+//! assert_eq!(1, pages.page());
+//!
+//! let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+//! pages.draw(&mut display).unwrap();
+//! ```
+//!
+//! [`View`]: crate::View
+//! [`ViewGroup`]: crate::view_group::ViewGroup
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableViewGroup, EmptyViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// Shows one page of a [`ViewGroup`] at a time.
+///
+/// For more information and an example, see the [module level documentation](crate::layout::paged).
+pub struct PagedLayout<VG> {
+    pages: VG,
+    page: usize,
+}
+
+impl<VG> PagedLayout<VG>
+where
+    VG: ViewGroup,
+{
+    /// Creates a new paged layout that shows the first page.
+    ///
+    /// Every page is moved so that its top left corner is at the top left corner of the first
+    /// page.
+    #[inline]
+    #[must_use]
+    pub fn new(mut pages: VG) -> Self {
+        if let Some(first) = pages.try_at(0) {
+            let position = first.bounds().top_left;
+
+            for idx in 1..pages.len() {
+                let by = position - pages.bounds_of(idx).top_left;
+                pages.translate_child(idx, by);
+            }
+        }
+
+        Self { pages, page: 0 }
+    }
+
+    /// Returns the index of the active page.
+    #[inline]
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of pages.
+    #[inline]
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Makes the page at position `page` active.
+    ///
+    /// Indexes past the last page select the last page.
+    #[inline]
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page.min(self.page_count().saturating_sub(1));
+    }
+
+    /// Makes the next page active, wrapping around after the last page. Returns the index of the
+    /// new active page.
+    #[inline]
+    pub fn next_page(&mut self) -> usize {
+        let count = self.page_count().max(1);
+        self.page = (self.page + 1) % count;
+        self.page
+    }
+
+    /// Makes the previous page active, wrapping around before the first page. Returns the index of
+    /// the new active page.
+    #[inline]
+    pub fn prev_page(&mut self) -> usize {
+        let count = self.page_count().max(1);
+        self.page = (self.page + count - 1) % count;
+        self.page
+    }
+
+    /// Returns a reference to the active page.
+    #[inline]
+    pub fn current(&self) -> &dyn View {
+        self.pages.try_at(self.page).unwrap_or(&EmptyViewGroup)
+    }
+
+    /// Returns a mutable reference to the active page, or `None` if there are no pages.
+    #[inline]
+    pub fn current_mut(&mut self) -> Option<&mut dyn View> {
+        self.pages.try_at_mut(self.page)
+    }
+
+    /// Returns a reference to the pages.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.pages
+    }
+
+    /// Returns a mutable reference to the pages.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.pages
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> VG {
+        self.pages
+    }
+}
+
+impl<VG> Clone for PagedLayout<VG>
+where
+    VG: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            pages: self.pages.clone(),
+            page: self.page,
+        }
+    }
+}
+
+impl<VG> View for PagedLayout<VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(&mut self.pages, by);
+    }
+
+    /// Returns the bounds of the active page.
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.current().bounds()
+    }
+}
+
+impl<C, VG> Drawable for PagedLayout<VG>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C> + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.page < self.pages.len() {
+            self.pages.drawable_at(self.page).draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn only_the_active_page_is_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let first = Rectangle::new(Point::new(1, 1), Size::new(2, 2)).into_styled(style);
+        let second = Rectangle::new(Point::new(5, 5), Size::new(3, 1)).into_styled(style);
+
+        let mut pages = PagedLayout::new(Chain::new(first).append(second));
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+            pages.bounds()
+        );
+
+        pages.set_page(1);
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(3, 1)),
+            pages.bounds()
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        pages.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            " ###", //
+        ]);
+    }
+
+    #[test]
+    fn page_navigation_wraps_around() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut pages = PagedLayout::new(Chain::new(rect).append(rect).append(rect));
+
+        assert_eq!(2, pages.prev_page());
+        assert_eq!(0, pages.next_page());
+        assert_eq!(1, pages.next_page());
+
+        pages.set_page(10);
+        assert_eq!(2, pages.page());
+    }
+
+    #[test]
+    fn translate_moves_every_page() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut pages = PagedLayout::new(Chain::new(rect).append(rect)).translate(Point::new(3, 4));
+        pages.next_page();
+
+        assert_eq!(Point::new(3, 4), pages.bounds().top_left);
+    }
+}
@@ -0,0 +1,356 @@
+//! Radial layout
+//!
+//! A radial layout places its views on a circle, so that the center of every view is on the
+//! circle. The views are placed at equal angles, starting at a given angle and going clockwise.
+//! This is useful for the tick labels of a dial, or for the items of a circular menu.
+//!
+//! By default, the views are spread around the whole circle, starting at 3 o'clock, the same way
+//! `embedded-graphics` measures angles. Use [`RadialLayout::with_sweep`] to place the views on an
+//! arc instead. On an arc, the first and last views are placed at the two ends of the arc.
+//!
+//! # Example
+//!
+//! Place the labels of a gauge on the top half of a circle:
+//!
+//! ```rust
+//! # use embedded_layout::prelude::*;
+//! # use embedded_graphics::{
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     text::Text,
+//! # };
+//! use embedded_layout::layout::radial::RadialLayout;
+//!
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let layout = RadialLayout::new(
+//!     Point::new(32, 32),
+//!     20,
+//!     Chain::new(Text::new("0", Point::zero(), text_style))
+//!         .append(Text::new("50", Point::zero(), text_style))
+//!         .append(Text::new("100", Point::zero(), text_style)),
+//! )
+//! .with_start_angle(180.0.deg())
+//! .with_sweep(180.0.deg())
+//! .arrange();
+//!
+//! assert_eq!(Point::new(12, 32), layout.inner().parent.parent.object.bounds().center());
+//! assert_eq!(Point::new(32, 12), layout.inner().parent.object.bounds().center());
+//! assert_eq!(Point::new(52, 32), layout.inner().object.bounds().center());
+//! ```
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Angle,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// Millidegrees in a full circle.
+const FULL_CIRCLE: i64 = 360_000;
+
+/// `RadialLayout`
+///
+/// [`RadialLayout`] places its views on a circle, at equal angles.
+///
+/// For more information and examples see the [module level documentation](crate::layout::radial).
+pub struct RadialLayout<VG> {
+    center: Point,
+    radius: u32,
+    start: Angle,
+    sweep: Angle,
+    views: VG,
+}
+
+impl<VG> RadialLayout<VG>
+where
+    VG: ViewGroup,
+{
+    /// Create a new [`RadialLayout`] that places the views on a circle.
+    ///
+    /// The first view is placed at 3 o'clock, and the views are spread around the whole circle.
+    #[inline]
+    #[must_use]
+    pub fn new(center: Point, radius: u32, views: VG) -> Self {
+        Self {
+            center,
+            radius,
+            start: Angle::zero(),
+            sweep: Angle::from_degrees(360.0),
+            views,
+        }
+    }
+
+    /// Change the angle of the first view.
+    ///
+    /// Angles are measured clockwise, starting at 3 o'clock.
+    #[inline]
+    #[must_use]
+    pub fn with_start_angle(mut self, start: Angle) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Change the angle the views are spread over.
+    ///
+    /// If the sweep is less than a full circle, the first and last views are placed at the ends
+    /// of the arc. Negative angles place the views counterclockwise.
+    #[inline]
+    #[must_use]
+    pub fn with_sweep(mut self, sweep: Angle) -> Self {
+        self.sweep = sweep;
+        self
+    }
+
+    /// Returns the center of the circle.
+    #[inline]
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    /// Returns the radius of the circle.
+    #[inline]
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Returns a reference to the contained views.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.views
+    }
+
+    /// Returns a mutable reference to the contained views.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.views
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> VG {
+        self.views
+    }
+
+    /// Returns the point on the circle where the center of the view at position `idx` is placed.
+    #[inline]
+    pub fn position_of(&self, idx: usize) -> Point {
+        let count = self.views.len() as i64;
+        let sweep = millidegrees(self.sweep);
+
+        let steps = if count > 1 && sweep.abs() < FULL_CIRCLE {
+            count - 1
+        } else {
+            count.max(1)
+        };
+        let angle = millidegrees(self.start) + sweep * idx as i64 / steps;
+
+        let radius = self.radius as i64;
+        self.center
+            + Point::new(
+                sin_scaled(angle + FULL_CIRCLE / 4, radius),
+                sin_scaled(angle, radius),
+            )
+    }
+
+    /// Place the centers of the views on the circle.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        for idx in 0..self.views.len() {
+            let by = self.position_of(idx) - self.views.bounds_of(idx).center();
+            self.views.translate_child(idx, by);
+        }
+
+        self
+    }
+}
+
+/// Converts an angle to integer millidegrees.
+fn millidegrees(angle: Angle) -> i64 {
+    (angle.to_degrees() * 1000.0) as i64
+}
+
+/// Returns `scale * sin(angle)`, rounded to the nearest integer.
+///
+/// `angle` is given in millidegrees. The sine is approximated using Bhaskara I's formula, which is
+/// accurate to about 0.2%, so the error is less than a pixel for the radii that fit on a display.
+fn sin_scaled(angle: i64, scale: i64) -> i32 {
+    let angle = angle.rem_euclid(FULL_CIRCLE);
+    let (angle, sign) = if angle >= FULL_CIRCLE / 2 {
+        (angle - FULL_CIRCLE / 2, -1)
+    } else {
+        (angle, 1)
+    };
+
+    // sin(x) ~ 4x(180 - x) / (40500 - x(180 - x)), for 0 <= x <= 180 degrees
+    let p = angle as i128 * (FULL_CIRCLE / 2 - angle) as i128;
+    let numerator = 4 * p * scale as i128;
+    let denominator = 40_500_000_000 - p;
+
+    sign * ((numerator + denominator / 2) / denominator) as i32
+}
+
+impl<VG> Clone for RadialLayout<VG>
+where
+    VG: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            center: self.center,
+            radius: self.radius,
+            start: self.start,
+            sweep: self.sweep,
+            views: self.views.clone(),
+        }
+    }
+}
+
+impl<VG> View for RadialLayout<VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.center += by;
+        View::translate_impl(&mut self.views, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(&self.views)
+    }
+}
+
+impl<VG> ViewGroup for RadialLayout<VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.views.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.views.at_mut(idx)
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views.translate_child(idx, by)
+    }
+}
+
+impl<C, VG> DrawableViewGroup<C> for RadialLayout<VG>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.views.drawable_at(idx)
+    }
+}
+
+impl<C, VG> Drawable for RadialLayout<VG>
+where
+    C: PixelColor,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{geometry::AngleUnit, prelude::Size};
+
+    #[test]
+    fn views_are_centered_on_the_circle() {
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 3));
+
+        let layout = RadialLayout::new(
+            Point::new(20, 20),
+            10,
+            Chain::new(rect).append(rect).append(rect).append(rect),
+        )
+        .arrange();
+
+        assert_eq!(Point::new(30, 20), layout.bounds_of(0).center());
+        assert_eq!(Point::new(20, 30), layout.bounds_of(1).center());
+        assert_eq!(Point::new(10, 20), layout.bounds_of(2).center());
+        assert_eq!(Point::new(20, 10), layout.bounds_of(3).center());
+        assert_eq!(
+            Rectangle::new(Point::new(9, 9), Size::new(23, 23)),
+            layout.bounds()
+        );
+    }
+
+    #[test]
+    fn views_are_placed_on_arc() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+
+        let layout = RadialLayout::new(
+            Point::zero(),
+            100,
+            Chain::new(rect).append(rect).append(rect),
+        )
+        .with_start_angle(-90.0.deg())
+        .with_sweep(-90.0.deg())
+        .arrange();
+
+        assert_eq!(Point::new(0, -100), layout.bounds_of(0).top_left);
+        assert_eq!(Point::new(-71, -71), layout.bounds_of(1).top_left);
+        assert_eq!(Point::new(-100, 0), layout.bounds_of(2).top_left);
+    }
+
+    #[test]
+    fn sine_approximation() {
+        assert_eq!(0, sin_scaled(0, 1000));
+        assert_eq!(500, sin_scaled(30_000, 1000));
+        assert_eq!(1000, sin_scaled(90_000, 1000));
+        assert_eq!(-1000, sin_scaled(-90_000, 1000));
+        assert_eq!(0, sin_scaled(180_000, 1000));
+    }
+}
@@ -0,0 +1,467 @@
+//! Relative layout
+//!
+//! A relative layout positions its [`View`]s according to a list of [`Constraint`]s. Each
+//! constraint places a view relative to one of its siblings, for example "place the second view to
+//! the right of the first one, with a 4px gap, aligned to its top edge".
+//!
+//! Constraints are applied in order when calling [`RelativeLayout::arrange`]. A constraint uses the
+//! position of its anchor at the time it is applied, so anchors should be constrained before the
+//! views that refer to them. Views without a constraint are not moved.
+//!
+//! # Example
+//!
+//! Place a title, a value to the right of the title, and a description below the title:
+//!
+//! ```rust
+//! # use embedded_layout::prelude::*;
+//! # use embedded_graphics::{
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     text::Text,
+//! #     prelude::*,
+//! # };
+//! use embedded_layout::{
+//!     layout::relative::{Constraint, RelativeLayout},
+//!     view_group::ViewGroup,
+//! };
+//!
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let layout = RelativeLayout::new(
+//!     Chain::new(Text::new("Temperature", Point::zero(), text_style))
+//!         .append(Text::new("21 C", Point::zero(), text_style))
+//!         .append(Text::new("Living room", Point::zero(), text_style)),
+//!     [
+//!         Constraint::new(1, 0).right_of(4).align_top(),
+//!         Constraint::new(2, 0).below(0).align_left(),
+//!     ],
+//! )
+//! .arrange();
+//!
+//! let views = layout.inner();
+//! assert_eq!(views.bounds_of(0).top_left.y, views.bounds_of(1).top_left.y);
+//! assert_eq!(views.bounds_of(0).top_left.x, views.bounds_of(2).top_left.x);
+//! ```
+//!
+//! [`View`]: crate::View
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::{horizontal, vertical, Alignment},
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup},
+    View,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum HorizontalRelation {
+    None,
+    Left,
+    Center,
+    Right,
+    LeftOf(u32),
+    RightOf(u32),
+}
+
+impl HorizontalRelation {
+    fn align(self, view: Rectangle, anchor: Rectangle) -> i32 {
+        match self {
+            HorizontalRelation::None => horizontal::NoAlignment.align(view, anchor),
+            HorizontalRelation::Left => horizontal::Left.align(view, anchor),
+            HorizontalRelation::Center => horizontal::Center.align(view, anchor),
+            HorizontalRelation::Right => horizontal::Right.align(view, anchor),
+            HorizontalRelation::LeftOf(gap) => {
+                horizontal::RightToLeft.align_with_offset(view, anchor, -(gap as i32))
+            }
+            HorizontalRelation::RightOf(gap) => {
+                horizontal::LeftToRight.align_with_offset(view, anchor, gap as i32)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum VerticalRelation {
+    None,
+    Top,
+    Center,
+    Bottom,
+    Above(u32),
+    Below(u32),
+}
+
+impl VerticalRelation {
+    fn align(self, view: Rectangle, anchor: Rectangle) -> i32 {
+        match self {
+            VerticalRelation::None => vertical::NoAlignment.align(view, anchor),
+            VerticalRelation::Top => vertical::Top.align(view, anchor),
+            VerticalRelation::Center => vertical::Center.align(view, anchor),
+            VerticalRelation::Bottom => vertical::Bottom.align(view, anchor),
+            VerticalRelation::Above(gap) => {
+                vertical::BottomToTop.align_with_offset(view, anchor, -(gap as i32))
+            }
+            VerticalRelation::Below(gap) => {
+                vertical::TopToBottom.align_with_offset(view, anchor, gap as i32)
+            }
+        }
+    }
+}
+
+/// Positions a view relative to one of its siblings.
+///
+/// A constraint refers to views by their index in the [`ViewGroup`]. The horizontal and vertical
+/// positions are set independently. A newly created constraint doesn't move the view along either
+/// axis.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Constraint {
+    view: usize,
+    anchor: usize,
+    horizontal: HorizontalRelation,
+    vertical: VerticalRelation,
+}
+
+impl Constraint {
+    /// Creates a new constraint that positions the view at index `view` relative to the view at
+    /// index `anchor`.
+    #[inline]
+    pub const fn new(view: usize, anchor: usize) -> Self {
+        Self {
+            view,
+            anchor,
+            horizontal: HorizontalRelation::None,
+            vertical: VerticalRelation::None,
+        }
+    }
+
+    const fn with_horizontal(self, horizontal: HorizontalRelation) -> Self {
+        Self {
+            view: self.view,
+            anchor: self.anchor,
+            horizontal,
+            vertical: self.vertical,
+        }
+    }
+
+    const fn with_vertical(self, vertical: VerticalRelation) -> Self {
+        Self {
+            view: self.view,
+            anchor: self.anchor,
+            horizontal: self.horizontal,
+            vertical,
+        }
+    }
+
+    /// Aligns the left edges of the view and the anchor.
+    #[inline]
+    pub const fn align_left(self) -> Self {
+        self.with_horizontal(HorizontalRelation::Left)
+    }
+
+    /// Aligns the horizontal centers of the view and the anchor.
+    #[inline]
+    pub const fn center_horizontally(self) -> Self {
+        self.with_horizontal(HorizontalRelation::Center)
+    }
+
+    /// Aligns the right edges of the view and the anchor.
+    #[inline]
+    pub const fn align_right(self) -> Self {
+        self.with_horizontal(HorizontalRelation::Right)
+    }
+
+    /// Places the view to the left of the anchor, leaving `gap` pixels between them.
+    #[inline]
+    pub const fn left_of(self, gap: u32) -> Self {
+        self.with_horizontal(HorizontalRelation::LeftOf(gap))
+    }
+
+    /// Places the view to the right of the anchor, leaving `gap` pixels between them.
+    #[inline]
+    pub const fn right_of(self, gap: u32) -> Self {
+        self.with_horizontal(HorizontalRelation::RightOf(gap))
+    }
+
+    /// Aligns the top edges of the view and the anchor.
+    #[inline]
+    pub const fn align_top(self) -> Self {
+        self.with_vertical(VerticalRelation::Top)
+    }
+
+    /// Aligns the vertical centers of the view and the anchor.
+    #[inline]
+    pub const fn center_vertically(self) -> Self {
+        self.with_vertical(VerticalRelation::Center)
+    }
+
+    /// Aligns the bottom edges of the view and the anchor.
+    #[inline]
+    pub const fn align_bottom(self) -> Self {
+        self.with_vertical(VerticalRelation::Bottom)
+    }
+
+    /// Places the view above the anchor, leaving `gap` pixels between them.
+    #[inline]
+    pub const fn above(self, gap: u32) -> Self {
+        self.with_vertical(VerticalRelation::Above(gap))
+    }
+
+    /// Places the view below the anchor, leaving `gap` pixels between them.
+    #[inline]
+    pub const fn below(self, gap: u32) -> Self {
+        self.with_vertical(VerticalRelation::Below(gap))
+    }
+
+    fn apply(&self, view_group: &mut impl ViewGroup) {
+        let view = view_group.bounds_of(self.view);
+        let anchor = view_group.bounds_of(self.anchor);
+
+        let by = Point::new(
+            self.horizontal.align(view, anchor),
+            self.vertical.align(view, anchor),
+        );
+
+        view_group.translate_child(self.view, by);
+    }
+}
+
+/// `RelativeLayout`
+///
+/// [`RelativeLayout`] positions views relative to their siblings, according to a list of
+/// [`Constraint`]s.
+///
+/// The constraints can be stored in anything that can be viewed as a slice, usually an array.
+///
+/// For more information and examples see the [module level documentation](crate::layout::relative).
+pub struct RelativeLayout<VG, CS> {
+    views: VG,
+    constraints: CS,
+}
+
+impl<VG, CS> RelativeLayout<VG, CS>
+where
+    VG: ViewGroup,
+    CS: AsRef<[Constraint]>,
+{
+    /// Create a new [`RelativeLayout`] that arranges `views` according to `constraints`.
+    #[inline]
+    #[must_use]
+    pub fn new(views: VG, constraints: CS) -> Self {
+        Self { views, constraints }
+    }
+
+    /// Returns a reference to the contained views.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.views
+    }
+
+    /// Returns a mutable reference to the contained views.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.views
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> VG {
+        self.views
+    }
+
+    /// Arrange the views according to the constraints.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        Self::apply_constraints(self.constraints.as_ref(), &mut self.views);
+        self
+    }
+
+    /// Arrange a [`ViewGroup`] according to the constraints of the layout.
+    #[inline]
+    pub fn arrange_view_group(&self, view_group: &mut impl ViewGroup) {
+        Self::apply_constraints(self.constraints.as_ref(), view_group);
+    }
+
+    fn apply_constraints(constraints: &[Constraint], view_group: &mut impl ViewGroup) {
+        for constraint in constraints {
+            constraint.apply(view_group);
+        }
+    }
+}
+
+impl<VG, CS> Clone for RelativeLayout<VG, CS>
+where
+    VG: Clone,
+    CS: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            views: self.views.clone(),
+            constraints: self.constraints.clone(),
+        }
+    }
+}
+
+impl<VG, CS> View for RelativeLayout<VG, CS>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        View::translate_impl(&mut self.views, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        View::bounds(&self.views)
+    }
+}
+
+impl<VG, CS> ViewGroup for RelativeLayout<VG, CS>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.views.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.views.at_mut(idx)
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views.translate_child(idx, by)
+    }
+}
+
+impl<C, VG, CS> DrawableViewGroup<C> for RelativeLayout<VG, CS>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.views.drawable_at(idx)
+    }
+}
+
+impl<C, VG, CS> Drawable for RelativeLayout<VG, CS>
+where
+    C: PixelColor,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn constraints_are_applied_in_order() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+        let small = Rectangle::new(Point::new(5, 5), Size::new(1, 1)).into_styled(style);
+
+        let layout = RelativeLayout::new(
+            Chain::new(rect).append(small).append(rect),
+            [
+                Constraint::new(1, 0).right_of(1).align_bottom(),
+                Constraint::new(2, 1).below(1).center_horizontally(),
+            ],
+        )
+        .arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "##    ", //
+                "## #  ", "      ", "   ## ", "   ## ",
+            ])
+        );
+    }
+
+    #[test]
+    fn left_of_and_above() {
+        let rect = Rectangle::new(Point::new(10, 10), Size::new(3, 3));
+
+        let layout = RelativeLayout::new(
+            Chain::new(rect).append(rect).append(rect),
+            [
+                Constraint::new(1, 0).left_of(2).align_top(),
+                Constraint::new(2, 0).above(0).align_right(),
+            ],
+        )
+        .arrange();
+
+        let views = layout.inner();
+        assert_eq!(Point::new(5, 10), views.bounds_of(1).top_left);
+        assert_eq!(Point::new(10, 7), views.bounds_of(2).top_left);
+        assert_eq!(
+            Rectangle::with_corners(Point::new(5, 7), Point::new(12, 12)),
+            layout.bounds()
+        );
+    }
+
+    #[test]
+    fn unconstrained_axis_is_kept() {
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 3));
+
+        let layout = RelativeLayout::new(
+            Chain::new(rect).append(rect.translate(Point::new(0, 7))),
+            [Constraint::new(1, 0).right_of(0)],
+        )
+        .arrange();
+
+        assert_eq!(Point::new(3, 7), layout.inner().bounds_of(1).top_left);
+    }
+}
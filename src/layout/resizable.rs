@@ -0,0 +1,72 @@
+use embedded_graphics::prelude::Size;
+
+use crate::View;
+
+/// A [`View`] that can be resized by layouts.
+///
+/// Layouts use this trait to stretch views, for example to make every button in a vertical menu
+/// as wide as the widest one using [`horizontal::Stretch`].
+///
+/// To let layouts find the implementation, also override [`View::as_resizable_mut`] to return
+/// `Some(self)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{
+///     layout::{linear::LinearLayout, ResizableView},
+///     prelude::*,
+///     view_group::ViewGroup,
+/// };
+///
+/// struct Button {
+///     bounds: Rectangle,
+/// }
+///
+/// impl View for Button {
+///     fn translate_impl(&mut self, by: Point) {
+///         self.bounds.top_left += by;
+///     }
+///
+///     fn bounds(&self) -> Rectangle {
+///         self.bounds
+///     }
+///
+///     fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+///         Some(self)
+///     }
+/// }
+///
+/// impl ResizableView for Button {
+///     fn resize(&mut self, size: Size) {
+///         self.bounds.size = size;
+///     }
+/// }
+///
+/// let layout = LinearLayout::vertical(
+///     Chain::new(Button { bounds: Rectangle::new(Point::zero(), Size::new(30, 10)) })
+///         .append(Button { bounds: Rectangle::new(Point::zero(), Size::new(50, 10)) }),
+/// )
+/// .with_alignment(horizontal::Stretch)
+/// .arrange();
+///
+/// assert_eq!(Size::new(50, 10), layout.bounds_of(0).size);
+/// ```
+///
+/// [`horizontal::Stretch`]: crate::align::horizontal::Stretch
+pub trait ResizableView: View {
+    /// Changes the size of the view, keeping its top left corner in place.
+    fn resize(&mut self, size: Size);
+
+    /// Returns `true` if layouts should resize the view even if they don't stretch their views.
+    ///
+    /// The default implementation returns `false`. See [`Divider`] for a view that always fills
+    /// the layout.
+    ///
+    /// [`Divider`]: crate::views::Divider
+    #[inline]
+    fn fills_layout(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,460 @@
+//! Split layout
+//!
+//! A split layout divides an area into panes along the horizontal or vertical axis, and aligns one
+//! [`View`] into each pane. The size of a pane is either fixed, a percentage of the whole area, or
+//! an equal share of the space left over by the other panes.
+//!
+//! The first view is placed into the first pane, the second view into the second pane and so on.
+//! Views without a matching pane are not moved.
+//!
+//! # Example
+//!
+//! Create a screen with a 10px tall title bar, a 10px tall status bar and a body that takes up
+//! the rest of the display:
+//!
+//! ```rust
+//! # use embedded_layout::prelude::*;
+//! # use embedded_graphics::{
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     primitives::Rectangle,
+//! #     text::Text,
+//! #     prelude::*,
+//! # };
+//! use embedded_layout::layout::split::{Pane, SplitLayout};
+//!
+//! let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let layout = SplitLayout::vertical(
+//!     display_area,
+//!     Chain::new(Text::new("Title", Point::zero(), text_style))
+//!         .append(Text::new("Body", Point::zero(), text_style))
+//!         .append(Text::new("Status", Point::zero(), text_style)),
+//!     [Pane::Fixed(10), Pane::Fill, Pane::Fixed(10)],
+//! )
+//! .with_alignment(horizontal::Center, vertical::Center)
+//! .arrange();
+//!
+//! assert_eq!(
+//!     Rectangle::new(Point::new(0, 10), Size::new(128, 44)),
+//!     layout.pane_bounds(1)
+//! );
+//! ```
+//!
+//! [`View`]: crate::View
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::{horizontal, vertical, HorizontalAlignment, VerticalAlignment},
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup},
+    View,
+};
+
+/// The size of a pane in a [`SplitLayout`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Pane {
+    /// A pane with the given size, in pixels.
+    Fixed(u32),
+
+    /// A pane that takes up the given percentage of the layout.
+    Percent(u32),
+
+    /// A pane that takes up an equal share of the space not used by `Fixed` and `Percent` panes.
+    Fill,
+}
+
+impl Pane {
+    fn size(self, total_size: u32) -> u32 {
+        match self {
+            Pane::Fixed(size) => size,
+            Pane::Percent(percent) => (total_size as u64 * percent as u64 / 100) as u32,
+            Pane::Fill => 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum SplitDirection {
+    Horizontal,
+    Vertical,
+}
+
+impl SplitDirection {
+    fn primary_size(self, size: Size) -> u32 {
+        match self {
+            SplitDirection::Horizontal => size.width,
+            SplitDirection::Vertical => size.height,
+        }
+    }
+
+    fn pane(self, bounds: Rectangle, offset: u32, size: u32) -> Rectangle {
+        match self {
+            SplitDirection::Horizontal => Rectangle::new(
+                bounds.top_left + Point::new(offset as i32, 0),
+                Size::new(size, bounds.size.height),
+            ),
+            SplitDirection::Vertical => Rectangle::new(
+                bounds.top_left + Point::new(0, offset as i32),
+                Size::new(bounds.size.width, size),
+            ),
+        }
+    }
+}
+
+/// `SplitLayout`
+///
+/// [`SplitLayout`] divides an area into panes and aligns one view into each pane.
+///
+/// The panes can be stored in anything that can be viewed as a slice, usually an array.
+///
+/// For more information and examples see the [module level documentation](crate::layout::split).
+pub struct SplitLayout<VG, PS, H, V> {
+    bounds: Rectangle,
+    direction: SplitDirection,
+    panes: PS,
+    views: VG,
+    horizontal: H,
+    vertical: V,
+}
+
+impl<VG, PS> SplitLayout<VG, PS, horizontal::Left, vertical::Top>
+where
+    VG: ViewGroup,
+    PS: AsRef<[Pane]>,
+{
+    /// Create a new [`SplitLayout`] that places its panes next to each other, from left to right.
+    ///
+    /// By default, views are aligned to the top left corner of their panes.
+    #[inline]
+    #[must_use]
+    pub fn horizontal(bounds: Rectangle, views: VG, panes: PS) -> Self {
+        Self::new(bounds, SplitDirection::Horizontal, views, panes)
+    }
+
+    /// Create a new [`SplitLayout`] that places its panes below each other, from top to bottom.
+    ///
+    /// By default, views are aligned to the top left corner of their panes.
+    #[inline]
+    #[must_use]
+    pub fn vertical(bounds: Rectangle, views: VG, panes: PS) -> Self {
+        Self::new(bounds, SplitDirection::Vertical, views, panes)
+    }
+
+    fn new(bounds: Rectangle, direction: SplitDirection, views: VG, panes: PS) -> Self {
+        Self {
+            bounds,
+            direction,
+            panes,
+            views,
+            horizontal: horizontal::Left,
+            vertical: vertical::Top,
+        }
+    }
+}
+
+impl<VG, PS, H, V> SplitLayout<VG, PS, H, V>
+where
+    VG: ViewGroup,
+    PS: AsRef<[Pane]>,
+    H: HorizontalAlignment,
+    V: VerticalAlignment,
+{
+    /// Change the alignment of the views inside their panes.
+    #[inline]
+    #[must_use]
+    pub fn with_alignment<H2, V2>(self, horizontal: H2, vertical: V2) -> SplitLayout<VG, PS, H2, V2>
+    where
+        H2: HorizontalAlignment,
+        V2: VerticalAlignment,
+    {
+        SplitLayout {
+            bounds: self.bounds,
+            direction: self.direction,
+            panes: self.panes,
+            views: self.views,
+            horizontal,
+            vertical,
+        }
+    }
+
+    /// Returns a reference to the contained views.
+    #[inline]
+    pub fn inner(&self) -> &VG {
+        &self.views
+    }
+
+    /// Returns a mutable reference to the contained views.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut VG {
+        &mut self.views
+    }
+
+    /// Consume the layout object and return the wrapped [`ViewGroup`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> VG {
+        self.views
+    }
+
+    /// Returns the area of the pane at position `idx`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `idx` is not a valid pane index.
+    #[inline]
+    pub fn pane_bounds(&self, idx: usize) -> Rectangle {
+        let panes = self.panes.as_ref();
+        assert!(idx < panes.len(), "Invalid pane index");
+
+        let total_size = self.direction.primary_size(self.bounds.size);
+
+        let mut used = 0u32;
+        let mut fill_panes = 0;
+        for pane in panes {
+            used = used.saturating_add(pane.size(total_size));
+            if *pane == Pane::Fill {
+                fill_panes += 1;
+            }
+        }
+
+        let remaining = total_size.saturating_sub(used);
+
+        let mut offset = 0;
+        let mut fill_idx = 0;
+        for (i, pane) in panes.iter().enumerate() {
+            let size = if *pane == Pane::Fill {
+                fill_idx += 1;
+                // The last fill pane takes up the space lost to rounding.
+                if fill_idx == fill_panes {
+                    remaining - remaining / fill_panes * (fill_panes - 1)
+                } else {
+                    remaining / fill_panes
+                }
+            } else {
+                pane.size(total_size)
+            };
+
+            if i == idx {
+                return self.direction.pane(self.bounds, offset, size);
+            }
+
+            offset = offset.saturating_add(size);
+        }
+
+        unreachable!()
+    }
+
+    /// Align the views into their panes.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        let count = self.views.len().min(self.panes.as_ref().len());
+
+        for idx in 0..count {
+            let pane = self.pane_bounds(idx);
+            let view = self.views.bounds_of(idx);
+
+            let by = Point::new(
+                self.horizontal.align(view, pane),
+                self.vertical.align(view, pane),
+            );
+
+            self.views.translate_child(idx, by);
+        }
+
+        self
+    }
+}
+
+impl<VG, PS, H, V> Clone for SplitLayout<VG, PS, H, V>
+where
+    VG: Clone,
+    PS: Clone,
+    H: Clone,
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bounds: self.bounds,
+            direction: self.direction,
+            panes: self.panes.clone(),
+            views: self.views.clone(),
+            horizontal: self.horizontal.clone(),
+            vertical: self.vertical.clone(),
+        }
+    }
+}
+
+impl<VG, PS, H, V> View for SplitLayout<VG, PS, H, V>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.bounds.top_left += by;
+        View::translate_impl(&mut self.views, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<VG, PS, H, V> ViewGroup for SplitLayout<VG, PS, H, V>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.views.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.views.at_mut(idx)
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views.translate_child(idx, by)
+    }
+}
+
+impl<C, VG, PS, H, V> DrawableViewGroup<C> for SplitLayout<VG, PS, H, V>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.views.drawable_at(idx)
+    }
+}
+
+impl<C, VG, PS, H, V> Drawable for SplitLayout<VG, PS, H, V>
+where
+    C: PixelColor,
+    VG: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.views.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Primitive,
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn pane_sizes() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+        let layout = SplitLayout::horizontal(
+            Rectangle::new(Point::new(1, 2), Size::new(100, 10)),
+            Chain::new(rect),
+            [Pane::Fixed(16), Pane::Fill, Pane::Percent(30), Pane::Fill],
+        );
+
+        assert_eq!(
+            Rectangle::new(Point::new(1, 2), Size::new(16, 10)),
+            layout.pane_bounds(0)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(17, 2), Size::new(27, 10)),
+            layout.pane_bounds(1)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(44, 2), Size::new(30, 10)),
+            layout.pane_bounds(2)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(74, 2), Size::new(27, 10)),
+            layout.pane_bounds(3)
+        );
+    }
+
+    #[test]
+    fn views_are_aligned_into_panes() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 1)).into_styled(style);
+
+        let layout = SplitLayout::vertical(
+            Rectangle::new(Point::zero(), Size::new(4, 6)),
+            Chain::new(rect).append(rect).append(rect),
+            [Pane::Fixed(1), Pane::Fill, Pane::Fixed(1)],
+        )
+        .with_alignment(horizontal::Right, vertical::Center)
+        .arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "  ##", //
+                "    ", "  ##", "    ", "    ", "  ##",
+            ])
+        );
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(4, 6)),
+            layout.bounds()
+        );
+    }
+
+    #[test]
+    fn extra_views_are_not_moved() {
+        let rect = Rectangle::new(Point::new(3, 3), Size::new(1, 1));
+
+        let layout = SplitLayout::horizontal(
+            Rectangle::new(Point::zero(), Size::new(10, 10)),
+            Chain::new(rect).append(rect),
+            [Pane::Fill],
+        )
+        .arrange();
+
+        assert_eq!(Point::zero(), layout.inner().bounds_of(0).top_left);
+        assert_eq!(Point::new(3, 3), layout.inner().bounds_of(1).top_left);
+    }
+}
@@ -0,0 +1,318 @@
+//! Status bar
+//!
+//! A status bar is a fixed height strip at the top or at the bottom of the screen, with three
+//! groups of views: one aligned to the left edge, one to the center and one to the right edge. A
+//! typical status bar shows the time on the left, a title in the center and icons like the battery
+//! level on the right.
+//!
+//! Each group is a [`ViewGroup`] that is arranged horizontally, and is vertically centered in the
+//! strip. Use [`EmptyViewGroup`] for groups that are not needed.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     primitives::Rectangle,
+//! #     text::Text,
+//! # };
+//! use embedded_layout::{layout::status_bar::StatusBar, prelude::*, view_group::EmptyViewGroup};
+//!
+//! let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//!
+//! let mut status_bar = StatusBar::top(
+//!     display_area,
+//!     12,
+//!     Chain::new(Text::new("12:34", Point::zero(), text_style)),
+//!     EmptyViewGroup,
+//!     Chain::new(Text::new("BT", Point::zero(), text_style))
+//!         .append(Text::new("85%", Point::zero(), text_style)),
+//! );
+//!
+//! assert_eq!(Point::new(128 - 18, 1), status_bar.right().object.bounds().top_left);
+//!
+//! // Update the battery level.
+//! status_bar.right_mut().object.text = "100%";
+//! status_bar.rearrange();
+//!
+//! assert_eq!(Point::new(128 - 24, 1), status_bar.right().object.bounds().top_left);
+//! ```
+//!
+//! [`ViewGroup`]: crate::view_group::ViewGroup
+//! [`EmptyViewGroup`]: crate::view_group::EmptyViewGroup
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::{horizontal, vertical, Align, HorizontalAlignment},
+    layout::linear::{FixedMargin, LinearLayout},
+    view_group::{EmptyViewGroup, ViewGroup},
+    View,
+};
+
+/// A strip with a left, a center and a right group of views.
+///
+/// For more information and an example, see the [module level documentation](crate::layout::status_bar).
+pub struct StatusBar<L, M, R> {
+    bounds: Rectangle,
+    spacing: u32,
+    left: L,
+    center: M,
+    right: R,
+}
+
+impl<L, M, R> StatusBar<L, M, R>
+where
+    L: ViewGroup,
+    M: ViewGroup,
+    R: ViewGroup,
+{
+    /// Creates a status bar of the given height at the top of `area`.
+    ///
+    /// The default spacing between the views of a group is 2px.
+    #[inline]
+    #[must_use]
+    pub fn top(area: Rectangle, height: u32, left: L, center: M, right: R) -> Self {
+        let bounds = Rectangle::new(area.top_left, Size::new(area.size.width, height));
+
+        Self::new(bounds, left, center, right)
+    }
+
+    /// Creates a status bar of the given height at the bottom of `area`.
+    ///
+    /// The default spacing between the views of a group is 2px.
+    #[inline]
+    #[must_use]
+    pub fn bottom(area: Rectangle, height: u32, left: L, center: M, right: R) -> Self {
+        let top = area.top_left.y + area.size.height as i32 - height as i32;
+        let bounds = Rectangle::new(
+            Point::new(area.top_left.x, top),
+            Size::new(area.size.width, height),
+        );
+
+        Self::new(bounds, left, center, right)
+    }
+
+    fn new(bounds: Rectangle, left: L, center: M, right: R) -> Self {
+        let mut status_bar = Self {
+            bounds,
+            spacing: 2,
+            left,
+            center,
+            right,
+        };
+        status_bar.rearrange();
+        status_bar
+    }
+
+    /// Sets the space between the views of a group.
+    #[inline]
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self.rearrange();
+        self
+    }
+
+    /// Arranges the groups again.
+    ///
+    /// Call this method after changing the size of some of the views, e.g. after changing a text.
+    #[inline]
+    pub fn rearrange(&mut self) {
+        let (bounds, spacing) = (self.bounds, self.spacing);
+
+        arrange_group(&mut self.left, bounds, spacing, horizontal::Left);
+        arrange_group(&mut self.center, bounds, spacing, horizontal::Center);
+        arrange_group(&mut self.right, bounds, spacing, horizontal::Right);
+    }
+
+    /// Returns a reference to the left group.
+    #[inline]
+    pub fn left(&self) -> &L {
+        &self.left
+    }
+
+    /// Returns a mutable reference to the left group.
+    #[inline]
+    pub fn left_mut(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Returns a reference to the center group.
+    #[inline]
+    pub fn center(&self) -> &M {
+        &self.center
+    }
+
+    /// Returns a mutable reference to the center group.
+    #[inline]
+    pub fn center_mut(&mut self) -> &mut M {
+        &mut self.center
+    }
+
+    /// Returns a reference to the right group.
+    #[inline]
+    pub fn right(&self) -> &R {
+        &self.right
+    }
+
+    /// Returns a mutable reference to the right group.
+    #[inline]
+    pub fn right_mut(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Consumes the status bar and returns the left, center and right groups.
+    #[inline]
+    pub fn into_inner(self) -> (L, M, R) {
+        (self.left, self.center, self.right)
+    }
+}
+
+/// Places the views of `group` in a row, and aligns the row in the strip.
+fn arrange_group(
+    group: &mut impl ViewGroup,
+    bounds: Rectangle,
+    spacing: u32,
+    alignment: impl HorizontalAlignment,
+) {
+    LinearLayout::horizontal(EmptyViewGroup)
+        .with_alignment(vertical::Center)
+        .with_spacing(FixedMargin(spacing as i32))
+        .arrange_in_place(group);
+
+    group.align_to_mut(&bounds, alignment, vertical::Center);
+}
+
+impl<L, M, R> Clone for StatusBar<L, M, R>
+where
+    L: Clone,
+    M: Clone,
+    R: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bounds: self.bounds,
+            spacing: self.spacing,
+            left: self.left.clone(),
+            center: self.center.clone(),
+            right: self.right.clone(),
+        }
+    }
+}
+
+impl<L, M, R> View for StatusBar<L, M, R>
+where
+    L: ViewGroup,
+    M: ViewGroup,
+    R: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.bounds.top_left += by;
+        View::translate_impl(&mut self.left, by);
+        View::translate_impl(&mut self.center, by);
+        View::translate_impl(&mut self.right, by);
+    }
+
+    /// Returns the area of the strip.
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<C, L, M, R> Drawable for StatusBar<L, M, R>
+where
+    C: PixelColor,
+    L: ViewGroup + Drawable<Color = C>,
+    M: ViewGroup + Drawable<Color = C>,
+    R: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.left.draw(display)?;
+        self.center.draw(display)?;
+        self.right.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Primitive,
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn groups_are_aligned_in_the_strip() {
+        let area = Rectangle::new(Point::new(1, 1), Size::new(20, 10));
+        let small = Rectangle::new(Point::zero(), Size::new(2, 1));
+        let tall = Rectangle::new(Point::zero(), Size::new(2, 3));
+
+        let status_bar = StatusBar::top(
+            area,
+            5,
+            Chain::new(small).append(small),
+            Chain::new(tall),
+            Chain::new(small),
+        )
+        .with_spacing(1);
+
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(20, 5)),
+            status_bar.bounds()
+        );
+        assert_eq!(Point::new(1, 3), status_bar.left().parent.object.top_left);
+        assert_eq!(Point::new(4, 3), status_bar.left().object.top_left);
+        assert_eq!(Point::new(10, 2), status_bar.center().object.top_left);
+        assert_eq!(Point::new(19, 3), status_bar.right().object.top_left);
+
+        let status_bar =
+            StatusBar::bottom(area, 5, EmptyViewGroup, EmptyViewGroup, Chain::new(small));
+        assert_eq!(
+            Rectangle::new(Point::new(1, 6), Size::new(20, 5)),
+            status_bar.bounds()
+        );
+        assert_eq!(Point::new(19, 8), status_bar.right().object.top_left);
+    }
+
+    #[test]
+    fn every_group_is_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let dot = Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style);
+
+        let status_bar = StatusBar::top(
+            Rectangle::new(Point::zero(), Size::new(9, 3)),
+            1,
+            Chain::new(dot).append(dot),
+            Chain::new(dot),
+            Chain::new(dot),
+        )
+        .with_spacing(1);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        status_bar.draw(&mut display).unwrap();
+
+        display.assert_pattern(&["# # #   #"]);
+    }
+}
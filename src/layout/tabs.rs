@@ -0,0 +1,301 @@
+//! Tabs
+//!
+//! A tab layout places a row of tab labels above a content area. Each label belongs to a page of
+//! content, and only the page of the active tab is drawn. The active tab is an index that can be
+//! changed at runtime, so every page keeps its own type.
+//!
+//! The active label is highlighted by selecting it. Wrap the labels in [`Selectable`] to choose
+//! how the active label is drawn.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{
+//! #     mock_display::MockDisplay,
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     text::Text,
+//! # };
+//! use embedded_layout::{
+//!     layout::tabs::Tabs,
+//!     prelude::*,
+//!     views::{InvertIndicator, Selectable},
+//! };
+//!
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//! let label = |text| Selectable::new(Text::new(text, Point::zero(), text_style), InvertIndicator);
+//!
+//! let tabs = Tabs::new(
+//!     Chain::new(label("Wifi")).append(label("Sound")),
+//!     Chain::new(Text::new("Connected", Point::zero(), text_style))
+//!         .append(Text::new("Vol: 80%", Point::zero(), text_style)),
+//! );
+//!
+//! let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+//! display.set_allow_overdraw(true);
+//!
+//! let mut tabs = tabs.align_to(&display.bounding_box(), horizontal::Left, vertical::Top);
+//!
+//! // Switch to the "Sound" tab.
+//! tabs.next_tab();
+//! assert_eq!(1, tabs.tab());
+//!
+//! tabs.draw(&mut display).unwrap();
+//! ```
+//!
+//! [`Selectable`]: crate::views::Selectable
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    layout::{
+        linear::{FixedMargin, LinearLayout},
+        paged::PagedLayout,
+    },
+    prelude::RectExt,
+    view_group::{DrawableViewGroup, EmptyViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// A row of tab labels above the content of the active tab.
+///
+/// For more information and an example, see the [module level documentation](crate::layout::tabs).
+pub struct Tabs<L, P> {
+    labels: L,
+    pages: PagedLayout<P>,
+    spacing: u32,
+}
+
+impl<L, P> Tabs<L, P>
+where
+    L: ViewGroup,
+    P: ViewGroup,
+{
+    /// Creates a new tab layout with the first tab active.
+    ///
+    /// `labels` and `pages` should contain the same number of views. The layout is placed to the
+    /// top left corner of the first label. The default spacing between the labels, and between
+    /// the labels and the content is 2px.
+    #[inline]
+    #[must_use]
+    pub fn new(labels: L, pages: P) -> Self {
+        let position = labels
+            .try_at(0)
+            .map_or(Point::zero(), |l| l.bounds().top_left);
+
+        let mut tabs = Self {
+            labels,
+            pages: PagedLayout::new(pages),
+            spacing: 2,
+        };
+        tabs.arrange(position);
+        tabs.set_tab(0);
+        tabs
+    }
+
+    /// Sets the space between the labels, and between the labels and the content.
+    #[inline]
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self.arrange(self.bounds().top_left);
+        self
+    }
+
+    /// Returns the index of the active tab.
+    #[inline]
+    pub fn tab(&self) -> usize {
+        self.pages.page()
+    }
+
+    /// Makes the tab at position `tab` active.
+    ///
+    /// Indexes past the last page select the last page.
+    #[inline]
+    pub fn set_tab(&mut self, tab: usize) {
+        self.pages.set_page(tab);
+        ViewGroupHelper::select(&mut self.labels, self.pages.page());
+    }
+
+    /// Makes the next tab active, wrapping around after the last tab. Returns the index of the
+    /// new active tab.
+    #[inline]
+    pub fn next_tab(&mut self) -> usize {
+        let tab = self.pages.next_page();
+        ViewGroupHelper::select(&mut self.labels, tab);
+        tab
+    }
+
+    /// Makes the previous tab active, wrapping around before the first tab. Returns the index of
+    /// the new active tab.
+    #[inline]
+    pub fn prev_tab(&mut self) -> usize {
+        let tab = self.pages.prev_page();
+        ViewGroupHelper::select(&mut self.labels, tab);
+        tab
+    }
+
+    /// Returns a reference to the labels.
+    #[inline]
+    pub fn labels(&self) -> &L {
+        &self.labels
+    }
+
+    /// Returns a reference to the pages.
+    #[inline]
+    pub fn pages(&self) -> &P {
+        self.pages.inner()
+    }
+
+    /// Returns a mutable reference to the pages.
+    #[inline]
+    pub fn pages_mut(&mut self) -> &mut P {
+        self.pages.inner_mut()
+    }
+
+    /// Consumes the layout and returns the labels and the pages.
+    #[inline]
+    pub fn into_inner(self) -> (L, P) {
+        (self.labels, self.pages.into_inner())
+    }
+
+    /// Places the labels in a row at `position`, and the pages below them.
+    fn arrange(&mut self, position: Point) {
+        LinearLayout::horizontal(EmptyViewGroup)
+            .with_spacing(FixedMargin(self.spacing as i32))
+            .translate(position)
+            .arrange_in_place(&mut self.labels);
+
+        let label_bottom = View::bounds(&self.labels)
+            .bottom_right()
+            .map_or(position.y, |p| p.y);
+        let content_top_left = Point::new(position.x, label_bottom + 1 + self.spacing as i32);
+
+        let by = content_top_left - self.pages.bounds().top_left;
+        self.pages.translate_impl(by);
+    }
+}
+
+impl<L, P> Clone for Tabs<L, P>
+where
+    L: Clone,
+    P: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            labels: self.labels.clone(),
+            pages: self.pages.clone(),
+            spacing: self.spacing,
+        }
+    }
+}
+
+impl<L, P> View for Tabs<L, P>
+where
+    L: ViewGroup,
+    P: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        View::translate_impl(&mut self.labels, by);
+        self.pages.translate_impl(by);
+    }
+
+    /// Returns the area covered by the labels and the active page.
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        View::bounds(&self.labels).enveloping(&self.pages.bounds())
+    }
+}
+
+impl<C, L, P> Drawable for Tabs<L, P>
+where
+    C: PixelColor,
+    L: ViewGroup + Drawable<Color = C>,
+    P: DrawableViewGroup<C> + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.labels.draw(display)?;
+        self.pages.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        prelude::*,
+        views::{OutlineIndicator, Selectable},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn labels_are_placed_above_content() {
+        let label = Rectangle::new(Point::zero(), Size::new(3, 2));
+        let page = Rectangle::new(Point::new(10, 10), Size::new(4, 4));
+
+        let tabs = Tabs::new(
+            Chain::new(label).append(label),
+            Chain::new(page).append(page),
+        )
+        .with_spacing(1)
+        .translate(Point::new(2, 2));
+
+        assert_eq!(Point::new(6, 2), tabs.labels().bounds_of(1).top_left);
+        assert_eq!(Point::new(2, 5), tabs.pages().bounds_of(1).top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(2, 2), Size::new(7, 7)),
+            tabs.bounds()
+        );
+    }
+
+    #[test]
+    fn only_active_tab_is_highlighted_and_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let label = Selectable::new(
+            Rectangle::new(Point::new(1, 1), Size::new(1, 1)).into_styled(style),
+            OutlineIndicator::new(BinaryColor::On),
+        );
+        let first = Rectangle::new(Point::zero(), Size::new(3, 1)).into_styled(style);
+        let second = Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style);
+
+        let mut tabs = Tabs::new(
+            Chain::new(label.clone()).append(label),
+            Chain::new(first).append(second),
+        );
+        assert_eq!(1, tabs.next_tab());
+        assert_eq!(Some(false), tabs.labels().at(0).is_selected());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        tabs.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "   ###", //
+            " # ###", //
+            "   ###", //
+            "      ", //
+            " #    ", //
+        ]);
+    }
+}
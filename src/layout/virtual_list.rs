@@ -0,0 +1,276 @@
+//! Virtualized list layout
+//!
+//! A virtual list shows a window of a long list of items, without creating a view for every item.
+//! It owns a small pool of item views. When the list is arranged, a binder closure configures the
+//! views of the pool to show the items of the visible window, for example by changing the text of
+//! a label. This way a list of thousands of items only needs as much memory as the items that fit
+//! on the screen.
+//!
+//! The views measure the items: every bound view is placed below the previous one, using its own
+//! height, so items of different heights are supported. Items that don't fit the height of the
+//! list completely are not shown.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{
+//! #     mock_display::MockDisplay,
+//! #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     text::{Baseline, Text},
+//! # };
+//! use embedded_layout::{layout::virtual_list::VirtualList, prelude::*};
+//!
+//! const NAMES: [&str; 4] = ["Zero", "One", "Two", "Three"];
+//!
+//! let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+//! let label = Text::with_baseline("", Point::zero(), text_style, Baseline::Top);
+//!
+//! // A list of 10 000 items, with room for 3 of them on the display.
+//! let mut list = VirtualList::new([label; 3], 10_000, 27, |idx, label: &mut Text<_>| {
+//!     label.text = NAMES[idx % NAMES.len()];
+//! })
+//! .arrange();
+//!
+//! assert_eq!(0..3, list.visible_range());
+//!
+//! assert_eq!(5000..5003, list.scroll_to(5000));
+//! assert_eq!("Zero", list.pool()[0].text);
+//!
+//! let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+//! list.draw(&mut display).unwrap();
+//! ```
+
+use core::ops::Range;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// Shows a window of a long list of items using a fixed pool of `N` reusable views.
+///
+/// For more information and an example, see the
+/// [module level documentation](crate::layout::virtual_list).
+pub struct VirtualList<V, F, const N: usize> {
+    pool: [V; N],
+    bind: F,
+    count: usize,
+    position: Point,
+    height: u32,
+    first: usize,
+    bound: usize,
+}
+
+impl<V, F, const N: usize> VirtualList<V, F, N>
+where
+    V: View,
+    F: FnMut(usize, &mut V),
+{
+    /// Creates a new virtual list.
+    ///
+    /// `count` is the number of items in the list, and `height` is the height of the list in
+    /// pixels. `bind` is called with the index of an item and a view of the pool, and it needs to
+    /// configure the view to show the item.
+    ///
+    /// The list is placed at `(0, 0)` and shows the items from the first one. Call
+    /// [`VirtualList::arrange`] to bind and place the views.
+    #[inline]
+    #[must_use]
+    pub fn new(pool: [V; N], count: usize, height: u32, bind: F) -> Self {
+        Self {
+            pool,
+            bind,
+            count,
+            position: Point::zero(),
+            height,
+            first: 0,
+            bound: 0,
+        }
+    }
+
+    /// Bind and arrange the views of the visible items and return the list.
+    #[inline]
+    #[must_use]
+    pub fn arrange(mut self) -> Self {
+        self.rearrange();
+        self
+    }
+
+    /// Bind and arrange the views of the visible items again, keeping the list at its current
+    /// position, and return the range of visible items.
+    ///
+    /// Call this method after the data of the visible items changed.
+    #[inline]
+    pub fn rearrange(&mut self) -> Range<usize> {
+        let bottom = self.position.y + self.height as i32;
+        let mut y = self.position.y;
+
+        self.bound = 0;
+        for (idx, view) in (self.first..self.count).zip(self.pool.iter_mut()) {
+            (self.bind)(idx, view);
+
+            let bounds = view.bounds();
+            if y + bounds.size.height as i32 > bottom {
+                break;
+            }
+
+            view.translate_impl(Point::new(self.position.x, y) - bounds.top_left);
+            y += bounds.size.height as i32;
+            self.bound += 1;
+        }
+
+        self.visible_range()
+    }
+
+    /// Makes the item at position `first` the first visible item, arranges the list, and returns
+    /// the range of visible items.
+    ///
+    /// Indexes past the last item make the last item the first visible one.
+    #[inline]
+    pub fn scroll_to(&mut self, first: usize) -> Range<usize> {
+        self.first = first.min(self.count.saturating_sub(1));
+        self.rearrange()
+    }
+
+    /// Changes the number of items, arranges the list, and returns the range of visible items.
+    ///
+    /// If the first visible item was removed, the list is scrolled to the last item.
+    #[inline]
+    pub fn set_count(&mut self, count: usize) -> Range<usize> {
+        self.count = count;
+        self.scroll_to(self.first)
+    }
+
+    /// Returns the number of items in the list.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the index of the first visible item.
+    #[inline]
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Returns the range of items that were visible when the list was last arranged.
+    #[inline]
+    pub fn visible_range(&self) -> Range<usize> {
+        self.first..self.first + self.bound
+    }
+
+    /// Returns the views that show the visible items, in order.
+    #[inline]
+    pub fn pool(&self) -> &[V] {
+        &self.pool[..self.bound]
+    }
+
+    /// Consume the layout object and return the pool of views.
+    #[inline]
+    #[must_use]
+    pub fn into_pool(self) -> [V; N] {
+        self.pool
+    }
+}
+
+impl<V, F, const N: usize> Clone for VirtualList<V, F, N>
+where
+    V: Clone,
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            bind: self.bind.clone(),
+            count: self.count,
+            position: self.position,
+            height: self.height,
+            first: self.first,
+            bound: self.bound,
+        }
+    }
+}
+
+impl<V, F, const N: usize> View for VirtualList<V, F, N>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+        for view in self.pool.iter_mut() {
+            view.translate_impl(by);
+        }
+    }
+
+    /// Returns the bounds of the list: the given height, and the width of the widest visible
+    /// item.
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let width = self.pool[..self.bound]
+            .iter()
+            .map(|view| view.bounds().size.width)
+            .max()
+            .unwrap_or(0);
+
+        Rectangle::new(self.position, Size::new(width, self.height))
+    }
+}
+
+impl<C, V, F, const N: usize> Drawable for VirtualList<V, F, N>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for view in self.pool[..self.bound].iter() {
+            view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pool_is_bound_to_visible_items() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 1));
+        let bind = |idx: usize, view: &mut Rectangle| {
+            view.size = Size::new(idx as u32 % 5, 3 + idx as u32 % 2);
+        };
+
+        let mut list = VirtualList::new([rect; 4], 1000, 10, bind)
+            .arrange()
+            .translate(Point::new(1, 1));
+
+        // 3 + 4 + 3 fits, the pool is not used up
+        assert_eq!(0..3, list.visible_range());
+        assert_eq!(Point::new(1, 8), list.pool()[2].top_left);
+        assert_eq!(Size::new(2, 10), list.size());
+
+        assert_eq!(998..1000, list.scroll_to(998));
+        assert_eq!(Size::new(3, 3), list.pool()[0].size);
+        assert_eq!(Point::new(1, 4), list.pool()[1].top_left);
+
+        assert_eq!(999..1000, list.scroll_to(1500));
+
+        assert_eq!(499..500, list.set_count(500));
+    }
+}
@@ -5,17 +5,22 @@
 //! `embedded-layout` consists of three main parts:
 //! - [alignments] that can be used to position two objects relative to one another
 //!   * `horizontal`
-//!     * `NoAlignment`, `Left`, `Right`, `Center`
+//!     * `NoAlignment`, `Left`, `Right`, `Center`, `Stretch`
 //!     * `LeftToRight`, `RightToLeft`
 //!   * `vertical`
-//!     * `NoAlignment`, `Top`, `Bottom`, `Center`
+//!     * `NoAlignment`, `Top`, `Bottom`, `Center`, `Baseline`, `Stretch`
 //!     * `TopToBottom`, `BottomToTop`
 //! - [layouts] that can be used to arrange multiple views
 //!   * `LinearLayout`
+//!   * `RelativeLayout`
+//!   * `SplitLayout`
 //! - [view groups] which are collections of view objects
 //!   * `Chain` to create ad-hoc collections (can hold views of different types)
 //!   * `Views` to create view groups from arrays and slices (can only hold views of a single type)
 //!   * `derive(ViewGroup)` to turn any plain old Rust struct into a view group
+//!   * `view_group_struct!` to declare a struct with named views and implement `ViewGroup` for it
+//!
+//! The [views] module contains ready-made views and wrappers, like `ScrollView`.
 //!
 //! # Views
 //!
@@ -92,11 +97,12 @@
 //! [the `embedded-graphics` simulator]: https://crates.io/crates/embedded-graphics-simulator/0.2.1
 //! [fully qualified syntax]: https://doc.rust-lang.org/book/ch19-03-advanced-traits.html#fully-qualified-syntax-for-disambiguation-calling-methods-with-the-same-name
 //! [`View`]: crate::View
-//! [layouts]: crate::layout
+//! [layouts]: mod@crate::layout
 //! [`LinearLayout`]: crate::layout::linear::LinearLayout
 //! [simulator README]: https://github.com/jamwaffles/embedded-graphics/tree/v0.6/simulator#usage-without-sdl2
 //! [alignments]: crate::align
 //! [view groups]: crate::view_group
+//! [views]: crate::views
 
 #![cfg_attr(not(test), no_std)]
 #![deny(missing_docs)]
@@ -107,19 +113,189 @@ use embedded_graphics::{geometry::Point, prelude::*, primitives::Rectangle};
 
 pub use embedded_layout_macros::ViewGroup;
 
+// `derive(ViewGroup)` refers to this crate by name, which the tests need to resolve.
+#[cfg(test)]
+extern crate self as embedded_layout;
+
+/// Implements the optional [`View`] hooks of a wrapper view by forwarding them to the wrapped view.
+///
+/// Wrappers should use this macro instead of forwarding the hooks by hand, so that a hook added to
+/// [`View`] later is forwarded by every wrapper. Hooks that the wrapper implements itself are
+/// listed after `except`.
+///
+/// - `forward_view_hooks!(field view)` forwards to the `view` field.
+/// - `forward_view_hooks!(fn get, get_mut)` forwards to the view returned by the `get` and
+///   `get_mut` methods, which return `Option<&T>` and `Option<&mut T>`. If these return `None`,
+///   the hooks return their default values.
+macro_rules! forward_view_hooks {
+    (field $field:ident $(; except $($except:ident),+)?) => {
+        forward_view_hooks!(@all (field $field) [$($($except)+)?]);
+    };
+    (fn $get:ident, $get_mut:ident $(; except $($except:ident),+)?) => {
+        forward_view_hooks!(@all (fn $get $get_mut) [$($($except)+)?]);
+    };
+
+    (@all $access:tt $except:tt) => {
+        forward_view_hooks!(@check secondary_alignment_override $access $except);
+        forward_view_hooks!(@check baseline $access $except);
+        forward_view_hooks!(@check anchor $access $except);
+        forward_view_hooks!(@check as_measurable_mut $access $except);
+        forward_view_hooks!(@check as_resizable_mut $access $except);
+        forward_view_hooks!(@check is_selected $access $except);
+        forward_view_hooks!(@check set_selected $access $except);
+        forward_view_hooks!(@check z_index $access $except);
+        forward_view_hooks!(@check as_any $access $except);
+        forward_view_hooks!(@check as_any_mut $access $except);
+    };
+
+    // Skip the hooks that the wrapper implements itself.
+    (@check secondary_alignment_override $access:tt [secondary_alignment_override $($rest:ident)*]) => {};
+    (@check baseline $access:tt [baseline $($rest:ident)*]) => {};
+    (@check anchor $access:tt [anchor $($rest:ident)*]) => {};
+    (@check as_measurable_mut $access:tt [as_measurable_mut $($rest:ident)*]) => {};
+    (@check as_resizable_mut $access:tt [as_resizable_mut $($rest:ident)*]) => {};
+    (@check is_selected $access:tt [is_selected $($rest:ident)*]) => {};
+    (@check set_selected $access:tt [set_selected $($rest:ident)*]) => {};
+    (@check z_index $access:tt [z_index $($rest:ident)*]) => {};
+    (@check as_any $access:tt [as_any $($rest:ident)*]) => {};
+    (@check as_any_mut $access:tt [as_any_mut $($rest:ident)*]) => {};
+    (@check $hook:ident $access:tt [$other:ident $($rest:ident)*]) => {
+        forward_view_hooks!(@check $hook $access [$($rest)*]);
+    };
+    (@check $hook:ident $access:tt []) => {
+        forward_view_hooks!(@hook $hook $access);
+    };
+
+    (@hook secondary_alignment_override $access:tt) => {
+        #[inline]
+        fn secondary_alignment_override(
+            &self,
+            reference: embedded_graphics::primitives::Rectangle,
+        ) -> Option<i32> {
+            forward_view_hooks!(@option self $access |view| view.secondary_alignment_override(reference))
+        }
+    };
+    (@hook baseline $access:tt) => {
+        #[inline]
+        fn baseline(&self) -> Option<i32> {
+            forward_view_hooks!(@option self $access |view| view.baseline())
+        }
+    };
+    (@hook anchor $access:tt) => {
+        #[inline]
+        fn anchor(&self) -> Option<embedded_graphics::geometry::Point> {
+            forward_view_hooks!(@option self $access |view| view.anchor())
+        }
+    };
+    (@hook as_measurable_mut $access:tt) => {
+        #[inline]
+        fn as_measurable_mut(&mut self) -> Option<&mut dyn $crate::layout::MeasurableView> {
+            forward_view_hooks!(@option_mut self $access |view| view.as_measurable_mut())
+        }
+    };
+    (@hook as_resizable_mut $access:tt) => {
+        #[inline]
+        fn as_resizable_mut(&mut self) -> Option<&mut dyn $crate::layout::ResizableView> {
+            forward_view_hooks!(@option_mut self $access |view| view.as_resizable_mut())
+        }
+    };
+    (@hook is_selected $access:tt) => {
+        #[inline]
+        fn is_selected(&self) -> Option<bool> {
+            forward_view_hooks!(@option self $access |view| view.is_selected())
+        }
+    };
+    (@hook set_selected $access:tt) => {
+        #[inline]
+        fn set_selected(&mut self, selected: bool) {
+            forward_view_hooks!(@unit_mut self $access |view| view.set_selected(selected))
+        }
+    };
+    (@hook z_index $access:tt) => {
+        #[inline]
+        fn z_index(&self) -> i32 {
+            forward_view_hooks!(@value self $access |view| view.z_index(), 0)
+        }
+    };
+    (@hook as_any $access:tt) => {
+        #[cfg(feature = "any")]
+        #[inline]
+        fn as_any(&self) -> Option<&dyn core::any::Any> {
+            forward_view_hooks!(@option self $access |view| view.as_any())
+        }
+    };
+    (@hook as_any_mut $access:tt) => {
+        #[cfg(feature = "any")]
+        #[inline]
+        fn as_any_mut(&mut self) -> Option<&mut dyn core::any::Any> {
+            forward_view_hooks!(@option_mut self $access |view| view.as_any_mut())
+        }
+    };
+
+    // Access the wrapped view. `self` is passed in because it must come from the method definition.
+    (@option $self:ident (field $field:ident) |$view:ident| $call:expr) => {{
+        let $view = &$self.$field;
+        $call
+    }};
+    (@option $self:ident (fn $get:ident $get_mut:ident) |$view:ident| $call:expr) => {
+        $self.$get().and_then(|$view| $call)
+    };
+    (@option_mut $self:ident (field $field:ident) |$view:ident| $call:expr) => {{
+        let $view = &mut $self.$field;
+        $call
+    }};
+    (@option_mut $self:ident (fn $get:ident $get_mut:ident) |$view:ident| $call:expr) => {
+        $self.$get_mut().and_then(|$view| $call)
+    };
+    (@value $self:ident (field $field:ident) |$view:ident| $call:expr, $default:expr) => {{
+        let $view = &$self.$field;
+        $call
+    }};
+    (@value $self:ident (fn $get:ident $get_mut:ident) |$view:ident| $call:expr, $default:expr) => {
+        $self.$get().map_or($default, |$view| $call)
+    };
+    (@unit_mut $self:ident (field $field:ident) |$view:ident| $call:expr) => {{
+        let $view = &mut $self.$field;
+        $call
+    }};
+    (@unit_mut $self:ident (fn $get:ident $get_mut:ident) |$view:ident| $call:expr) => {
+        if let Some($view) = $self.$get_mut() {
+            $call
+        }
+    };
+}
+
 pub mod align;
 pub mod layout;
 pub mod object_chain;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
 pub mod utils;
 pub mod view_group;
+pub mod views;
+
+/// Re-exports used by the macros of this crate.
+#[doc(hidden)]
+pub mod __private {
+    pub use embedded_graphics::{
+        draw_target::DrawTarget,
+        geometry::{Point, Size},
+        primitives::Rectangle,
+        Drawable,
+    };
+}
 
 /// The essentials. Also contains most of `embedded-graphics'` prelude.
 pub mod prelude {
     pub use crate::{
         align::{horizontal, vertical, Align},
-        chain,
-        object_chain::{Chain, Link},
-        utils::rect_helper::RectExt,
+        chain, chain_views,
+        object_chain::{Chain, IntoChain, Link},
+        utils::{
+            cached::Cached,
+            display_area::DisplayArea,
+            rect_helper::{Insets, RectExt},
+        },
         view_group::Views,
         View,
     };
@@ -131,6 +307,8 @@ pub mod prelude {
 ///
 /// See the `custom_view` example for how you can define more complex views.
 pub trait View {
+    // Hooks with a default implementation must also be added to `forward_view_hooks!`.
+
     /// Get the size of a View.
     #[inline]
     fn size(&self) -> Size {
@@ -168,6 +346,131 @@ pub trait View {
 
     /// Returns the bounding box of the `View` as a `Rectangle`
     fn bounds(&self) -> Rectangle;
+
+    /// Returns the offset that aligns this `View` to `reference` along the secondary axis of a
+    /// layout, if the `View` overrides the layout's secondary alignment.
+    ///
+    /// The default implementation returns `None`. See [`Aligned`] for a `View` that overrides the
+    /// secondary alignment.
+    ///
+    /// [`Aligned`]: crate::views::Aligned
+    #[inline]
+    fn secondary_alignment_override(&self, _reference: Rectangle) -> Option<i32> {
+        None
+    }
+
+    /// Returns the y coordinate of the `View`'s baseline, or `None` if the `View` has no baseline.
+    ///
+    /// The baseline is used by [`vertical::Baseline`] to line up views in a horizontal layout.
+    /// Views without a baseline are treated as if their baseline was their bottom edge.
+    ///
+    /// The default implementation returns `None`. Wrap views that implement [`BaselineView`] in
+    /// [`WithBaseline`] to make their baseline available to layouts.
+    ///
+    /// [`vertical::Baseline`]: crate::align::vertical::Baseline
+    /// [`BaselineView`]: crate::views::BaselineView
+    /// [`WithBaseline`]: crate::views::WithBaseline
+    #[inline]
+    fn baseline(&self) -> Option<i32> {
+        None
+    }
+
+    /// Returns the point that represents the position of the `View` visually, or `None` if the
+    /// `View` is represented by its bounds.
+    ///
+    /// The anchor is used by [`Align::align_anchor_to`] instead of the bounds of the view. For
+    /// example, the anchor of a text is the point it was positioned at, and the anchor of a circle
+    /// is its center.
+    ///
+    /// The default implementation returns `None`. Wrap views that implement [`AnchorView`] in
+    /// [`WithAnchor`] to make their anchor available to alignments.
+    ///
+    /// [`Align::align_anchor_to`]: crate::align::Align::align_anchor_to
+    /// [`AnchorView`]: crate::views::AnchorView
+    /// [`WithAnchor`]: crate::views::WithAnchor
+    #[inline]
+    fn anchor(&self) -> Option<Point> {
+        None
+    }
+
+    /// Returns the `View` as a [`MeasurableView`], if its size depends on the space available to
+    /// it.
+    ///
+    /// The default implementation returns `None`. Views that implement [`MeasurableView`] should
+    /// return `Some(self)`.
+    ///
+    /// [`MeasurableView`]: crate::layout::MeasurableView
+    #[inline]
+    fn as_measurable_mut(&mut self) -> Option<&mut dyn layout::MeasurableView> {
+        None
+    }
+
+    /// Returns the `View` as a [`ResizableView`], if layouts can change its size.
+    ///
+    /// The default implementation returns `None`. Views that implement [`ResizableView`] should
+    /// return `Some(self)`.
+    ///
+    /// [`ResizableView`]: crate::layout::ResizableView
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn layout::ResizableView> {
+        None
+    }
+
+    /// Returns whether the `View` is selected, or `None` if the `View` can't be selected.
+    ///
+    /// The default implementation returns `None`. See [`Selectable`] for a `View` that can be
+    /// selected.
+    ///
+    /// [`Selectable`]: crate::views::Selectable
+    #[inline]
+    fn is_selected(&self) -> Option<bool> {
+        None
+    }
+
+    /// Selects or deselects the `View`.
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn set_selected(&mut self, _selected: bool) {}
+
+    /// Returns the stacking order of the `View` in its view group.
+    ///
+    /// Views with a higher z-index are drawn over views with a lower z-index by
+    /// [`DrawableViewGroup::draw_ordered`]. The default implementation returns `0`. See [`ZOrder`]
+    /// to set the z-index of any view.
+    ///
+    /// [`DrawableViewGroup::draw_ordered`]: crate::view_group::DrawableViewGroup::draw_ordered
+    /// [`ZOrder`]: crate::views::ZOrder
+    #[inline]
+    fn z_index(&self) -> i32 {
+        0
+    }
+
+    /// Returns the `View` as [`Any`], so it can be downcast to its concrete type.
+    ///
+    /// The default implementation returns `None`. Wrap views in [`Downcastable`] to make them
+    /// available for downcasting.
+    ///
+    /// [`Any`]: core::any::Any
+    /// [`Downcastable`]: crate::views::Downcastable
+    #[cfg(feature = "any")]
+    #[inline]
+    fn as_any(&self) -> Option<&dyn core::any::Any> {
+        None
+    }
+
+    /// Returns the `View` as mutable [`Any`], so it can be downcast to its concrete type.
+    ///
+    /// The default implementation returns `None`. Wrap views in [`Downcastable`] to make them
+    /// available for downcasting.
+    ///
+    /// [`Any`]: core::any::Any
+    /// [`Downcastable`]: crate::views::Downcastable
+    #[cfg(feature = "any")]
+    #[inline]
+    fn as_any_mut(&mut self) -> Option<&mut dyn core::any::Any> {
+        None
+    }
 }
 
 impl<T> View for T
@@ -4,6 +4,15 @@
 //! and is built up from any number of `Link`s. This basic structure only allows you
 //! to query the number of elements, but you can implement a more useful trait for both `Link` and
 //! `Chain` to make this structure more useful.
+//!
+//! Objects can be added to either end of a chain using `append` and `prepend`, or inserted at a
+//! given position using `insert_at::<N, _>()`.
+//!
+//! Objects can be accessed by their position using `get::<N>()` or by their type using
+//! `get_by_type::<T>()`, and visitors can be used to inspect or convert every object of a chain,
+//! regardless of their types.
+
+use core::any::Any;
 
 mod private {
     pub trait Sealed {}
@@ -16,6 +25,14 @@ mod private {
 pub trait ChainElement: Sized + private::Sealed {
     /// Return the number of objects linked to this chain element
     fn len(&self) -> usize;
+
+    /// Returns `true` if the chain element contains no objects
+    ///
+    /// Object chains always contain at least one object, so this always returns `false`.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// This piece of the chain contains some object
@@ -43,6 +60,7 @@ where
     V: Clone,
     C: ChainElement + Clone,
 {
+    #[inline]
     fn clone(&self) -> Self {
         Self {
             object: self.object.clone(),
@@ -90,6 +108,7 @@ impl<V> Clone for Chain<V>
 where
     V: Clone,
 {
+    #[inline]
     fn clone(&self) -> Self {
         Self {
             object: self.object.clone(),
@@ -165,6 +184,649 @@ macro_rules! chain {
     };
 }
 
+/// Typed access to the `N`th object of a chain, counted from the first object.
+///
+/// Implemented for chains of up to 12 objects. Use [`Chain::get`] and [`Link::get`] instead of
+/// calling these methods directly.
+pub trait ChainGet<const N: usize>: ChainElement {
+    /// The type of the `N`th object.
+    type Output;
+
+    /// Returns a shared reference to the `N`th object.
+    fn element(&self) -> &Self::Output;
+
+    /// Returns an exclusive reference to the `N`th object.
+    fn element_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<V> ChainGet<0> for Chain<V> {
+    type Output = V;
+
+    #[inline]
+    fn element(&self) -> &V {
+        &self.object
+    }
+
+    #[inline]
+    fn element_mut(&mut self) -> &mut V {
+        &mut self.object
+    }
+}
+
+impl<const N: usize, V, C> ChainGet<N> for Link<V, C>
+where
+    C: ChainGet<N>,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn element(&self) -> &C::Output {
+        self.parent.element()
+    }
+
+    #[inline]
+    fn element_mut(&mut self) -> &mut C::Output {
+        self.parent.element_mut()
+    }
+}
+
+macro_rules! impl_chain_get_last {
+    ($n:literal : $($types:ident),+) => {
+        impl<V, $($types),+> ChainGet<$n> for Link<V, chain! { $($types),+ }> {
+            type Output = V;
+
+            #[inline]
+            fn element(&self) -> &V {
+                &self.object
+            }
+
+            #[inline]
+            fn element_mut(&mut self) -> &mut V {
+                &mut self.object
+            }
+        }
+    };
+}
+
+impl_chain_get_last!(1: A);
+impl_chain_get_last!(2: A, B);
+impl_chain_get_last!(3: A, B, C);
+impl_chain_get_last!(4: A, B, C, D);
+impl_chain_get_last!(5: A, B, C, D, E);
+impl_chain_get_last!(6: A, B, C, D, E, F);
+impl_chain_get_last!(7: A, B, C, D, E, F, G);
+impl_chain_get_last!(8: A, B, C, D, E, F, G, H);
+impl_chain_get_last!(9: A, B, C, D, E, F, G, H, I);
+impl_chain_get_last!(10: A, B, C, D, E, F, G, H, I, J);
+impl_chain_get_last!(11: A, B, C, D, E, F, G, H, I, J, K);
+
+/// Insertion of an object before the first object of a chain.
+///
+/// Use [`Chain::prepend`] and [`Link::prepend`] instead of calling this method directly.
+pub trait ChainPrepend<T>: ChainElement {
+    /// The type of the chain with the inserted object.
+    type Output: ChainElement;
+
+    /// Inserts `item` before the first object.
+    fn prepend_item(self, item: T) -> Self::Output;
+}
+
+impl<V, T> ChainPrepend<T> for Chain<V> {
+    type Output = Link<V, Chain<T>>;
+
+    #[inline]
+    fn prepend_item(self, item: T) -> Self::Output {
+        Chain::new(item).append(self.object)
+    }
+}
+
+impl<V, C, T> ChainPrepend<T> for Link<V, C>
+where
+    C: ChainPrepend<T>,
+{
+    type Output = Link<V, C::Output>;
+
+    #[inline]
+    fn prepend_item(self, item: T) -> Self::Output {
+        Link {
+            object: self.object,
+            parent: self.parent.prepend_item(item),
+        }
+    }
+}
+
+/// Insertion of an object at position `N` of a chain, counted from the first object.
+///
+/// The inserted object is passed as a single-object [`Chain`]. Implemented for chains of up to 12
+/// objects. Use [`Chain::insert_at`] and [`Link::insert_at`] instead of calling this method
+/// directly.
+pub trait ChainInsert<const N: usize, I>: ChainElement {
+    /// The type of the chain with the inserted object.
+    type Output: ChainElement;
+
+    /// Inserts the object of `item` so that it becomes the `N`th object.
+    fn insert_item(self, item: I) -> Self::Output;
+}
+
+impl<V, T> ChainInsert<0, Chain<T>> for Chain<V> {
+    type Output = Link<V, Chain<T>>;
+
+    #[inline]
+    fn insert_item(self, item: Chain<T>) -> Self::Output {
+        item.append(self.object)
+    }
+}
+
+impl<V, T> ChainInsert<1, Chain<T>> for Chain<V> {
+    type Output = Link<T, Self>;
+
+    #[inline]
+    fn insert_item(self, item: Chain<T>) -> Self::Output {
+        self.append(item.object)
+    }
+}
+
+impl<const N: usize, V, C, T> ChainInsert<N, Chain<T>> for Link<V, C>
+where
+    C: ChainInsert<N, Chain<T>>,
+{
+    type Output = Link<V, C::Output>;
+
+    #[inline]
+    fn insert_item(self, item: Chain<T>) -> Self::Output {
+        Link {
+            object: self.object,
+            parent: self.parent.insert_item(item),
+        }
+    }
+}
+
+macro_rules! impl_chain_insert_last {
+    ($n:literal : $($types:ident),+) => {
+        impl<V, T, $($types),+> ChainInsert<$n, Chain<T>> for Link<V, chain! { $($types),+ }> {
+            type Output = Link<T, Self>;
+
+            #[inline]
+            fn insert_item(self, item: Chain<T>) -> Self::Output {
+                self.append(item.object)
+            }
+        }
+    };
+}
+
+impl_chain_insert_last!(2: A);
+impl_chain_insert_last!(3: A, B);
+impl_chain_insert_last!(4: A, B, C);
+impl_chain_insert_last!(5: A, B, C, D);
+impl_chain_insert_last!(6: A, B, C, D, E);
+impl_chain_insert_last!(7: A, B, C, D, E, F);
+impl_chain_insert_last!(8: A, B, C, D, E, F, G);
+impl_chain_insert_last!(9: A, B, C, D, E, F, G, H);
+impl_chain_insert_last!(10: A, B, C, D, E, F, G, H, I);
+impl_chain_insert_last!(11: A, B, C, D, E, F, G, H, I, J);
+impl_chain_insert_last!(12: A, B, C, D, E, F, G, H, I, J, K);
+
+/// Access to the first object of a given type in a chain.
+///
+/// Implemented for chains of objects with `'static` types. Use [`Chain::get_by_type`] and
+/// [`Link::get_by_type`] instead of calling these methods directly.
+pub trait ChainFind: ChainElement {
+    /// Returns a shared reference to the first object of type `T`, or `None` if the chain has no
+    /// such object.
+    fn find<T: 'static>(&self) -> Option<&T>;
+
+    /// Returns an exclusive reference to the first object of type `T`, or `None` if the chain has
+    /// no such object.
+    fn find_mut<T: 'static>(&mut self) -> Option<&mut T>;
+}
+
+impl<V> ChainFind for Chain<V>
+where
+    V: 'static,
+{
+    #[inline]
+    fn find<T: 'static>(&self) -> Option<&T> {
+        (&self.object as &dyn Any).downcast_ref()
+    }
+
+    #[inline]
+    fn find_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        (&mut self.object as &mut dyn Any).downcast_mut()
+    }
+}
+
+impl<V, C> ChainFind for Link<V, C>
+where
+    V: 'static,
+    C: ChainFind,
+{
+    #[inline]
+    fn find<T: 'static>(&self) -> Option<&T> {
+        match self.parent.find() {
+            Some(object) => Some(object),
+            None => (&self.object as &dyn Any).downcast_ref(),
+        }
+    }
+
+    #[inline]
+    fn find_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        if self.parent.find::<T>().is_some() {
+            self.parent.find_mut()
+        } else {
+            (&mut self.object as &mut dyn Any).downcast_mut()
+        }
+    }
+}
+
+/// Visits objects of type `T` in an object chain.
+///
+/// Implement this trait for every type in the chain, usually by a generic implementation, and
+/// pass the visitor to [`Chain::for_each`] or [`Link::for_each`].
+pub trait ChainVisitor<T> {
+    /// Called for an object of the chain.
+    fn visit(&mut self, object: &T);
+}
+
+/// Visits and modifies objects of type `T` in an object chain.
+///
+/// See [`Chain::for_each_mut`] and [`Link::for_each_mut`].
+pub trait ChainVisitorMut<T> {
+    /// Called for an object of the chain.
+    fn visit_mut(&mut self, object: &mut T);
+}
+
+/// Converts objects of type `T` in an object chain.
+///
+/// See [`Chain::map`] and [`Link::map`].
+pub trait ChainMapper<T> {
+    /// The type of the converted object.
+    type Output;
+
+    /// Converts an object of the chain.
+    fn map(&mut self, object: T) -> Self::Output;
+}
+
+/// Calls a [`ChainVisitor`] for each object of a chain.
+pub trait ChainForEach<Vis>: ChainElement {
+    /// Calls `visitor` for each object, starting with the first one.
+    fn visit_all(&self, visitor: &mut Vis);
+}
+
+impl<Vis, V> ChainForEach<Vis> for Chain<V>
+where
+    Vis: ChainVisitor<V>,
+{
+    #[inline]
+    fn visit_all(&self, visitor: &mut Vis) {
+        visitor.visit(&self.object);
+    }
+}
+
+impl<Vis, V, C> ChainForEach<Vis> for Link<V, C>
+where
+    Vis: ChainVisitor<V>,
+    C: ChainForEach<Vis>,
+{
+    #[inline]
+    fn visit_all(&self, visitor: &mut Vis) {
+        self.parent.visit_all(visitor);
+        visitor.visit(&self.object);
+    }
+}
+
+/// Calls a [`ChainVisitorMut`] for each object of a chain.
+pub trait ChainForEachMut<Vis>: ChainElement {
+    /// Calls `visitor` for each object, starting with the first one.
+    fn visit_all_mut(&mut self, visitor: &mut Vis);
+}
+
+impl<Vis, V> ChainForEachMut<Vis> for Chain<V>
+where
+    Vis: ChainVisitorMut<V>,
+{
+    #[inline]
+    fn visit_all_mut(&mut self, visitor: &mut Vis) {
+        visitor.visit_mut(&mut self.object);
+    }
+}
+
+impl<Vis, V, C> ChainForEachMut<Vis> for Link<V, C>
+where
+    Vis: ChainVisitorMut<V>,
+    C: ChainForEachMut<Vis>,
+{
+    #[inline]
+    fn visit_all_mut(&mut self, visitor: &mut Vis) {
+        self.parent.visit_all_mut(visitor);
+        visitor.visit_mut(&mut self.object);
+    }
+}
+
+/// Converts every object of a chain using a [`ChainMapper`].
+pub trait ChainMap<M>: ChainElement {
+    /// The type of the converted chain.
+    type Output: ChainElement;
+
+    /// Converts each object, starting with the first one.
+    fn map_all(self, mapper: &mut M) -> Self::Output;
+}
+
+impl<M, V> ChainMap<M> for Chain<V>
+where
+    M: ChainMapper<V>,
+{
+    type Output = Chain<M::Output>;
+
+    #[inline]
+    fn map_all(self, mapper: &mut M) -> Self::Output {
+        Chain::new(mapper.map(self.object))
+    }
+}
+
+impl<M, V, C> ChainMap<M> for Link<V, C>
+where
+    M: ChainMapper<V>,
+    C: ChainMap<M>,
+{
+    type Output = Link<M::Output, C::Output>;
+
+    #[inline]
+    fn map_all(self, mapper: &mut M) -> Self::Output {
+        let parent = self.parent.map_all(mapper);
+
+        Link {
+            object: mapper.map(self.object),
+            parent,
+        }
+    }
+}
+
+macro_rules! impl_chain_access {
+    () => {
+        /// Returns a shared reference to the `N`th object of the chain, counted from the first
+        /// object.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use embedded_layout::prelude::*;
+        ///
+        /// let chain = chain_views![1u8, "two", 3u32];
+        ///
+        /// assert_eq!(&"two", chain.get::<1>());
+        /// ```
+        #[inline]
+        pub fn get<const N: usize>(&self) -> &<Self as ChainGet<N>>::Output
+        where
+            Self: ChainGet<N>,
+        {
+            self.element()
+        }
+
+        /// Returns an exclusive reference to the `N`th object of the chain, counted from the first
+        /// object.
+        #[inline]
+        pub fn get_mut<const N: usize>(&mut self) -> &mut <Self as ChainGet<N>>::Output
+        where
+            Self: ChainGet<N>,
+        {
+            self.element_mut()
+        }
+
+        /// Inserts an object before the first object of the chain.
+        ///
+        /// The inserted object is drawn first, and it is the first object arranged by layouts.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use embedded_layout::prelude::*;
+        ///
+        /// let chain: chain! { u8, u16, u32 } = Chain::new(2u32).prepend(1u16).prepend(0u8);
+        ///
+        /// assert_eq!(&0u8, chain.get::<0>());
+        /// ```
+        #[inline]
+        pub fn prepend<T>(self, item: T) -> <Self as ChainPrepend<T>>::Output
+        where
+            Self: ChainPrepend<T>,
+        {
+            self.prepend_item(item)
+        }
+
+        /// Inserts an object so that it becomes the `N`th object of the chain, counted from the
+        /// first object.
+        ///
+        /// `insert_at::<0, _>` is equivalent to [`prepend`](Self::prepend), and inserting at the
+        /// length of the chain is equivalent to `append`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use embedded_layout::prelude::*;
+        ///
+        /// let chain: chain! { u8, u16, u32 } = chain_views![0u8, 2u32].insert_at::<1, _>(1u16);
+        ///
+        /// assert_eq!(&1u16, chain.get::<1>());
+        /// ```
+        #[inline]
+        pub fn insert_at<const N: usize, T>(
+            self,
+            item: T,
+        ) -> <Self as ChainInsert<N, Chain<T>>>::Output
+        where
+            Self: ChainInsert<N, Chain<T>>,
+        {
+            self.insert_item(Chain::new(item))
+        }
+
+        /// Returns a shared reference to the first object of type `T`, or `None` if the chain has
+        /// no such object.
+        ///
+        /// The type is matched exactly, so this method can be used instead of navigating the
+        /// `parent` fields of an arranged chain. Objects must have `'static` types.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// # use embedded_graphics::{
+        /// #     prelude::*,
+        /// #     primitives::{Circle, Rectangle},
+        /// # };
+        /// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+        ///
+        /// let views = LinearLayout::horizontal(chain_views![
+        ///     Rectangle::new(Point::zero(), Size::new(5, 5)),
+        ///     Circle::new(Point::zero(), 5),
+        ///     Rectangle::new(Point::zero(), Size::new(3, 3)),
+        /// ])
+        /// .arrange()
+        /// .into_inner();
+        ///
+        /// assert_eq!(Some(Point::new(5, 0)), views.get_by_type::<Circle>().map(|c| c.top_left));
+        /// assert_eq!(Some(Size::new(5, 5)), views.get_by_type::<Rectangle>().map(|r| r.size));
+        /// assert!(views.get_by_type::<u8>().is_none());
+        /// ```
+        #[inline]
+        pub fn get_by_type<T: 'static>(&self) -> Option<&T>
+        where
+            Self: ChainFind,
+        {
+            self.find()
+        }
+
+        /// Returns an exclusive reference to the first object of type `T`, or `None` if the chain
+        /// has no such object.
+        #[inline]
+        pub fn get_by_type_mut<T: 'static>(&mut self) -> Option<&mut T>
+        where
+            Self: ChainFind,
+        {
+            self.find_mut()
+        }
+
+        /// Calls `visitor` for each object of the chain, starting with the first one.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// # use embedded_graphics::{
+        /// #     prelude::*,
+        /// #     primitives::{Circle, Rectangle},
+        /// # };
+        /// use embedded_layout::{object_chain::ChainVisitor, prelude::*};
+        ///
+        /// struct TotalWidth(u32);
+        ///
+        /// impl<T: View> ChainVisitor<T> for TotalWidth {
+        ///     fn visit(&mut self, object: &T) {
+        ///         self.0 += object.size().width;
+        ///     }
+        /// }
+        ///
+        /// let chain = chain_views![
+        ///     Circle::new(Point::zero(), 5),
+        ///     Rectangle::new(Point::zero(), Size::new(3, 3)),
+        /// ];
+        ///
+        /// let mut total = TotalWidth(0);
+        /// chain.for_each(&mut total);
+        /// assert_eq!(8, total.0);
+        /// ```
+        #[inline]
+        pub fn for_each<Vis>(&self, visitor: &mut Vis)
+        where
+            Self: ChainForEach<Vis>,
+        {
+            self.visit_all(visitor)
+        }
+
+        /// Calls `visitor` for each object of the chain, starting with the first one, and lets
+        /// the visitor modify the objects.
+        #[inline]
+        pub fn for_each_mut<Vis>(&mut self, visitor: &mut Vis)
+        where
+            Self: ChainForEachMut<Vis>,
+        {
+            self.visit_all_mut(visitor)
+        }
+
+        /// Converts every object of the chain using `mapper`.
+        #[inline]
+        pub fn map<M>(self, mapper: &mut M) -> <Self as ChainMap<M>>::Output
+        where
+            Self: ChainMap<M>,
+        {
+            self.map_all(mapper)
+        }
+    };
+}
+
+impl<V> Chain<V> {
+    impl_chain_access!();
+}
+
+impl<V, C: ChainElement> Link<V, C> {
+    impl_chain_access!();
+}
+
+/// Creates an object chain from the argument values.
+///
+/// This is the value counterpart of [`chain!`]: the objects are listed in the same order as the
+/// types in [`chain!`].
+///
+/// # Example:
+///
+/// Instead of writing this...
+///
+/// ```rust
+/// use embedded_layout::prelude::*;
+/// use embedded_graphics::{prelude::*, primitives::{Circle, Rectangle}};
+///
+/// let views = Chain::new(Circle::new(Point::zero(), 5))
+///     .append(Rectangle::new(Point::zero(), Size::new(5, 5)));
+/// ```
+///
+/// ... the `chain_views!` macro allows you to write this:
+///
+/// ```rust
+/// use embedded_layout::prelude::*;
+/// use embedded_graphics::{prelude::*, primitives::{Circle, Rectangle}};
+///
+/// let views: chain! { Circle, Rectangle } = chain_views![
+///     Circle::new(Point::zero(), 5),
+///     Rectangle::new(Point::zero(), Size::new(5, 5)),
+/// ];
+/// ```
+///
+/// [`chain!`]: crate::chain
+#[macro_export]
+macro_rules! chain_views {
+    ( $first:expr $(, $rest:expr)* $(,)? ) => {
+        $crate::object_chain::Chain::new($first) $(.append($rest))*
+    };
+}
+
+/// Converts a tuple into an object chain.
+///
+/// Implemented for tuples of up to 12 elements. The first element of the tuple becomes the first
+/// element of the chain.
+///
+/// `View` and `ViewGroup` can't be implemented for tuples directly, but converting a tuple is a
+/// short way to write a heterogeneous view group without nested `append` calls.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     prelude::*,
+/// #     primitives::{Circle, Rectangle, Triangle},
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+///
+/// let triangle = Triangle::new(Point::new(0, 0), Point::new(4, 0), Point::new(2, 4));
+/// let circle = Circle::new(Point::zero(), 5);
+/// let rectangle = Rectangle::new(Point::zero(), Size::new(5, 5));
+///
+/// let layout = LinearLayout::horizontal((triangle, circle, rectangle).into_chain()).arrange();
+///
+/// let _: &chain! { Triangle, Circle, Rectangle } = layout.inner();
+/// assert_eq!(Size::new(15, 5), layout.size());
+/// ```
+pub trait IntoChain {
+    /// The type of the resulting object chain.
+    type Chain: ChainElement;
+
+    /// Converts `self` into an object chain.
+    fn into_chain(self) -> Self::Chain;
+}
+
+macro_rules! impl_into_chain {
+    ($first:ident : $first_idx:tt $(, $name:ident : $idx:tt)*) => {
+        impl<$first $(, $name)*> IntoChain for ($first, $($name,)*) {
+            type Chain = chain! { $first $(, $name)* };
+
+            #[inline]
+            fn into_chain(self) -> Self::Chain {
+                Chain::new(self.$first_idx) $(.append(self.$idx))*
+            }
+        }
+    };
+}
+
+impl_into_chain!(A: 0);
+impl_into_chain!(A: 0, B: 1);
+impl_into_chain!(A: 0, B: 1, C: 2);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+impl_into_chain!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
 #[cfg(test)]
 mod test {
     #![allow(dead_code)]
@@ -200,6 +862,109 @@ mod test {
         f(&test.chain);
     }
 
+    #[test]
+    pub fn typed_access() {
+        let mut chain = chain_views![0u8, 1u16, 2u32, 3u64];
+
+        assert_eq!(&0u8, chain.get::<0>());
+        assert_eq!(&1u16, chain.get::<1>());
+        assert_eq!(&2u32, chain.get::<2>());
+        assert_eq!(&3u64, chain.get::<3>());
+
+        *chain.get_mut::<1>() = 5;
+        assert_eq!(5, chain.parent.parent.object);
+    }
+
+    #[test]
+    pub fn insertion() {
+        let chain: chain! { u8, u16, u64 } = Chain::new(1u16).prepend(0u8).append(3u64);
+        let chain: chain! { u8, u16, u32, u64 } = chain.insert_at::<2, _>(2u32);
+        assert_eq!(&2u32, chain.get::<2>());
+
+        let chain: chain! { i8, u8, u16, u32, u64, i16 } =
+            chain.insert_at::<0, _>(-1i8).insert_at::<5, _>(-2i16);
+        assert_eq!(&-1i8, chain.get::<0>());
+        assert_eq!(&-2i16, chain.get::<5>());
+        assert_eq!(&0u8, chain.get::<1>());
+    }
+
+    #[test]
+    pub fn access_by_type_finds_first_match() {
+        let mut chain = chain_views![0u8, 1u16, 2u8, 3u32];
+
+        assert_eq!(Some(&0u8), chain.get_by_type::<u8>());
+        assert_eq!(Some(&3u32), chain.get_by_type::<u32>());
+        assert_eq!(None, chain.get_by_type::<u64>());
+
+        *chain.get_by_type_mut::<u8>().unwrap() = 5;
+        assert_eq!(5, chain.parent.parent.parent.object);
+        assert_eq!(2, chain.parent.object);
+    }
+
+    struct Sum(u64);
+
+    impl<T: Copy + Into<u64>> ChainVisitor<T> for Sum {
+        fn visit(&mut self, object: &T) {
+            self.0 += (*object).into();
+        }
+    }
+
+    struct Double;
+
+    impl<T: Copy + core::ops::AddAssign> ChainVisitorMut<T> for Double {
+        fn visit_mut(&mut self, object: &mut T) {
+            *object += *object;
+        }
+    }
+
+    struct Widen;
+
+    impl<T: Into<u64>> ChainMapper<T> for Widen {
+        type Output = u64;
+
+        fn map(&mut self, object: T) -> u64 {
+            object.into()
+        }
+    }
+
+    #[test]
+    pub fn visit_and_map() {
+        let mut chain = chain_views![1u8, 2u16, 3u32];
+
+        let mut sum = Sum(0);
+        chain.for_each(&mut sum);
+        assert_eq!(6, sum.0);
+
+        chain.for_each_mut(&mut Double);
+        let widened: chain! { u64, u64, u64 } = chain.map(&mut Widen);
+        assert_eq!(&2, widened.get::<0>());
+        assert_eq!(&6, widened.get::<2>());
+    }
+
+    #[test]
+    pub fn chain_views_macro() {
+        let chain: chain! { u8, u16, u32 } = chain_views![0u8, 1u16, 2u32,];
+
+        assert_eq!(3, chain.len());
+        assert_eq!(0, chain.parent.parent.object);
+        assert_eq!(2, chain.object);
+
+        let single: Chain<u8> = chain_views![5u8];
+        assert_eq!(5, single.object);
+    }
+
+    #[test]
+    pub fn tuple_into_chain() {
+        let chain: chain! { u8, u16, u32 } = (0u8, 1u16, 2u32).into_chain();
+
+        assert_eq!(3, chain.len());
+        assert_eq!(0, chain.parent.parent.object);
+        assert_eq!(2, chain.object);
+
+        let single: Chain<u8> = (5u8,).into_chain();
+        assert_eq!(5, single.object);
+    }
+
     #[test]
     pub fn test_count() {
         assert_eq!(1, Chain::new(0).len());
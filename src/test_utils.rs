@@ -0,0 +1,200 @@
+//! Helpers for regression-testing layouts.
+//!
+//! This module is only available with the `test_utils` feature. It provides the tools this crate
+//! uses in its own tests, so that applications can test their screens the same way: render views
+//! into a [`MockDisplay`], print the rendered image as a pattern that can be pasted into
+//! [`MockDisplay::assert_pattern`], and check where the children of a view group were placed.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{
+//! #     pixelcolor::BinaryColor,
+//! #     prelude::*,
+//! #     primitives::{PrimitiveStyle, Rectangle},
+//! # };
+//! use embedded_layout::{
+//!     layout::linear::{FixedMargin, LinearLayout},
+//!     prelude::*,
+//!     test_utils::{assert_child_bounds, render, Pattern},
+//! };
+//!
+//! let style = PrimitiveStyle::with_fill(BinaryColor::On);
+//! let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+//!
+//! let layout = LinearLayout::horizontal(Chain::new(rect).append(rect))
+//!     .with_spacing(FixedMargin(1))
+//!     .arrange();
+//!
+//! assert_child_bounds(
+//!     layout.inner(),
+//!     &[
+//!         Rectangle::new(Point::new(0, 0), Size::new(2, 2)),
+//!         Rectangle::new(Point::new(3, 0), Size::new(2, 2)),
+//!     ],
+//! );
+//!
+//! let display = render(&layout);
+//! display.assert_pattern(&[
+//!     "## ##", //
+//!     "## ##", //
+//! ]);
+//!
+//! // Print the pattern to update the expected output
+//! println!("{}", Pattern(&display));
+//! ```
+
+use core::fmt::{self, Write};
+
+use embedded_graphics::{
+    mock_display::{ColorMapping, MockDisplay},
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::view_group::ViewGroup;
+
+/// Draws `view` into a new [`MockDisplay`] and returns the display.
+///
+/// Overdrawing pixels is allowed, so overlapping views, e.g. a background and its content, can
+/// be rendered.
+///
+/// # Panics
+///
+/// This function panics if drawing fails, e.g. if a pixel is drawn outside of the display.
+#[inline]
+pub fn render<V>(view: &V) -> MockDisplay<V::Color>
+where
+    V: Drawable,
+    V::Color: PixelColor,
+{
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+
+    if view.draw(&mut display).is_err() {
+        panic!("Failed to draw view");
+    }
+
+    display
+}
+
+/// Formats the content of a [`MockDisplay`] as a pattern.
+///
+/// The output contains one string literal per row, in the format used by
+/// [`MockDisplay::assert_pattern`], so it can be pasted into a test. Rows and columns are included
+/// from the origin to the bottom right corner of the drawn area.
+pub struct Pattern<'a, C>(pub &'a MockDisplay<C>)
+where
+    C: PixelColor;
+
+impl<C> fmt::Display for Pattern<'_, C>
+where
+    C: PixelColor + ColorMapping,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let area = self.0.affected_area();
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+
+        let bottom_right = area.top_left + area.size - Point::new(1, 1);
+        for y in 0..=bottom_right.y {
+            f.write_char('"')?;
+            for x in 0..=bottom_right.x {
+                let color = self.0.get_pixel(Point::new(x, y));
+                f.write_char(color.map_or(' ', C::color_to_char))?;
+            }
+            f.write_str("\", //\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the bounds of every child of a view group.
+///
+/// # Panics
+///
+/// This function panics if the number of children differs from the number of expected bounds,
+/// or if the bounds of a child differ from the expected ones. The message contains the index and
+/// both bounds of the first mismatching child.
+#[inline]
+#[track_caller]
+pub fn assert_child_bounds(view_group: &impl ViewGroup, expected: &[Rectangle]) {
+    assert_eq!(
+        expected.len(),
+        view_group.len(),
+        "The view group has {} children instead of {}",
+        view_group.len(),
+        expected.len()
+    );
+
+    for (idx, expected) in expected.iter().enumerate() {
+        let actual = view_group.bounds_of(idx);
+        assert_eq!(
+            *expected, actual,
+            "Child {} is at {:?} instead of {:?}",
+            idx, actual, expected
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    struct Output {
+        buffer: [u8; 64],
+        len: usize,
+    }
+
+    impl Write for Output {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.buffer
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn pattern_starts_at_origin() {
+        let on = Rectangle::new(Point::new(1, 1), Size::new(2, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+        let off = Rectangle::new(Point::new(1, 2), Size::new(1, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off));
+
+        let display = render(&Chain::new(on).append(off));
+
+        let mut output = Output {
+            buffer: [0; 64],
+            len: 0,
+        };
+        write!(output, "{}", Pattern(&display)).unwrap();
+
+        assert_eq!(
+            "\"   \", //\n\" ##\", //\n\" . \", //\n",
+            core::str::from_utf8(&output.buffer[..output.len]).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Child 1 is at")]
+    fn mismatching_child_is_reported() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let layout = LinearLayout::vertical(Chain::new(rect).append(rect)).arrange();
+
+        assert_child_bounds(layout.inner(), &[rect, rect]);
+    }
+}
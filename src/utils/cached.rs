@@ -0,0 +1,369 @@
+//! Bounds caching
+//!
+//! Computing the bounds of some views, like `Text` or complex view groups, is expensive. Layouts
+//! and alignment operations query the bounds of views often, so wrapping these views in a
+//! [`Cached`] wrapper can speed up these operations.
+use core::cell::Cell;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    layout::{MeasurableView, ResizableView},
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup},
+    View,
+};
+
+/// Caches the bounds of the wrapped view.
+///
+/// The bounds are computed when they are first needed, and are kept up to date when the view is
+/// translated. Changing the view in other ways requires computing the bounds again, so mutable
+/// access to the view is only given through [`Cached::modify`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let mut layout = LinearLayout::vertical(
+///     Chain::new(Cached::new(Text::new("Hello,", Point::zero(), text_style)))
+///         .append(Cached::new(Text::new("World!", Point::zero(), text_style))),
+/// )
+/// .arrange();
+///
+/// // Change the text. The bounds of the modified view will be computed again when needed.
+/// layout
+///     .inner_mut()
+///     .object
+///     .modify(|text| text.text = "Bye!");
+/// ```
+///
+/// # Caching the bounds of view groups
+///
+/// The bounds of a view group are computed from the bounds of every view in it, so aligning a
+/// large view group repeatedly scans all of its views. If the wrapped view is a view group,
+/// `Cached` also implements [`ViewGroup`]. The cached bounds are kept when the whole group is
+/// translated, and are computed again after one of its views is accessed mutably or translated,
+/// e.g. by arranging the group.
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+///
+/// let mut cells = [Rectangle::new(Point::zero(), Size::new(5, 5)); 50];
+/// let display_area = Rectangle::new(Point::zero(), Size::new(320, 240));
+///
+/// // The views are only scanned once, when the layout is first aligned.
+/// let row = Cached::new(LinearLayout::horizontal(Views::new(&mut cells)).arrange())
+///     .align_to(&display_area, horizontal::Center, vertical::Center)
+///     .align_to(&display_area, horizontal::Left, vertical::Top);
+///
+/// assert_eq!(Point::zero(), row.bounds().top_left);
+/// ```
+pub struct Cached<V> {
+    view: V,
+    bounds: Cell<Option<Rectangle>>,
+}
+
+impl<V> Cached<V>
+where
+    V: View,
+{
+    /// Wraps the given view.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self {
+            view,
+            bounds: Cell::new(None),
+        }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Modifies the wrapped view and invalidates the cached bounds.
+    #[inline]
+    pub fn modify<R>(&mut self, f: impl FnOnce(&mut V) -> R) -> R {
+        self.invalidate();
+        f(&mut self.view)
+    }
+
+    /// Invalidates the cached bounds.
+    ///
+    /// The bounds will be computed again the next time they are needed.
+    #[inline]
+    pub fn invalidate(&self) {
+        self.bounds.set(None);
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for Cached<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+
+impl<V> View for Cached<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+
+        if let Some(bounds) = self.bounds.get_mut() {
+            bounds.top_left += by;
+        }
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        match self.bounds.get() {
+            Some(bounds) => bounds,
+            None => {
+                let bounds = self.view.bounds();
+                self.bounds.set(Some(bounds));
+                bounds
+            }
+        }
+    }
+
+    #[inline]
+    fn as_measurable_mut(&mut self) -> Option<&mut dyn MeasurableView> {
+        let view = self.view.as_measurable_mut();
+        if view.is_some() {
+            self.bounds.set(None);
+        }
+        view
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        let view = self.view.as_resizable_mut();
+        if view.is_some() {
+            self.bounds.set(None);
+        }
+        view
+    }
+
+    #[cfg(feature = "any")]
+    #[inline]
+    fn as_any_mut(&mut self) -> Option<&mut dyn core::any::Any> {
+        let view = self.view.as_any_mut();
+        if view.is_some() {
+            self.bounds.set(None);
+        }
+        view
+    }
+
+    forward_view_hooks!(field view; except as_measurable_mut, as_resizable_mut, as_any_mut);
+}
+
+impl<V> ViewGroup for Cached<V>
+where
+    V: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.view.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.invalidate();
+        self.view.at_mut(idx)
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.view.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.invalidate();
+        self.view.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.view.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.invalidate();
+        self.view.translate_child(idx, by)
+    }
+}
+
+impl<C, V> DrawableViewGroup<C> for Cached<V>
+where
+    C: PixelColor,
+    V: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.view.drawable_at(idx)
+    }
+}
+
+impl<C, V> Drawable for Cached<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*, view_group::EmptyViewGroup};
+    use embedded_graphics::prelude::Size;
+
+    struct CountingView<'a> {
+        bounds: Rectangle,
+        bounds_calls: &'a Cell<usize>,
+    }
+
+    impl View for CountingView<'_> {
+        fn translate_impl(&mut self, by: Point) {
+            self.bounds.top_left += by;
+        }
+
+        fn bounds(&self) -> Rectangle {
+            self.bounds_calls.set(self.bounds_calls.get() + 1);
+            self.bounds
+        }
+    }
+
+    #[test]
+    fn bounds_are_computed_once() {
+        let bounds_calls = Cell::new(0);
+        let view = Cached::new(CountingView {
+            bounds: Rectangle::new(Point::new(1, 2), Size::new(3, 4)),
+            bounds_calls: &bounds_calls,
+        });
+
+        assert_eq!(0, bounds_calls.get());
+
+        assert_eq!(Size::new(3, 4), view.size());
+        let view = view.translate(Point::new(2, 2));
+        assert_eq!(
+            Rectangle::new(Point::new(3, 4), Size::new(3, 4)),
+            view.bounds()
+        );
+
+        assert_eq!(1, bounds_calls.get());
+    }
+
+    #[test]
+    fn modify_invalidates_bounds() {
+        let bounds_calls = Cell::new(0);
+        let mut view = Cached::new(CountingView {
+            bounds: Rectangle::new(Point::new(1, 2), Size::new(3, 4)),
+            bounds_calls: &bounds_calls,
+        });
+
+        assert_eq!(Size::new(3, 4), view.size());
+
+        view.modify(|view| view.bounds.size = Size::new(5, 5));
+
+        assert_eq!(Size::new(5, 5), view.size());
+        assert_eq!(2, bounds_calls.get());
+    }
+
+    #[test]
+    fn cached_view_group_is_arranged() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut cached = Cached::new(Chain::new(rect).append(rect));
+        assert_eq!(Size::new(2, 2), cached.size());
+
+        LinearLayout::horizontal(EmptyViewGroup).arrange_in_place(&mut cached);
+
+        assert_eq!(Size::new(4, 2), cached.size());
+    }
+
+    #[test]
+    fn arranging_does_not_invalidate_bounds() {
+        let bounds_calls = Cell::new(0);
+        let view = Cached::new(CountingView {
+            bounds: Rectangle::new(Point::new(1, 2), Size::new(3, 4)),
+            bounds_calls: &bounds_calls,
+        });
+
+        let layout = LinearLayout::vertical(Chain::new(view)).arrange();
+
+        assert_eq!(Size::new(3, 4), layout.size());
+        assert_eq!(1, bounds_calls.get());
+    }
+
+    #[test]
+    fn view_group_bounds_are_kept_until_a_child_moves() {
+        let bounds_calls = Cell::new(0);
+        let view = CountingView {
+            bounds: Rectangle::new(Point::zero(), Size::new(2, 2)),
+            bounds_calls: &bounds_calls,
+        };
+        let reference = Rectangle::new(Point::new(10, 10), Size::new(10, 10));
+
+        let mut cached = Cached::new(Chain::new(view));
+        cached
+            .align_to_mut(&reference, horizontal::Center, vertical::Center)
+            .align_to_mut(&reference, horizontal::Right, vertical::Bottom);
+
+        assert_eq!(Point::new(18, 18), cached.bounds().top_left);
+        assert_eq!(1, bounds_calls.get());
+
+        cached.translate_child(0, Point::new(1, 1));
+
+        assert_eq!(Point::new(19, 19), cached.bounds().top_left);
+        assert_eq!(2, bounds_calls.get());
+    }
+}
@@ -0,0 +1,58 @@
+//! Display area helpers
+//!
+//! Views are usually aligned to the display, or to the part of the display that is left after
+//! leaving some space around the edges. [`DisplayArea`] returns these areas as [`Rectangle`]s, so
+//! they can be used as reference views.
+
+use embedded_graphics::{draw_target::DrawTarget, primitives::Rectangle};
+
+use crate::utils::rect_helper::{Insets, RectExt};
+
+/// Returns the drawable area of a display.
+///
+/// This trait is implemented for every [`DrawTarget`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::Rectangle,
+/// # };
+/// use embedded_layout::prelude::*;
+///
+/// let display: MockDisplay<BinaryColor> = MockDisplay::new();
+///
+/// let content_area = display.display_area_with_margin(Insets::symmetric(4, 2));
+/// let button = Rectangle::new(Point::zero(), Size::new(10, 5)).align_to(
+///     &content_area,
+///     horizontal::Right,
+///     vertical::Bottom,
+/// );
+///
+/// assert_eq!(Point::new(50, 57), button.top_left);
+/// ```
+pub trait DisplayArea {
+    /// Returns the area of the display.
+    fn display_area(&self) -> Rectangle;
+
+    /// Returns the area of the display, without the given margins.
+    fn display_area_with_margin(&self, margin: Insets) -> Rectangle;
+}
+
+impl<T> DisplayArea for T
+where
+    T: DrawTarget,
+{
+    #[inline]
+    fn display_area(&self) -> Rectangle {
+        self.bounding_box()
+    }
+
+    #[inline]
+    fn display_area_with_margin(&self, margin: Insets) -> Rectangle {
+        self.display_area().shrink(margin)
+    }
+}
@@ -0,0 +1,220 @@
+//! Draw target adapters
+//!
+//! A layout places views by translating them. Custom views that draw multiple primitives need to
+//! offset every primitive by their position. The adapters in this module let views draw in local
+//! coordinates instead, where `(0, 0)` is the top left corner of the view.
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{Dimensions, Point},
+    primitives::Rectangle,
+    Pixel,
+};
+
+use crate::View;
+
+/// A [`DrawTarget`] adapter that offsets every drawn pixel.
+///
+/// Pixels drawn at `(0, 0)` appear at `offset` on the parent draw target. Use
+/// [`TranslatedDrawTarget::for_view`] to draw a view in its local coordinate system. In this case
+/// the bounding box of the adapter is the area of the view, so primitives can be aligned to it.
+///
+/// # Example
+///
+/// A custom view that draws a frame with a cross in it:
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Line, PrimitiveStyle, Rectangle},
+/// # };
+/// use embedded_layout::{prelude::*, utils::draw_target::TranslatedDrawTarget};
+///
+/// struct Cross {
+///     bounds: Rectangle,
+/// }
+///
+/// impl View for Cross {
+///     fn translate_impl(&mut self, by: Point) {
+///         self.bounds.top_left += by;
+///     }
+///
+///     fn bounds(&self) -> Rectangle {
+///         self.bounds
+///     }
+/// }
+///
+/// impl Drawable for Cross {
+///     type Color = BinaryColor;
+///     type Output = ();
+///
+///     fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+///     where
+///         D: DrawTarget<Color = BinaryColor>,
+///     {
+///         let mut display = TranslatedDrawTarget::for_view(display, self);
+///         let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+///
+///         // The primitives don't need to know where the view is.
+///         let area = display.bounding_box();
+///         area.into_styled(style).draw(&mut display)?;
+///         Line::new(Point::zero(), area.bottom_right().unwrap())
+///             .into_styled(style)
+///             .draw(&mut display)
+///     }
+/// }
+///
+/// let cross = Cross {
+///     bounds: Rectangle::new(Point::zero(), Size::new(4, 4)),
+/// }
+/// .translate(Point::new(1, 1));
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// display.set_allow_overdraw(true);
+/// cross.draw(&mut display).unwrap();
+///
+/// display.assert_pattern(&[
+///     "     ", //
+///     " ####", //
+///     " ## #", //
+///     " # ##", //
+///     " ####", //
+/// ]);
+/// ```
+pub struct TranslatedDrawTarget<'a, D> {
+    parent: &'a mut D,
+    offset: Point,
+    bounds: Rectangle,
+}
+
+impl<'a, D> TranslatedDrawTarget<'a, D>
+where
+    D: DrawTarget,
+{
+    /// Offsets every pixel drawn to `parent` by `offset`.
+    ///
+    /// The bounding box of the adapter is the bounding box of `parent`, in the translated
+    /// coordinate system.
+    #[inline]
+    pub fn new(parent: &'a mut D, offset: Point) -> Self {
+        let bounds = parent.bounding_box().translate(-offset);
+
+        Self {
+            parent,
+            offset,
+            bounds,
+        }
+    }
+
+    /// Lets `view` draw itself in its local coordinate system.
+    ///
+    /// Pixels drawn at `(0, 0)` appear at the top left corner of `view`. The bounding box of the
+    /// adapter is the area of the view, starting at `(0, 0)`. Pixels outside of this area are not
+    /// clipped.
+    #[inline]
+    pub fn for_view(parent: &'a mut D, view: &impl View) -> Self {
+        let bounds = view.bounds();
+
+        Self {
+            parent,
+            offset: bounds.top_left,
+            bounds: Rectangle::new(Point::zero(), bounds.size),
+        }
+    }
+
+    /// Returns the offset that is added to every drawn pixel.
+    #[inline]
+    pub fn offset(&self) -> Point {
+        self.offset
+    }
+}
+
+impl<D> Dimensions for TranslatedDrawTarget<'_, D>
+where
+    D: DrawTarget,
+{
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<D> DrawTarget for TranslatedDrawTarget<'_, D>
+where
+    D: DrawTarget,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    #[inline]
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let offset = self.offset;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(p, color)| Pixel(p + offset, color)),
+        )
+    }
+
+    #[inline]
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.parent
+            .fill_contiguous(&area.translate(self.offset), colors)
+    }
+
+    #[inline]
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.fill_solid(&area.translate(self.offset), color)
+    }
+
+    #[inline]
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+        Drawable,
+    };
+
+    #[test]
+    fn pixels_are_offset() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        let mut translated = TranslatedDrawTarget::new(&mut display, Point::new(2, 1));
+
+        assert_eq!(
+            Rectangle::new(Point::new(-2, -1), Size::new(64, 64)),
+            translated.bounding_box()
+        );
+
+        Rectangle::new(Point::zero(), Size::new(2, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut translated)
+            .unwrap();
+        Pixel(Point::new(0, 1), BinaryColor::On)
+            .draw(&mut translated)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            "  ##", //
+            "  # ", //
+        ]);
+    }
+}
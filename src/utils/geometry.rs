@@ -0,0 +1,143 @@
+//! Saving and restoring arranged geometry
+//!
+//! Arranging complex screens takes time, but the result only depends on the views and the layout
+//! parameters. [`export`] returns the arranged bounds of the views of a view group, and [`apply`]
+//! moves the views of a view group back to these positions, without running the layouts again.
+//! The geometry can be computed on a host and compiled into the firmware, or it can be stored in
+//! flash after the first boot.
+//!
+//! With the `serde` feature enabled, [`ViewGeometry`] implements `Serialize` and `Deserialize`.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use embedded_graphics::{prelude::*, primitives::Rectangle};
+//! use embedded_layout::{
+//!     layout::linear::LinearLayout,
+//!     prelude::*,
+//!     utils::geometry::{self, ViewGeometry},
+//! };
+//!
+//! let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+//!
+//! // Arrange the views once, e.g. on the host, and save the result.
+//! let layout = LinearLayout::vertical(Chain::new(rect).append(rect)).arrange();
+//! let mut saved = [ViewGeometry::default(); 2];
+//! for (slot, geometry) in saved.iter_mut().zip(geometry::export(layout.inner())) {
+//!     *slot = geometry;
+//! }
+//!
+//! // Later, place the views without arranging them.
+//! let mut views = Chain::new(rect).append(rect);
+//! geometry::apply(&mut views, saved);
+//!
+//! assert_eq!(Point::new(0, 5), views.object.top_left);
+//! ```
+
+use embedded_graphics::{
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+use crate::view_group::ViewGroup;
+
+/// The arranged bounds of a single view of a view group.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewGeometry {
+    /// The position of the view in its view group.
+    pub index: usize,
+
+    /// The x coordinate of the top left corner of the view.
+    pub x: i32,
+
+    /// The y coordinate of the top left corner of the view.
+    pub y: i32,
+
+    /// The width of the view.
+    pub width: u32,
+
+    /// The height of the view.
+    pub height: u32,
+}
+
+impl ViewGeometry {
+    /// Creates a new object from the bounds of the view at position `index`.
+    #[inline]
+    pub const fn new(index: usize, bounds: Rectangle) -> Self {
+        Self {
+            index,
+            x: bounds.top_left.x,
+            y: bounds.top_left.y,
+            width: bounds.size.width,
+            height: bounds.size.height,
+        }
+    }
+
+    /// Returns the bounds of the view.
+    #[inline]
+    pub const fn bounds(&self) -> Rectangle {
+        Rectangle::new(
+            Point::new(self.x, self.y),
+            Size::new(self.width, self.height),
+        )
+    }
+}
+
+/// Returns the bounds of every view of `view_group`.
+#[inline]
+pub fn export(view_group: &impl ViewGroup) -> impl Iterator<Item = ViewGeometry> + '_ {
+    (0..view_group.len()).map(move |idx| ViewGeometry::new(idx, view_group.bounds_of(idx)))
+}
+
+/// Moves the views of `view_group` to the saved positions.
+///
+/// Views that have a different size than the saved one are resized if they implement
+/// [`ResizableView`]. Entries with an index that is out of bounds are ignored.
+///
+/// [`ResizableView`]: crate::layout::ResizableView
+#[inline]
+pub fn apply(view_group: &mut impl ViewGroup, geometry: impl IntoIterator<Item = ViewGeometry>) {
+    for geometry in geometry {
+        if geometry.index >= view_group.len() {
+            continue;
+        }
+
+        let bounds = geometry.bounds();
+        if view_group.bounds_of(geometry.index).size != bounds.size {
+            if let Some(view) = view_group.at_mut(geometry.index).as_resizable_mut() {
+                view.resize(bounds.size);
+            }
+        }
+
+        let by = bounds.top_left - view_group.bounds_of(geometry.index).top_left;
+        view_group.translate_child(geometry.index, by);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{prelude::*, views::Marquee};
+
+    #[test]
+    fn geometry_is_restored() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let saved = [
+            ViewGeometry::new(1, Rectangle::new(Point::new(3, 4), Size::new(2, 2))),
+            ViewGeometry::new(0, Rectangle::new(Point::new(-1, 1), Size::new(5, 2))),
+            ViewGeometry::new(2, Rectangle::new(Point::new(9, 9), Size::new(1, 1))),
+        ];
+
+        let mut views = Chain::new(Marquee::new(rect, 1)).append(rect);
+        apply(&mut views, saved);
+
+        assert_eq!(Point::new(3, 4), views.object.top_left);
+        assert_eq!(saved[1].bounds(), views.parent.object.bounds());
+
+        let mut exported = export(&views);
+        assert_eq!(Some(saved[1]), exported.next());
+        assert_eq!(Some(saved[0]), exported.next());
+        assert_eq!(None, exported.next());
+    }
+}
@@ -1,3 +1,9 @@
 //! Utility collection module
 
+pub mod cached;
+pub mod display_area;
+pub mod draw_target;
+pub mod geometry;
+#[cfg(feature = "profiling")]
+pub mod profiling;
 pub mod rect_helper;
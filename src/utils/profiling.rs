@@ -0,0 +1,279 @@
+//! Arrange profiling
+//!
+//! Arranging a layout queries the bounds of its views many times, and some views, like `Text`,
+//! are expensive to measure. Wrapping a view in [`Profiled`] counts how often its bounds are
+//! queried and how often it is translated, which helps finding the views that are worth
+//! [`Cached`](crate::utils::cached::Cached) on a given target.
+//!
+//! This module is only available with the `profiling` feature.
+use core::cell::Cell;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup},
+    View,
+};
+
+/// The number of operations performed on a [`Profiled`] view.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ArrangeStats {
+    /// The number of times the bounds of the view, or of one of its children, were queried.
+    pub bounds_calls: u32,
+
+    /// The number of times the view, or one of its children, was translated.
+    pub translations: u32,
+}
+
+/// Counts the operations performed on the wrapped view.
+///
+/// If the wrapped view is a view group, `Profiled` also implements [`ViewGroup`] and counts calls
+/// to [`ViewGroup::bounds_of`] and [`ViewGroup::translate_child`] as well. Operations performed
+/// on children through [`ViewGroup::at`] and [`ViewGroup::at_mut`] are not counted; wrap the
+/// children to profile them individually.
+///
+/// # Example
+///
+/// Wrapping a view in [`Cached`] and a `Profiled` view inside it shows how many times the bounds
+/// of the view are actually computed.
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     utils::profiling::Profiled,
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let layout = LinearLayout::vertical(
+///     Chain::new(Profiled::new(Text::new("Hello,", Point::zero(), text_style)))
+///         .append(Cached::new(Profiled::new(Text::new("World!", Point::zero(), text_style)))),
+/// )
+/// .arrange();
+///
+/// let uncached = layout.inner().parent.object.stats();
+/// let cached = layout.inner().object.inner().stats();
+///
+/// assert_eq!(1, cached.bounds_calls);
+/// assert!(uncached.bounds_calls > cached.bounds_calls);
+/// ```
+///
+/// [`Cached`]: crate::utils::cached::Cached
+pub struct Profiled<V> {
+    view: V,
+    stats: Cell<ArrangeStats>,
+}
+
+impl<V> Profiled<V>
+where
+    V: View,
+{
+    /// Wraps the given view.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self {
+            view,
+            stats: Cell::new(ArrangeStats::default()),
+        }
+    }
+
+    /// Returns the number of operations performed since the view was wrapped or the counters were
+    /// last reset.
+    #[inline]
+    pub fn stats(&self) -> ArrangeStats {
+        self.stats.get()
+    }
+
+    /// Resets the counters.
+    #[inline]
+    pub fn reset_stats(&self) {
+        self.stats.set(ArrangeStats::default());
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Profiled<V> {
+    fn count_bounds_call(&self) {
+        let mut stats = self.stats.get();
+        stats.bounds_calls = stats.bounds_calls.saturating_add(1);
+        self.stats.set(stats);
+    }
+
+    fn count_translation(&mut self) {
+        let stats = self.stats.get_mut();
+        stats.translations = stats.translations.saturating_add(1);
+    }
+}
+
+impl<V> Clone for Profiled<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+impl<V> View for Profiled<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.count_translation();
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.count_bounds_call();
+        self.view.bounds()
+    }
+
+    forward_view_hooks!(field view);
+}
+
+impl<V> ViewGroup for Profiled<V>
+where
+    V: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        self.view.at(idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        self.view.at_mut(idx)
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.view.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.view.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.count_bounds_call();
+        self.view.bounds_of(idx)
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.count_translation();
+        self.view.translate_child(idx, by)
+    }
+}
+
+impl<C, V> DrawableViewGroup<C> for Profiled<V>
+where
+    C: PixelColor,
+    V: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        self.view.drawable_at(idx)
+    }
+}
+
+impl<C, V> Drawable for Profiled<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn operations_are_counted() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+
+        let mut view = Profiled::new(rect);
+        view.bounds();
+        view.translate_mut(Point::new(1, 1));
+
+        assert_eq!(
+            ArrangeStats {
+                bounds_calls: 1,
+                translations: 1
+            },
+            view.stats()
+        );
+
+        view.reset_stats();
+        assert_eq!(ArrangeStats::default(), view.stats());
+    }
+
+    #[test]
+    fn view_group_operations_are_counted() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let mut views = [rect, rect, rect];
+
+        let layout = Profiled::new(Views::new(&mut views));
+        let layout = LinearLayout::vertical(layout).arrange();
+
+        let stats = layout.inner().stats();
+        assert_ne!(0, stats.bounds_calls);
+        assert!(stats.translations >= 3);
+    }
+}
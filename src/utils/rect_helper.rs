@@ -1,14 +1,195 @@
 //! `Rectangle` utility methods
 //!
 //! This module implements a few useful extensions to `Rectangle`.
+//!
+//! Besides combining rectangles, `RectExt` can slice a `Rectangle` into smaller cells that views
+//! can be aligned to, which is useful to compose simple grids without a layout object.
+//!
+//! ```rust
+//! # use embedded_graphics::{prelude::*, primitives::Rectangle};
+//! use embedded_layout::prelude::*;
+//!
+//! let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+//!
+//! // Place 6 buttons in a 3x2 grid.
+//! for cell in display_area.grid(3, 2) {
+//!     let button = Rectangle::new(Point::zero(), Size::new(30, 20)).align_to(
+//!         &cell,
+//!         horizontal::Center,
+//!         vertical::Center,
+//!     );
+//!     # assert!(cell.contains(button.center()));
+//! }
+//! ```
 use embedded_graphics::{geometry::AnchorPoint, prelude::*, primitives::Rectangle};
 
 /// The trait that describes the extension methods.
 pub trait RectExt {
     /// Return the bounding `Rectangle` that encompasses both `Rectangles`
     fn enveloping(&self, other: &Rectangle) -> Rectangle;
+
+    /// Divide the `Rectangle` into `n` rows of (nearly) equal height, from top to bottom
+    ///
+    /// If the height can't be divided evenly, some rows will be 1px taller than the others.
+    fn split_rows(&self, n: u32) -> Cells;
+
+    /// Divide the `Rectangle` into `n` columns of (nearly) equal width, from left to right
+    ///
+    /// If the width can't be divided evenly, some columns will be 1px wider than the others.
+    fn split_columns(&self, n: u32) -> Cells;
+
+    /// Divide the `Rectangle` into a grid of `columns` x `rows` cells, row by row
+    fn grid(&self, columns: u32, rows: u32) -> Cells;
+
+    /// Split the `Rectangle` into a left and a right part, `x` pixels from the left edge
+    ///
+    /// If `x` is larger than the width, the right part will be zero sized.
+    fn split_at_x(&self, x: u32) -> (Rectangle, Rectangle);
+
+    /// Split the `Rectangle` into a top and a bottom part, `y` pixels from the top edge
+    ///
+    /// If `y` is larger than the height, the bottom part will be zero sized.
+    fn split_at_y(&self, y: u32) -> (Rectangle, Rectangle);
+
+    /// Return the `Rectangle` with its edges moved inwards by the given insets
+    ///
+    /// If the insets are larger than the `Rectangle`, the result will be zero sized.
+    fn shrink(&self, insets: Insets) -> Rectangle;
+
+    /// Return the `Rectangle` with its edges moved outwards by the given insets
+    fn inflate(&self, insets: Insets) -> Rectangle;
+
+    /// Return a `Rectangle` sized as a percentage of this one, placed at the given anchor point
+    ///
+    /// For example, `AnchorPoint::BottomRight` returns the rectangle in the bottom right corner.
+    fn relative(&self, width_percent: u32, height_percent: u32, anchor: AnchorPoint) -> Rectangle;
 }
 
+/// Distances from the edges of a `Rectangle`
+///
+/// Used by [`RectExt::shrink`] and [`RectExt::inflate`], for example to calculate the content
+/// area of a display with margins.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Insets {
+    /// Distance from the top edge
+    pub top: u32,
+
+    /// Distance from the right edge
+    pub right: u32,
+
+    /// Distance from the bottom edge
+    pub bottom: u32,
+
+    /// Distance from the left edge
+    pub left: u32,
+}
+
+impl Insets {
+    /// Create insets with different values for each edge
+    #[inline]
+    pub const fn new(top: u32, right: u32, bottom: u32, left: u32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Create insets with the same value for every edge
+    #[inline]
+    pub const fn uniform(inset: u32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// Create insets with one value for the left and right, and one for the top and bottom edges
+    #[inline]
+    pub const fn symmetric(horizontal: u32, vertical: u32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Returns the sum of the left and right insets
+    #[inline]
+    pub const fn horizontal(&self) -> u32 {
+        self.left.saturating_add(self.right)
+    }
+
+    /// Returns the sum of the top and bottom insets
+    #[inline]
+    pub const fn vertical(&self) -> u32 {
+        self.top.saturating_add(self.bottom)
+    }
+}
+
+/// Iterator over the cells of a `Rectangle`
+///
+/// Created by [`RectExt::split_rows`], [`RectExt::split_columns`] and [`RectExt::grid`].
+#[derive(Clone, Debug)]
+pub struct Cells {
+    area: Rectangle,
+    columns: u32,
+    rows: u32,
+    index: u32,
+}
+
+impl Cells {
+    fn new(area: Rectangle, columns: u32, rows: u32) -> Self {
+        Self {
+            area,
+            columns,
+            rows,
+            index: 0,
+        }
+    }
+
+    fn cell_count(&self) -> u32 {
+        self.columns * self.rows
+    }
+
+    /// Returns the start offset of the `idx`th of `n` parts of `length`.
+    fn offset(length: u32, idx: u32, n: u32) -> u32 {
+        (length as u64 * idx as u64 / n as u64) as u32
+    }
+
+    fn span(length: u32, idx: u32, n: u32) -> (u32, u32) {
+        let start = Self::offset(length, idx, n);
+        let end = Self::offset(length, idx + 1, n);
+
+        (start, end - start)
+    }
+}
+
+impl Iterator for Cells {
+    type Item = Rectangle;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.cell_count() {
+            return None;
+        }
+
+        let column = self.index % self.columns;
+        let row = self.index / self.columns;
+        self.index += 1;
+
+        let (x, width) = Self::span(self.area.size.width, column, self.columns);
+        let (y, height) = Self::span(self.area.size.height, row, self.rows);
+
+        Some(Rectangle::new(
+            self.area.top_left + Point::new(x as i32, y as i32),
+            Size::new(width, height),
+        ))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cell_count().saturating_sub(self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Cells {}
+
 impl RectExt for Rectangle {
     #[inline]
     fn enveloping(&self, other: &Rectangle) -> Rectangle {
@@ -27,12 +208,92 @@ impl RectExt for Rectangle {
             ),
         )
     }
+
+    #[inline]
+    fn split_rows(&self, n: u32) -> Cells {
+        Cells::new(*self, 1, n)
+    }
+
+    #[inline]
+    fn split_columns(&self, n: u32) -> Cells {
+        Cells::new(*self, n, 1)
+    }
+
+    #[inline]
+    fn grid(&self, columns: u32, rows: u32) -> Cells {
+        Cells::new(*self, columns, rows)
+    }
+
+    #[inline]
+    fn split_at_x(&self, x: u32) -> (Rectangle, Rectangle) {
+        let x = x.min(self.size.width);
+
+        (
+            Rectangle::new(self.top_left, Size::new(x, self.size.height)),
+            Rectangle::new(
+                self.top_left + Point::new(x as i32, 0),
+                Size::new(self.size.width - x, self.size.height),
+            ),
+        )
+    }
+
+    #[inline]
+    fn split_at_y(&self, y: u32) -> (Rectangle, Rectangle) {
+        let y = y.min(self.size.height);
+
+        (
+            Rectangle::new(self.top_left, Size::new(self.size.width, y)),
+            Rectangle::new(
+                self.top_left + Point::new(0, y as i32),
+                Size::new(self.size.width, self.size.height - y),
+            ),
+        )
+    }
+
+    #[inline]
+    fn shrink(&self, insets: Insets) -> Rectangle {
+        let width = self.size.width.saturating_sub(insets.horizontal());
+        let height = self.size.height.saturating_sub(insets.vertical());
+
+        // Zero sized results are placed where the insets meet, but stay inside the original area.
+        let left = insets.left.min(self.size.width - width);
+        let top = insets.top.min(self.size.height - height);
+
+        Rectangle::new(
+            self.top_left + Point::new(left as i32, top as i32),
+            Size::new(width, height),
+        )
+    }
+
+    #[inline]
+    fn inflate(&self, insets: Insets) -> Rectangle {
+        Rectangle::new(
+            self.top_left - Point::new(insets.left as i32, insets.top as i32),
+            Size::new(
+                self.size.width.saturating_add(insets.horizontal()),
+                self.size.height.saturating_add(insets.vertical()),
+            ),
+        )
+    }
+
+    #[inline]
+    fn relative(&self, width_percent: u32, height_percent: u32, anchor: AnchorPoint) -> Rectangle {
+        let percent_of = |length: u32, percent: u32| (length as u64 * percent as u64 / 100) as u32;
+
+        self.resized(
+            Size::new(
+                percent_of(self.size.width, width_percent),
+                percent_of(self.size.height, height_percent),
+            ),
+            anchor,
+        )
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
-    use embedded_graphics::{prelude::*, primitives::Rectangle};
+    use embedded_graphics::{geometry::AnchorPoint, prelude::*, primitives::Rectangle};
 
     #[test]
     fn test_enveloping() {
@@ -47,4 +308,109 @@ mod test {
             rect0.enveloping(&rect2)
         );
     }
+
+    #[test]
+    fn test_split_rows_and_columns() {
+        let rect = Rectangle::new(Point::new(1, 2), Size::new(10, 5));
+
+        let mut rows = rect.split_rows(2);
+        assert_eq!(2, rows.len());
+        assert_eq!(
+            Some(Rectangle::new(Point::new(1, 2), Size::new(10, 2))),
+            rows.next()
+        );
+        assert_eq!(
+            Some(Rectangle::new(Point::new(1, 4), Size::new(10, 3))),
+            rows.next()
+        );
+        assert_eq!(None, rows.next());
+
+        let widths = rect.split_columns(3).map(|cell| cell.size.width);
+        assert!(widths.eq([3, 3, 4]));
+
+        assert_eq!(0, rect.split_columns(0).count());
+    }
+
+    #[test]
+    fn test_grid() {
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 4));
+
+        let cells = rect.grid(2, 2);
+        assert!(cells.eq([
+            Rectangle::new(Point::new(0, 0), Size::new(2, 2)),
+            Rectangle::new(Point::new(2, 0), Size::new(2, 2)),
+            Rectangle::new(Point::new(0, 2), Size::new(2, 2)),
+            Rectangle::new(Point::new(2, 2), Size::new(2, 2)),
+        ]));
+
+        assert_eq!(0, rect.grid(0, 3).count());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let rect = Rectangle::new(Point::new(1, 1), Size::new(10, 5));
+
+        assert_eq!(
+            (
+                Rectangle::new(Point::new(1, 1), Size::new(4, 5)),
+                Rectangle::new(Point::new(5, 1), Size::new(6, 5)),
+            ),
+            rect.split_at_x(4)
+        );
+        assert_eq!(
+            (
+                Rectangle::new(Point::new(1, 1), Size::new(10, 2)),
+                Rectangle::new(Point::new(1, 3), Size::new(10, 3)),
+            ),
+            rect.split_at_y(2)
+        );
+        assert_eq!(
+            (rect, Rectangle::new(Point::new(1, 6), Size::new(10, 0))),
+            rect.split_at_y(10)
+        );
+    }
+
+    #[test]
+    fn test_shrink_and_inflate() {
+        let rect = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+        let insets = Insets::new(1, 2, 3, 4);
+
+        let shrunk = rect.shrink(insets);
+        assert_eq!(Rectangle::new(Point::new(14, 11), Size::new(14, 6)), shrunk);
+        assert_eq!(rect, shrunk.inflate(insets));
+
+        assert_eq!(
+            Rectangle::new(Point::new(12, 11), Size::new(16, 8)),
+            rect.shrink(Insets::symmetric(2, 1))
+        );
+    }
+
+    #[test]
+    fn test_shrink_too_much() {
+        let rect = Rectangle::new(Point::new(10, 10), Size::new(4, 4));
+
+        assert_eq!(
+            Rectangle::new(Point::new(14, 11), Size::new(0, 2)),
+            rect.shrink(Insets::new(1, 0, 1, 5))
+        );
+        assert!(rect.shrink(Insets::uniform(5)).is_zero_sized());
+    }
+
+    #[test]
+    fn test_relative() {
+        let rect = Rectangle::new(Point::new(10, 10), Size::new(100, 50));
+
+        assert_eq!(
+            Rectangle::new(Point::new(10, 10), Size::new(30, 25)),
+            rect.relative(30, 50, AnchorPoint::TopLeft)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(35, 30), Size::new(50, 10)),
+            rect.relative(50, 20, AnchorPoint::Center)
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(90, 10), Size::new(20, 50)),
+            rect.relative(20, 100, AnchorPoint::TopRight)
+        );
+    }
 }
@@ -0,0 +1,246 @@
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// Builds a [`DynViewGroup`] from references to views of different types.
+///
+/// The builder is an alternative to `derive(ViewGroup)` and [`view_group_struct!`] that doesn't
+/// need macros: register the fields of a struct, or any other views, in the order they should
+/// appear in the view group. The views are stored as [`DrawableView`] trait objects, so they can
+/// have different types, and the view group can be drawn. At most `N` views can be registered.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Circle, PrimitiveStyle, Styled},
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     view_group::ViewGroupBuilder,
+/// };
+///
+/// struct Screen<'a> {
+///     icon: Styled<Circle, PrimitiveStyle<BinaryColor>>,
+///     label: Text<'a, MonoTextStyle<'a, BinaryColor>>,
+/// }
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let mut screen = Screen {
+///     icon: Circle::new(Point::zero(), 9).into_styled(PrimitiveStyle::with_fill(BinaryColor::On)),
+///     label: Text::new("Connected", Point::zero(), text_style),
+/// };
+///
+/// let views = ViewGroupBuilder::<_, 2>::new()
+///     .register(&mut screen.icon)
+///     .register(&mut screen.label)
+///     .build();
+///
+/// let layout = LinearLayout::horizontal(views).arrange();
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// layout.draw(&mut display).unwrap();
+/// drop(layout);
+///
+/// assert_eq!(9, screen.label.bounds().top_left.x);
+/// ```
+///
+/// [`view_group_struct!`]: crate::view_group_struct
+pub struct ViewGroupBuilder<'a, C, const N: usize>
+where
+    C: PixelColor,
+{
+    group: DynViewGroup<'a, C, N>,
+}
+
+impl<'a, C, const N: usize> ViewGroupBuilder<'a, C, N>
+where
+    C: PixelColor,
+{
+    /// Creates a new builder without any views.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            group: DynViewGroup {
+                views: [(); N].map(|_| None),
+                len: 0,
+            },
+        }
+    }
+
+    /// Registers a view as the next view of the view group.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `N` views are already registered.
+    #[inline]
+    #[must_use]
+    pub fn register(mut self, view: &'a mut dyn DrawableView<C>) -> Self {
+        assert!(
+            self.group.len < N,
+            "ViewGroupBuilder can hold at most {} views",
+            N
+        );
+
+        self.group.views[self.group.len] = Some(view);
+        self.group.len += 1;
+        self
+    }
+
+    /// Returns the view group that contains the registered views.
+    #[inline]
+    pub fn build(self) -> DynViewGroup<'a, C, N> {
+        self.group
+    }
+}
+
+impl<C, const N: usize> Default for ViewGroupBuilder<'_, C, N>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A view group of references to views of different types.
+///
+/// Use [`ViewGroupBuilder`] to create a `DynViewGroup`.
+pub struct DynViewGroup<'a, C, const N: usize>
+where
+    C: PixelColor,
+{
+    views: [Option<&'a mut dyn DrawableView<C>>; N],
+    len: usize,
+}
+
+impl<C, const N: usize> View for DynViewGroup<'_, C, N>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<C, const N: usize> ViewGroup for DynViewGroup<'_, C, N>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        match self.views.get(idx) {
+            Some(Some(view)) => view.as_view(),
+            _ => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        match self.views.get_mut(idx) {
+            Some(Some(view)) => view.as_view_mut(),
+            _ => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+}
+
+impl<C, const N: usize> DrawableViewGroup<C> for DynViewGroup<'_, C, N>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        match self.views.get(idx) {
+            Some(Some(view)) => &**view,
+            _ => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+}
+
+impl<C, const N: usize> Drawable for DynViewGroup<'_, C, N>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for idx in 0..self.len {
+            self.drawable_at(idx).draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        pixelcolor::BinaryColor,
+        prelude::Size,
+        primitives::{Circle, Primitive, PrimitiveStyle},
+    };
+
+    #[test]
+    fn views_are_accessed_in_registration_order() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let mut circle = Circle::new(Point::zero(), 3).into_styled(style);
+        let mut rect = Rectangle::new(Point::new(5, 5), Size::new(2, 2)).into_styled(style);
+
+        let mut group = ViewGroupBuilder::<_, 3>::new()
+            .register(&mut rect)
+            .register(&mut circle)
+            .build();
+
+        assert_eq!(2, group.len());
+        assert_eq!(Size::new(3, 3), group.bounds_of(1).size);
+        assert!(group.try_at(2).is_none());
+
+        group.translate_child(0, Point::new(1, 0));
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(8, 7)),
+            group.bounds()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn adding_too_many_views_panics() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let mut a = Rectangle::zero().into_styled(style);
+        let mut b = Rectangle::zero().into_styled(style);
+
+        let _ = ViewGroupBuilder::<_, 1>::new()
+            .register(&mut a)
+            .register(&mut b);
+    }
+}
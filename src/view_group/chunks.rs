@@ -0,0 +1,355 @@
+use core::ops::{Deref, DerefMut, Range};
+
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// Wrapper that implements ViewGroup for a slice of views, grouped into chunks of `N` views.
+///
+/// Every chunk is a [`Chunk`] view group, so a flat array of cells can be arranged as the rows of a
+/// grid without copying them: arrange the views of every chunk first, then arrange the chunks
+/// themselves. If the number of views is not a multiple of `N`, the remaining views are not part
+/// of any chunk.
+///
+/// # Panics
+///
+/// Creating a `Chunks` object panics if `N` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     view_group::{Chunks, EmptyViewGroup},
+/// };
+///
+/// let mut cells = [Rectangle::new(Point::zero(), Size::new(5, 5)); 6];
+///
+/// let mut rows: Chunks<_, 3> = Chunks::new(&mut cells);
+/// for row in rows.iter_mut() {
+///     LinearLayout::horizontal(EmptyViewGroup).arrange_in_place(row);
+/// }
+/// LinearLayout::vertical(rows).arrange();
+///
+/// assert_eq!(Point::new(10, 0), cells[2].top_left);
+/// assert_eq!(Point::new(0, 5), cells[3].top_left);
+/// assert_eq!(Point::new(10, 5), cells[5].top_left);
+/// ```
+pub struct Chunks<'a, T, const N: usize>
+where
+    T: View,
+{
+    views: &'a mut [T],
+}
+
+impl<'a, T, const N: usize> Chunks<'a, T, N>
+where
+    T: View,
+{
+    /// Wraps the given slice.
+    #[inline]
+    pub fn new(views: &'a mut [T]) -> Self {
+        assert!(N != 0, "Chunk size must not be zero");
+
+        Self { views }
+    }
+
+    /// Returns a shared reference to the chunk at position `idx`, or `None` if `idx` is out of
+    /// bounds.
+    #[inline]
+    pub fn chunk(&self, idx: usize) -> Option<&Chunk<T, N>> {
+        let views = self.views.get(Self::range(idx)?)?;
+
+        views.try_into().ok().map(Chunk::from_ref)
+    }
+
+    /// Returns an exclusive reference to the chunk at position `idx`, or `None` if `idx` is out of
+    /// bounds.
+    #[inline]
+    pub fn chunk_mut(&mut self, idx: usize) -> Option<&mut Chunk<T, N>> {
+        let views = self.views.get_mut(Self::range(idx)?)?;
+
+        views.try_into().ok().map(Chunk::from_mut)
+    }
+
+    /// Returns the range of views in the chunk at position `idx`, or `None` if it overflows.
+    fn range(idx: usize) -> Option<Range<usize>> {
+        let start = idx.checked_mul(N)?;
+        let end = start.checked_add(N)?;
+
+        Some(start..end)
+    }
+
+    /// Returns an iterator over the chunks.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Chunk<T, N>> {
+        self.views
+            .chunks_exact(N)
+            .filter_map(|views| views.try_into().ok().map(Chunk::from_ref))
+    }
+
+    /// Returns an iterator that allows modifying the chunks.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Chunk<T, N>> {
+        self.views
+            .chunks_exact_mut(N)
+            .filter_map(|views| views.try_into().ok().map(Chunk::from_mut))
+    }
+}
+
+impl<T, const N: usize> ViewGroup for Chunks<'_, T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len() / N
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        match self.chunk(idx) {
+            Some(chunk) => chunk,
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        match self.chunk_mut(idx) {
+            Some(chunk) => chunk,
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.chunk(idx).map(|chunk| chunk as &dyn View)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.chunk_mut(idx).map(|chunk| chunk as &mut dyn View)
+    }
+}
+
+impl<T, const N: usize> View for Chunks<'_, T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<C, T, const N: usize> DrawableViewGroup<C> for Chunks<'_, T, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        match self.chunk(idx) {
+            Some(chunk) => chunk,
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+}
+
+impl<C, T, const N: usize> Drawable for Chunks<'_, T, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        for chunk in self.iter() {
+            chunk.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A chunk of `N` views of a [`Chunks`] view group.
+#[repr(transparent)]
+pub struct Chunk<T, const N: usize> {
+    views: [T; N],
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+    fn from_ref(views: &[T; N]) -> &Self {
+        // SAFETY: `Chunk` is a `repr(transparent)` wrapper around `[T; N]`.
+        unsafe { &*(views as *const [T; N] as *const Self) }
+    }
+
+    fn from_mut(views: &mut [T; N]) -> &mut Self {
+        // SAFETY: `Chunk` is a `repr(transparent)` wrapper around `[T; N]`.
+        unsafe { &mut *(views as *mut [T; N] as *mut Self) }
+    }
+}
+
+impl<T, const N: usize> ViewGroup for Chunk<T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        &self.views[idx]
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        &mut self.views[idx]
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.get(idx).map(|view| view as &dyn View)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.get_mut(idx).map(|view| view as &mut dyn View)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views[idx].bounds()
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views[idx].translate_impl(by)
+    }
+}
+
+impl<T, const N: usize> View for Chunk<T, N>
+where
+    T: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<T, const N: usize> Deref for Chunk<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.views
+    }
+}
+
+impl<T, const N: usize> DerefMut for Chunk<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.views
+    }
+}
+
+impl<C, T, const N: usize> DrawableViewGroup<C> for Chunk<T, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        &self.views[idx]
+    }
+}
+
+impl<C, T, const N: usize> Drawable for Chunk<T, N>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        for view in self.views.iter() {
+            view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{prelude::Size, primitives::Line};
+
+    #[test]
+    fn remaining_views_are_not_chunked() {
+        let mut views = [Line::new(Point::zero(), Point::new(1, 1)); 5];
+
+        let mut chunks: Chunks<_, 2> = Chunks::new(&mut views);
+
+        assert_eq!(2, chunks.len());
+        assert_eq!(2, chunks.iter().count());
+        assert!(chunks.try_at(2).is_none());
+        assert!(chunks.chunk(usize::MAX).is_none());
+        assert!(chunks.chunk_mut(usize::MAX / 2 + 1).is_none());
+
+        chunks.translate_child(1, Point::new(1, 2));
+
+        assert_eq!(Point::zero(), views[1].start);
+        assert_eq!(Point::new(1, 2), views[2].start);
+        assert_eq!(Point::new(1, 2), views[3].start);
+        assert_eq!(Point::zero(), views[4].start);
+    }
+
+    #[test]
+    fn chunk_bounds_envelope_its_views() {
+        let mut views = [
+            Rectangle::new(Point::zero(), Size::new(1, 1)),
+            Rectangle::new(Point::new(2, 3), Size::new(1, 1)),
+        ];
+
+        let chunks: Chunks<_, 2> = Chunks::new(&mut views);
+
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(3, 4)),
+            chunks.bounds_of(0)
+        );
+        assert_eq!(2, chunks.chunk(0).unwrap().len());
+    }
+}
@@ -0,0 +1,267 @@
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// Two [`ViewGroup`]s combined into one.
+///
+/// The views of the first group come first, followed by the views of the second group. Both
+/// groups keep their own types, so pre-built parts of a screen can be arranged and drawn together
+/// without rebuilding them as a single object chain.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     view_group::{Concat, ViewGroup},
+/// };
+///
+/// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+///
+/// let header = Chain::new(rect).append(rect);
+/// let mut body = [rect; 3];
+///
+/// let layout = LinearLayout::vertical(Concat::new(header, Views::new(&mut body))).arrange();
+///
+/// assert_eq!(5, layout.inner().len());
+/// assert_eq!(Point::new(0, 20), layout.inner().second().bounds_of(2).top_left);
+/// ```
+pub struct Concat<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Concat<A, B>
+where
+    A: ViewGroup,
+    B: ViewGroup,
+{
+    /// Combines two view groups.
+    #[inline]
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns a reference to the first view group.
+    #[inline]
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns a mutable reference to the first view group.
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut A {
+        &mut self.first
+    }
+
+    /// Returns a reference to the second view group.
+    #[inline]
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+
+    /// Returns a mutable reference to the second view group.
+    #[inline]
+    pub fn second_mut(&mut self) -> &mut B {
+        &mut self.second
+    }
+
+    /// Consumes the object and returns the two view groups.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B> Clone for Concat<A, B>
+where
+    A: Clone,
+    B: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first.clone(),
+            second: self.second.clone(),
+        }
+    }
+}
+
+impl<A, B> View for Concat<A, B>
+where
+    A: ViewGroup,
+    B: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<A, B> ViewGroup for Concat<A, B>
+where
+    A: ViewGroup,
+    B: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        let first = self.first.len();
+        if idx < first {
+            self.first.at(idx)
+        } else {
+            self.second.at(idx - first)
+        }
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        let first = self.first.len();
+        if idx < first {
+            self.first.at_mut(idx)
+        } else {
+            self.second.at_mut(idx - first)
+        }
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        let first = self.first.len();
+        if idx < first {
+            self.first.try_at(idx)
+        } else {
+            self.second.try_at(idx - first)
+        }
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        let first = self.first.len();
+        if idx < first {
+            self.first.try_at_mut(idx)
+        } else {
+            self.second.try_at_mut(idx - first)
+        }
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        let first = self.first.len();
+        if idx < first {
+            self.first.bounds_of(idx)
+        } else {
+            self.second.bounds_of(idx - first)
+        }
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        let first = self.first.len();
+        if idx < first {
+            self.first.translate_child(idx, by)
+        } else {
+            self.second.translate_child(idx - first, by)
+        }
+    }
+}
+
+impl<C, A, B> DrawableViewGroup<C> for Concat<A, B>
+where
+    C: PixelColor,
+    A: DrawableViewGroup<C>,
+    B: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        let first = self.first.len();
+        if idx < first {
+            self.first.drawable_at(idx)
+        } else {
+            self.second.drawable_at(idx - first)
+        }
+    }
+}
+
+impl<C, A, B> Drawable for Concat<A, B>
+where
+    C: PixelColor,
+    A: ViewGroup + Drawable<Color = C>,
+    B: ViewGroup + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.first.draw(display)?;
+        self.second.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::{FixedMargin, LinearLayout},
+        prelude::*,
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn indexes_continue_in_second_group() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+        let mut views = [rect.translate(Point::new(4, 4))];
+
+        let group = Concat::new(Chain::new(rect).append(rect), Views::new(&mut views));
+
+        assert_eq!(3, group.len());
+        assert_eq!(Point::new(4, 4), group.bounds_of(2).top_left);
+        assert!(group.try_at(2).is_some());
+        assert!(group.try_at(3).is_none());
+    }
+
+    #[test]
+    fn groups_are_arranged_and_drawn_together() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style);
+
+        let layout = LinearLayout::horizontal(Concat::new(
+            Chain::new(rect),
+            Concat::new(Chain::new(rect), Chain::new(rect)),
+        ))
+        .with_spacing(FixedMargin(1))
+        .arrange();
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+
+        display.assert_pattern(&["# # #"]);
+    }
+}
@@ -0,0 +1,577 @@
+//! Object-safe drawing for [`View`] objects.
+//!
+//! `embedded-graphics`' [`Drawable`] and [`DrawTarget`] traits are not object-safe, so a
+//! `&dyn View` returned by [`ViewGroup::at`] can't be drawn. [`DrawableView`] is an object-safe
+//! alternative that draws into a type-erased [`DynDrawTarget`].
+
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{Dimensions, PixelColor},
+    primitives::Rectangle,
+    Drawable, Pixel,
+};
+
+use crate::{
+    object_chain::{Chain, ChainElement, Link},
+    view_group::{ViewGroup, ViewRefs, Views},
+    View,
+};
+
+/// Error returned by [`DynDrawTarget`].
+///
+/// The error of the underlying draw target is returned when drawing a [`DrawableView`] trait object
+/// using [`Drawable::draw`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DrawError(());
+
+/// Object-safe subset of [`DrawTarget`].
+trait ErasedDrawTarget<C: PixelColor> {
+    fn draw_iter(&mut self, pixels: &mut dyn Iterator<Item = Pixel<C>>) -> Result<(), DrawError>;
+
+    fn fill_contiguous(
+        &mut self,
+        area: &Rectangle,
+        colors: &mut dyn Iterator<Item = C>,
+    ) -> Result<(), DrawError>;
+
+    fn fill_solid(&mut self, area: &Rectangle, color: C) -> Result<(), DrawError>;
+
+    fn clear(&mut self, color: C) -> Result<(), DrawError>;
+
+    fn bounding_box(&self) -> Rectangle;
+}
+
+/// Stores the first error returned by the wrapped draw target.
+struct ErrorCapture<'a, D>
+where
+    D: DrawTarget,
+{
+    display: &'a mut D,
+    error: Option<D::Error>,
+}
+
+impl<D> ErrorCapture<'_, D>
+where
+    D: DrawTarget,
+{
+    fn capture(&mut self, result: Result<(), D::Error>) -> Result<(), DrawError> {
+        result.map_err(|error| {
+            self.error = Some(error);
+            DrawError(())
+        })
+    }
+}
+
+impl<D> ErasedDrawTarget<D::Color> for ErrorCapture<'_, D>
+where
+    D: DrawTarget,
+{
+    fn draw_iter(
+        &mut self,
+        pixels: &mut dyn Iterator<Item = Pixel<D::Color>>,
+    ) -> Result<(), DrawError> {
+        let result = self.display.draw_iter(pixels);
+        self.capture(result)
+    }
+
+    fn fill_contiguous(
+        &mut self,
+        area: &Rectangle,
+        colors: &mut dyn Iterator<Item = D::Color>,
+    ) -> Result<(), DrawError> {
+        let result = self.display.fill_contiguous(area, colors);
+        self.capture(result)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: D::Color) -> Result<(), DrawError> {
+        let result = self.display.fill_solid(area, color);
+        self.capture(result)
+    }
+
+    fn clear(&mut self, color: D::Color) -> Result<(), DrawError> {
+        let result = self.display.clear(color);
+        self.capture(result)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        self.display.bounding_box()
+    }
+}
+
+/// A type-erased [`DrawTarget`].
+///
+/// `DynDrawTarget` is passed to [`DrawableView::draw_dyn`]. To draw a [`DrawableView`] trait
+/// object, call [`Drawable::draw`] on it, which creates the `DynDrawTarget` for you.
+pub struct DynDrawTarget<'a, C>
+where
+    C: PixelColor,
+{
+    target: &'a mut dyn ErasedDrawTarget<C>,
+}
+
+impl<C> Dimensions for DynDrawTarget<'_, C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<C> DrawTarget for DynDrawTarget<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Error = DrawError;
+
+    #[inline]
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.target.draw_iter(&mut pixels.into_iter())
+    }
+
+    #[inline]
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.target.fill_contiguous(area, &mut colors.into_iter())
+    }
+
+    #[inline]
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.fill_solid(area, color)
+    }
+
+    #[inline]
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(color)
+    }
+}
+
+/// An object-safe [`View`] that can be drawn.
+///
+/// This trait is blanket-implemented for every [`View`] that implements [`Drawable`]. A
+/// `&dyn DrawableView<C>` can be drawn using [`Drawable::draw`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Circle, PrimitiveStyle, Rectangle},
+/// # };
+/// use embedded_layout::{prelude::*, view_group::DrawableView};
+///
+/// let style = PrimitiveStyle::with_fill(BinaryColor::On);
+/// let circle = Circle::new(Point::zero(), 5).into_styled(style);
+/// let rectangle = Rectangle::new(Point::new(6, 0), Size::new(5, 5)).into_styled(style);
+///
+/// let views: [&dyn DrawableView<BinaryColor>; 2] = [&circle, &rectangle];
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// for view in views {
+///     view.draw(&mut display).unwrap();
+/// }
+/// ```
+pub trait DrawableView<C>: View
+where
+    C: PixelColor,
+{
+    /// Draws the view to a type-erased draw target.
+    fn draw_dyn(&self, display: &mut DynDrawTarget<'_, C>) -> Result<(), DrawError>;
+
+    /// Returns a shared reference to the view as a [`View`] trait object.
+    fn as_view(&self) -> &dyn View;
+
+    /// Returns an exclusive reference to the view as a [`View`] trait object.
+    fn as_view_mut(&mut self) -> &mut dyn View;
+}
+
+impl<C, T> DrawableView<C> for T
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn draw_dyn(&self, display: &mut DynDrawTarget<'_, C>) -> Result<(), DrawError> {
+        self.draw(display)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn as_view(&self) -> &dyn View {
+        self
+    }
+
+    #[inline]
+    fn as_view_mut(&mut self) -> &mut dyn View {
+        self
+    }
+}
+
+impl<C> Drawable for dyn DrawableView<C> + '_
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut capture = ErrorCapture {
+            display,
+            error: None,
+        };
+
+        // The error returned by `draw_dyn` only signals that `capture` holds the actual error.
+        let _ = self.draw_dyn(&mut DynDrawTarget {
+            target: &mut capture,
+        });
+
+        match capture.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A [`ViewGroup`] whose [`View`] objects can be drawn.
+pub trait DrawableViewGroup<C>: ViewGroup
+where
+    C: PixelColor,
+{
+    /// Returns a shared reference to the drawable [`View`] object at position `idx`.
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C>;
+
+    /// Draws the part of the view group that is inside `clip`.
+    ///
+    /// Views that are entirely outside of `clip` are skipped without drawing them, and the other
+    /// views are clipped. This is faster than drawing every view into a clipped draw target when
+    /// only a small part of a large view group is visible, for example in a [`ScrollView`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{
+    /// #     mock_display::MockDisplay,
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     primitives::{PrimitiveStyle, Rectangle},
+    /// # };
+    /// use embedded_layout::{
+    ///     layout::linear::{FixedMargin, LinearLayout},
+    ///     prelude::*,
+    ///     view_group::DrawableViewGroup,
+    /// };
+    ///
+    /// let style = PrimitiveStyle::with_fill(BinaryColor::On);
+    /// let mut rows = [Rectangle::new(Point::zero(), Size::new(4, 2)).into_styled(style); 100];
+    ///
+    /// let layout = LinearLayout::vertical(Views::new(&mut rows))
+    ///     .with_spacing(FixedMargin(1))
+    ///     .arrange();
+    ///
+    /// // Only the first two rows are drawn, the rest are skipped.
+    /// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+    /// layout
+    ///     .inner()
+    ///     .draw_clipped(&mut display, &Rectangle::new(Point::zero(), Size::new(4, 4)))
+    ///     .unwrap();
+    ///
+    /// display.assert_pattern(&[
+    ///     "####", //
+    ///     "####", //
+    ///     "    ", //
+    ///     "####", //
+    /// ]);
+    /// ```
+    ///
+    /// [`ScrollView`]: crate::views::ScrollView
+    #[inline]
+    fn draw_clipped<D>(&self, display: &mut D, clip: &Rectangle) -> Result<(), D::Error>
+    where
+        Self: Sized,
+        D: DrawTarget<Color = C>,
+    {
+        let mut clipped = display.clipped(clip);
+
+        for i in 0..ViewGroup::len(self) {
+            if !self.bounds_of(i).intersection(clip).is_zero_sized() {
+                self.drawable_at(i).draw(&mut clipped)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draws the view group in stacking order.
+    ///
+    /// Views are drawn in increasing order of their [`View::z_index`], so views with a higher
+    /// z-index are drawn over the others. Views with the same z-index are drawn in the order of
+    /// their indexes. This lets overlapping views be declared in their logical order, e.g. for
+    /// keyboard navigation, and drawn in a different order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{
+    /// #     mock_display::MockDisplay,
+    /// #     pixelcolor::BinaryColor,
+    /// #     prelude::*,
+    /// #     primitives::{PrimitiveStyle, Rectangle},
+    /// # };
+    /// use embedded_layout::{prelude::*, view_group::DrawableViewGroup, views::ZOrder};
+    ///
+    /// let icon = Rectangle::new(Point::zero(), Size::new(4, 3))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+    /// let badge = Rectangle::new(Point::zero(), Size::new(2, 2))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off))
+    ///     .align_to(&icon, horizontal::Right, vertical::Top);
+    ///
+    /// // The badge comes first, but it is drawn over the icon
+    /// let views = Chain::new(ZOrder::new(badge, 1)).append(icon);
+    ///
+    /// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    /// views.draw_ordered(&mut display).unwrap();
+    ///
+    /// display.assert_pattern(&[
+    ///     "##..", //
+    ///     "##..", //
+    ///     "####", //
+    /// ]);
+    /// ```
+    #[inline]
+    fn draw_ordered<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        Self: Sized,
+        D: DrawTarget<Color = C>,
+    {
+        let len = ViewGroup::len(self);
+        let mut level = (0..len).map(|i| self.at(i).z_index()).min();
+
+        while let Some(current) = level {
+            let mut next = None;
+
+            for i in 0..len {
+                let z_index = self.at(i).z_index();
+                if z_index == current {
+                    self.drawable_at(i).draw(display)?;
+                } else if z_index > current && next.map_or(true, |next| z_index < next) {
+                    next = Some(z_index);
+                }
+            }
+
+            level = next;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, V> DrawableViewGroup<C> for Chain<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        assert_eq!(idx, 0);
+
+        &self.object
+    }
+}
+
+impl<C, V, VC> DrawableViewGroup<C> for Link<V, VC>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+    VC: DrawableViewGroup<C> + ChainElement,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        if idx == ViewGroup::len(self) - 1 {
+            return &self.object;
+        }
+
+        self.parent.drawable_at(idx)
+    }
+}
+
+impl<C, T> DrawableViewGroup<C> for Views<'_, T>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        &self[idx]
+    }
+}
+
+impl<C, T> DrawableViewGroup<C> for ViewRefs<'_, '_, T>
+where
+    C: PixelColor,
+    T: View + Drawable<Color = C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        &*self[idx]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::{FixedMargin, LinearLayout},
+        prelude::*,
+        view_group::ViewGroupHelper,
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{OriginDimensions, Point, Primitive, Size},
+        primitives::{Circle, PrimitiveStyle},
+    };
+
+    #[test]
+    fn draw_chain_through_trait_objects() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+        let circle = Circle::new(Point::zero(), 3).into_styled(style);
+
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(circle))
+            .with_alignment(vertical::Top)
+            .arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        ViewGroupHelper::draw(layout.inner(), &mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "## # ", //
+                "#####", "   # ",
+            ])
+        );
+    }
+
+    struct FailingDisplay;
+
+    impl OriginDimensions for FailingDisplay {
+        fn size(&self) -> Size {
+            Size::new(10, 10)
+        }
+    }
+
+    impl DrawTarget for FailingDisplay {
+        type Color = BinaryColor;
+        type Error = u8;
+
+        fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            Err(42)
+        }
+    }
+
+    #[test]
+    fn errors_are_forwarded() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let circle = Circle::new(Point::zero(), 3).into_styled(style);
+
+        let view: &dyn DrawableView<BinaryColor> = &circle;
+
+        assert_eq!(Err(42), view.draw(&mut FailingDisplay));
+    }
+
+    #[test]
+    fn draw_clipped_skips_invisible_views() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 2)).into_styled(style);
+
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(rect).append(rect))
+            .with_spacing(FixedMargin(1))
+            .arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout
+            .inner()
+            .draw_clipped(
+                &mut disp,
+                &Rectangle::new(Point::new(2, 1), Size::new(4, 2)),
+            )
+            .unwrap();
+
+        disp.assert_pattern(&[
+            "      ", //
+            "  # ##", //
+        ]);
+
+        // The display would fail if any view was drawn.
+        let outside = Rectangle::new(Point::new(0, 5), Size::new(10, 10));
+        assert_eq!(
+            Ok(()),
+            layout.inner().draw_clipped(&mut FailingDisplay, &outside)
+        );
+    }
+
+    #[test]
+    fn draw_ordered_uses_z_index_then_position() {
+        use crate::views::ZOrder;
+        use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+
+        let pixel = |color| {
+            Rectangle::new(Point::zero(), Size::new(1, 1))
+                .into_styled(PrimitiveStyle::with_fill(color))
+        };
+
+        let mut views = Chain::new(ZOrder::new(pixel(Rgb888::RED), 0))
+            .append(ZOrder::new(pixel(Rgb888::GREEN), -1))
+            .append(ZOrder::new(pixel(Rgb888::BLUE), 0));
+
+        let mut disp: MockDisplay<Rgb888> = MockDisplay::new();
+        disp.set_allow_overdraw(true);
+        views.draw_ordered(&mut disp).unwrap();
+        assert_eq!(Some(Rgb888::BLUE), disp.get_pixel(Point::zero()));
+
+        views.object.set_z_index(-2);
+
+        let mut disp: MockDisplay<Rgb888> = MockDisplay::new();
+        disp.set_allow_overdraw(true);
+        views.draw_ordered(&mut disp).unwrap();
+        assert_eq!(Some(Rgb888::RED), disp.get_pixel(Point::zero()));
+    }
+
+    #[test]
+    fn draw_ordered_uses_z_index_of_wrapped_views() {
+        use crate::views::{Visibility, ZOrder};
+        use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+
+        let pixel = |color| {
+            Rectangle::new(Point::zero(), Size::new(1, 1))
+                .into_styled(PrimitiveStyle::with_fill(color))
+        };
+
+        let views = Chain::new(Cached::new(ZOrder::new(pixel(Rgb888::RED), 2)))
+            .append(Visibility::new(ZOrder::new(pixel(Rgb888::GREEN), 1)))
+            .append(pixel(Rgb888::BLUE));
+
+        let mut disp: MockDisplay<Rgb888> = MockDisplay::new();
+        disp.set_allow_overdraw(true);
+        views.draw_ordered(&mut disp).unwrap();
+        assert_eq!(Some(Rgb888::RED), disp.get_pixel(Point::zero()));
+    }
+}
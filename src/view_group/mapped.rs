@@ -0,0 +1,252 @@
+use core::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{EmptyViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// A view group that produces its views from a slice of data.
+///
+/// The views are not stored: every time a view is measured or drawn, it is created by calling the
+/// mapping function with the corresponding data item, then moved to the position the view was
+/// arranged to. Only the position of every view is stored, in a slice of [`Point`]s that has to be
+/// at least as long as the data.
+///
+/// Because the views don't outlive the operation they are created for, [`ViewGroup::try_at`] and
+/// [`ViewGroup::try_at_mut`] return `None`. This means that the views can be arranged and drawn,
+/// but layouts can't use their optional properties, like baselines, or resize them.
+///
+/// # Panics
+///
+/// Creating a `MappedViews` object panics if there are fewer positions than data items.
+///
+/// [`ViewGroup::at`] and [`ViewGroup::at_mut`] always panic, because there is no view to return a
+/// reference to. Use [`ViewGroup::bounds_of`] and [`ViewGroup::translate_child`], or
+/// [`MappedViews::view`] to access the views.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     view_group::{MappedViews, ViewGroup},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let sensors = ["Temp", "Humidity", "Pressure"];
+/// let mut positions = [Point::zero(); 3];
+///
+/// let list = LinearLayout::vertical(MappedViews::new(&sensors, &mut positions, |name| {
+///     Text::new(name, Point::zero(), text_style)
+/// }))
+/// .arrange();
+///
+/// assert_eq!(Point::new(0, 9), list.inner().bounds_of(1).top_left);
+///
+/// # let mut display: embedded_graphics::mock_display::MockDisplay<BinaryColor> =
+/// #     embedded_graphics::mock_display::MockDisplay::new();
+/// list.draw(&mut display).unwrap();
+/// ```
+pub struct MappedViews<'a, D, V, F>
+where
+    F: Fn(&D) -> V,
+    V: View,
+{
+    data: &'a [D],
+    positions: &'a mut [Point],
+    map: F,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<'a, D, V, F> MappedViews<'a, D, V, F>
+where
+    F: Fn(&D) -> V,
+    V: View,
+{
+    /// Creates a view group from a slice of data, a slice to store the position of every view, and
+    /// a function that creates a view from a data item.
+    ///
+    /// The views are expected to be created at the origin. The positions are offsets relative to
+    /// where the mapping function places the views.
+    #[inline]
+    pub fn new(data: &'a [D], positions: &'a mut [Point], map: F) -> Self {
+        assert!(
+            positions.len() >= data.len(),
+            "Not enough positions for the data"
+        );
+
+        Self {
+            data,
+            positions,
+            map,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates the view at position `idx`, or returns `None` if `idx` is out of bounds.
+    ///
+    /// The returned view is moved to where it was arranged to.
+    #[inline]
+    pub fn view(&self, idx: usize) -> Option<V> {
+        let data = self.data.get(idx)?;
+
+        let mut view = (self.map)(data);
+        view.translate_impl(self.positions[idx]);
+
+        Some(view)
+    }
+
+    /// Returns the data the views are created from.
+    #[inline]
+    pub fn data(&self) -> &'a [D] {
+        self.data
+    }
+}
+
+impl<D, V, F> ViewGroup for MappedViews<'_, D, V, F>
+where
+    F: Fn(&D) -> V,
+    V: View,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        panic!("MappedViews can't return a reference to view {}", idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        panic!("MappedViews can't return a reference to view {}", idx)
+    }
+
+    #[inline]
+    fn try_at(&self, _idx: usize) -> Option<&dyn View> {
+        None
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, _idx: usize) -> Option<&mut dyn View> {
+        None
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.view(idx)
+            .map_or_else(|| EmptyViewGroup.bounds(), |view| view.bounds())
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        if let Some(position) = self.positions[..self.data.len()].get_mut(idx) {
+            *position += by;
+        }
+    }
+}
+
+impl<D, V, F> View for MappedViews<'_, D, V, F>
+where
+    F: Fn(&D) -> V,
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<C, D, V, F> Drawable for MappedViews<'_, D, V, F>
+where
+    C: PixelColor,
+    F: Fn(&D) -> V,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<DT>(&self, display: &mut DT) -> Result<(), DT::Error>
+    where
+        DT: DrawTarget<Color = C>,
+    {
+        for idx in 0..self.data.len() {
+            if let Some(view) = self.view(idx) {
+                view.draw(display)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn views_are_created_at_their_arranged_position() {
+        let levels = [3, 1, 2];
+        let mut positions = [Point::zero(); 3];
+
+        let bars = LinearLayout::horizontal(MappedViews::new(&levels, &mut positions, |&level| {
+            Rectangle::new(Point::zero(), Size::new(1, level))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+        }))
+        .with_alignment(vertical::Bottom)
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(1, 2), Size::new(1, 1)),
+            bars.inner().bounds_of(1)
+        );
+
+        let mut display = MockDisplay::new();
+        bars.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "#  ", //
+            "# #", //
+            "###", //
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "MappedViews can't return a reference to view 0")]
+    fn views_cant_be_borrowed() {
+        let levels = [3];
+        let mut positions = [Point::zero(); 1];
+
+        let views = MappedViews::new(&levels, &mut positions, |&level| {
+            Rectangle::new(Point::zero(), Size::new(1, level))
+        });
+
+        views.at(0);
+    }
+}
@@ -1,65 +1,247 @@
 //! ViewGroup definition and implementation for common types.
 
-use embedded_graphics::{prelude::Point, primitives::Rectangle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::PixelColor,
+    prelude::{Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
 
 use crate::{prelude::RectExt, View};
 
+mod builder;
+mod chunks;
+mod concat;
+mod drawable;
+mod mapped;
+mod named;
 mod object_chain;
+mod sub_group;
 mod views;
 
-pub use views::Views;
+pub use builder::{DynViewGroup, ViewGroupBuilder};
+pub use chunks::{Chunk, Chunks};
+pub use concat::Concat;
+pub use drawable::{DrawError, DrawableView, DrawableViewGroup, DynDrawTarget};
+pub use mapped::MappedViews;
+pub use sub_group::SubGroup;
+pub use views::{ViewRefs, Views};
 
 /// A set of operations required to implement [`View`] containers.
 pub trait ViewGroup: View {
     /// Returns the number of [`View`] objects in this view group.
     fn len(&self) -> usize;
 
+    /// Returns `true` if the view group contains no [`View`] objects.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Returns a shared reference the [`View`] object at position `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds. Use [`ViewGroup::try_at`] to handle out of bounds indexes.
     fn at(&self, idx: usize) -> &dyn View;
 
     /// Returns an exclusive reference to the [`View`] object at position `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds. Use [`ViewGroup::try_at_mut`] to handle out of bounds
+    /// indexes.
     fn at_mut(&mut self, idx: usize) -> &mut dyn View;
 
+    /// Returns a shared reference the [`View`] object at position `idx`, or `None` if `idx` is out
+    /// of bounds.
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        if idx < self.len() {
+            Some(self.at(idx))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an exclusive reference to the [`View`] object at position `idx`, or `None` if `idx`
+    /// is out of bounds.
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        if idx < self.len() {
+            Some(self.at_mut(idx))
+        } else {
+            None
+        }
+    }
+
     /// Returns the bounding box of the given View.
+    #[inline]
     fn bounds_of(&self, idx: usize) -> Rectangle {
         self.at(idx).bounds()
     }
 
     /// Translates the given View.
+    #[inline]
     fn translate_child(&mut self, idx: usize, by: Point) {
         self.at_mut(idx).translate_impl(by)
     }
+
+    /// Calls `f` with the index and an exclusive reference of every [`View`] object, in order.
+    ///
+    /// Use this method to update the content of the views of an already arranged view group. Note
+    /// that the view group is not arranged again, so changes that affect the size of views may
+    /// need a new call to `arrange`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::{layout::linear::LinearLayout, prelude::*, view_group::ViewGroup};
+    ///
+    /// let mut markers = [Rectangle::new(Point::zero(), Size::new(5, 1)); 3];
+    /// let levels = [4, 10, 7];
+    ///
+    /// let mut layout = LinearLayout::horizontal(Views::new(&mut markers)).arrange();
+    ///
+    /// // Move the markers to their levels without arranging them again
+    /// layout
+    ///     .inner_mut()
+    ///     .for_each_mut(|idx, marker| marker.translate_impl(Point::new(0, levels[idx])));
+    ///
+    /// assert_eq!(Point::new(5, 10), layout.inner().bounds_of(1).top_left);
+    /// ```
+    ///
+    /// Object chains have an inherent `for_each_mut` method that visits the concrete objects. Call
+    /// `ViewGroup::for_each_mut(&mut chain, f)` to use this method with object chains.
+    #[inline]
+    fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(usize, &mut dyn View),
+    {
+        for idx in 0..self.len() {
+            f(idx, self.at_mut(idx));
+        }
+    }
+
+    /// Returns the [`View`] object at position `idx` as [`Any`], or `None` if `idx` is out of
+    /// bounds or the view does not support downcasting.
+    ///
+    /// See [`Downcastable`] for more information and an example.
+    ///
+    /// [`Any`]: core::any::Any
+    /// [`Downcastable`]: crate::views::Downcastable
+    #[cfg(feature = "any")]
+    #[inline]
+    fn at_any(&self, idx: usize) -> Option<&dyn core::any::Any> {
+        self.try_at(idx).and_then(View::as_any)
+    }
+
+    /// Returns the [`View`] object at position `idx` as mutable [`Any`], or `None` if `idx` is out
+    /// of bounds or the view does not support downcasting.
+    ///
+    /// See [`Downcastable`] for more information and an example.
+    ///
+    /// [`Any`]: core::any::Any
+    /// [`Downcastable`]: crate::views::Downcastable
+    #[cfg(feature = "any")]
+    #[inline]
+    fn at_any_mut(&mut self, idx: usize) -> Option<&mut dyn core::any::Any> {
+        self.try_at_mut(idx).and_then(View::as_any_mut)
+    }
+}
+
+/// A [`ViewGroup`] that always contains the same number of [`View`] objects.
+///
+/// The number of views is known at compile time, so it can be used to size buffers, e.g. to store
+/// the measured size of every view. Object chains, [`EmptyViewGroup`] and types that use
+/// `derive(ViewGroup)` or [`view_group_struct!`] implement this trait.
+///
+/// Enums that use `derive(ViewGroup)` contain a different number of views depending on their
+/// variant. For them, `LEN` is the number of views in the largest variant, and
+/// [`ViewGroup::len`] may be smaller.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{prelude::*, view_group::ConstLenViewGroup};
+///
+/// type Row = Link<Rectangle, Chain<Rectangle>>;
+///
+/// // The number of views can be checked at compile time.
+/// const _: () = assert!(Row::LEN == 2);
+///
+/// fn sizes<VG: ConstLenViewGroup>(views: &VG) -> [Size; 4] {
+///     assert!(VG::LEN <= 4);
+///
+///     let mut sizes = [Size::zero(); 4];
+///     for (idx, size) in sizes.iter_mut().take(VG::LEN).enumerate() {
+///         *size = views.bounds_of(idx).size;
+///     }
+///     sizes
+/// }
+///
+/// let rect = Rectangle::new(Point::zero(), Size::new(1, 2));
+/// let row: Row = Chain::new(rect).append(rect);
+///
+/// assert_eq!(Size::new(1, 2), sizes(&row)[1]);
+/// ```
+///
+/// [`view_group_struct!`]: crate::view_group_struct
+pub trait ConstLenViewGroup: ViewGroup {
+    /// The number of [`View`] objects in this view group, or the maximum number of views for
+    /// derived enums.
+    const LEN: usize;
 }
 
 /// A [`ViewGroup`] that contains no [`View`] objects.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct EmptyViewGroup;
 
-/// A single instance of [`EmptyViewGroup`].
-pub static mut EMPTY_VIEW_GROUP: EmptyViewGroup = EmptyViewGroup;
-
 impl View for EmptyViewGroup {
+    #[inline]
     fn translate_impl(&mut self, _by: Point) {}
 
+    #[inline]
     fn bounds(&self) -> Rectangle {
         Rectangle::zero()
     }
 }
 
 impl ViewGroup for EmptyViewGroup {
+    #[inline]
     fn len(&self) -> usize {
         0
     }
 
-    fn at(&self, _idx: usize) -> &dyn View {
-        self
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        panic!("ViewGroup index out of bounds: {}", idx)
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        panic!("ViewGroup index out of bounds: {}", idx)
+    }
+
+    #[inline]
+    fn try_at(&self, _idx: usize) -> Option<&dyn View> {
+        None
     }
 
-    fn at_mut(&mut self, _idx: usize) -> &mut dyn View {
-        self
+    #[inline]
+    fn try_at_mut(&mut self, _idx: usize) -> Option<&mut dyn View> {
+        None
     }
 }
 
+impl ConstLenViewGroup for EmptyViewGroup {
+    const LEN: usize = 0;
+}
+
 /// Utility struct to simplify implementing [`View`] operations for any [`ViewGroup`].
 pub struct ViewGroupHelper;
 
@@ -75,16 +257,328 @@ impl ViewGroupHelper {
     /// Returns the smallest bounding box that envelopes all [`View`] objects in a view group.
     #[inline]
     pub fn bounds(vg: &impl ViewGroup) -> Rectangle {
-        if ViewGroup::len(vg) == 0 {
+        if ViewGroup::is_empty(vg) {
             return EmptyViewGroup.bounds();
         }
 
         let mut rect = vg.bounds_of(0);
 
         for i in 1..vg.len() {
-            rect = rect.enveloping(&vg.bounds_of(i));
+            rect = envelope_views(rect, vg.bounds_of(i));
         }
 
         rect
     }
+
+    /// Returns the index of the first selected [`View`] object in a view group.
+    #[inline]
+    pub fn selected(vg: &impl ViewGroup) -> Option<usize> {
+        (0..ViewGroup::len(vg)).find(|&i| vg.at(i).is_selected() == Some(true))
+    }
+
+    /// Selects the [`View`] object at position `idx` and deselects every other view.
+    #[inline]
+    pub fn select(vg: &mut impl ViewGroup, idx: usize) {
+        for i in 0..ViewGroup::len(vg) {
+            vg.at_mut(i).set_selected(i == idx);
+        }
+    }
+
+    /// Selects the next selectable [`View`] object after the selected one, wrapping around at the
+    /// end of the view group. Returns the index of the newly selected view.
+    ///
+    /// If no view is selected, the first selectable view is selected.
+    #[inline]
+    pub fn select_next(vg: &mut impl ViewGroup) -> Option<usize> {
+        let count = ViewGroup::len(vg);
+        let start = Self::selected(vg).map_or(0, |current| current + 1);
+
+        let next = (0..count)
+            .map(|i| (start + i) % count)
+            .find(|&i| vg.at(i).is_selected().is_some())?;
+
+        Self::select(vg, next);
+        Some(next)
+    }
+
+    /// Selects the previous selectable [`View`] object before the selected one, wrapping around
+    /// at the start of the view group. Returns the index of the newly selected view.
+    ///
+    /// If no view is selected, the last selectable view is selected.
+    #[inline]
+    pub fn select_prev(vg: &mut impl ViewGroup) -> Option<usize> {
+        let count = ViewGroup::len(vg);
+        let start = Self::selected(vg).unwrap_or(0);
+
+        let prev = (1..=count)
+            .map(|i| (start + count - i) % count)
+            .find(|&i| vg.at(i).is_selected().is_some())?;
+
+        Self::select(vg, prev);
+        Some(prev)
+    }
+
+    /// Draws every [`View`] object in a view group, in order.
+    #[inline]
+    pub fn draw<C, D>(vg: &impl DrawableViewGroup<C>, display: &mut D) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        for i in 0..ViewGroup::len(vg) {
+            vg.drawable_at(i).draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if a view with the given bounds is hidden, like a hidden [`Visibility`] view.
+///
+/// Hidden views take up no space. Views that are empty along only one axis, like spacers, are not
+/// hidden.
+///
+/// [`Visibility`]: crate::views::Visibility
+pub(crate) fn is_hidden(bounds: Rectangle) -> bool {
+    bounds.size == Size::zero()
+}
+
+/// Returns the bounding box of two views in a view group, ignoring hidden views.
+pub(crate) fn envelope_views(bounds: Rectangle, other: Rectangle) -> Rectangle {
+    if is_hidden(other) {
+        bounds
+    } else if is_hidden(bounds) {
+        other
+    } else {
+        bounds.enveloping(&other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::*,
+        primitives::{PrimitiveStyle, Rectangle, Styled},
+    };
+
+    use crate::{
+        layout::linear::LinearLayout,
+        prelude::*,
+        view_group::{ConstLenViewGroup, EmptyViewGroup, ViewGroup},
+        ViewGroup,
+    };
+
+    #[derive(ViewGroup)]
+    struct Pair<C: PixelColor> {
+        first: Styled<Rectangle, PrimitiveStyle<C>>,
+        second: Styled<Rectangle, PrimitiveStyle<C>>,
+    }
+
+    #[derive(ViewGroup)]
+    struct WhereClause<C>
+    where
+        C: PixelColor,
+    {
+        first: Styled<Rectangle, PrimitiveStyle<C>>,
+        second: Styled<Rectangle, PrimitiveStyle<C>>,
+    }
+
+    trait ThemeColor: PixelColor + From<BinaryColor> {}
+
+    impl ThemeColor for BinaryColor {}
+
+    #[derive(ViewGroup)]
+    #[viewgroup(color_param = C)]
+    struct ColorParam<C: ThemeColor> {
+        first: Styled<Rectangle, PrimitiveStyle<C>>,
+        second: Styled<Rectangle, PrimitiveStyle<C>>,
+    }
+
+    #[derive(ViewGroup)]
+    #[viewgroup(color = BinaryColor)]
+    struct ConcreteColor {
+        first: Styled<Rectangle, PrimitiveStyle<BinaryColor>>,
+        second: Styled<Rectangle, PrimitiveStyle<BinaryColor>>,
+    }
+
+    #[derive(ViewGroup)]
+    struct NoColor<V: View> {
+        first: V,
+        second: V,
+    }
+
+    #[derive(ViewGroup)]
+    enum Screen {
+        Blank,
+        Single(Rectangle),
+        Split(Rectangle, Rectangle),
+    }
+
+    const _: () = assert!(Pair::<BinaryColor>::LEN == 2);
+    const _: () = assert!(Screen::LEN == 2);
+
+    /// A view that doesn't implement `Clone`.
+    struct NotClone(Rectangle);
+
+    impl View for NotClone {
+        fn translate_impl(&mut self, by: Point) {
+            self.0.top_left += by;
+        }
+
+        fn bounds(&self) -> Rectangle {
+            self.0
+        }
+    }
+
+    #[derive(ViewGroup)]
+    struct NotCloneFields {
+        first: NotClone,
+        second: NotClone,
+    }
+
+    #[derive(ViewGroup)]
+    #[viewgroup(transform)]
+    struct Transformed {
+        first: Rectangle,
+        second: Rectangle,
+    }
+
+    fn rect<C: PixelColor + From<BinaryColor>>(x: i32) -> Styled<Rectangle, PrimitiveStyle<C>> {
+        Rectangle::new(Point::new(x, 0), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On.into()))
+    }
+
+    fn pair() -> Pair<BinaryColor> {
+        Pair {
+            first: rect(0),
+            second: rect(0),
+        }
+    }
+
+    fn assert_draws_two_rects(view_group: &impl Drawable<Color = BinaryColor>) {
+        let mut display = MockDisplay::new();
+        view_group.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "## ##", //
+            "## ##",
+        ]);
+    }
+
+    #[test]
+    fn derive_detects_color_param_bound() {
+        assert_draws_two_rects(&Pair {
+            first: rect(0),
+            second: rect(3),
+        });
+    }
+
+    #[test]
+    fn derive_detects_color_param_bound_in_where_clause() {
+        assert_draws_two_rects(&WhereClause {
+            first: rect(0),
+            second: rect(3),
+        });
+    }
+
+    #[test]
+    fn derive_uses_color_param_attribute() {
+        assert_draws_two_rects(&ColorParam {
+            first: rect::<BinaryColor>(0),
+            second: rect(3),
+        });
+    }
+
+    #[test]
+    fn derive_uses_color_attribute() {
+        assert_draws_two_rects(&ConcreteColor {
+            first: rect(0),
+            second: rect(3),
+        });
+    }
+
+    #[test]
+    fn derive_supports_fields_without_clone() {
+        let square = || NotClone(Rectangle::new(Point::zero(), Size::new(2, 2)));
+        let view_group = NotCloneFields {
+            first: square(),
+            second: square(),
+        };
+
+        let view_group = LinearLayout::horizontal(view_group).arrange().into_inner();
+
+        assert_eq!(Point::zero(), view_group.first.bounds().top_left);
+        assert_eq!(Point::new(2, 0), view_group.second.bounds().top_left);
+    }
+
+    #[test]
+    fn derive_implements_transform() {
+        let view_group = Transformed {
+            first: Rectangle::new(Point::zero(), Size::new(2, 2)),
+            second: Rectangle::new(Point::new(3, 0), Size::new(2, 2)),
+        };
+
+        let translated = Transform::translate(&view_group, Point::new(1, 2));
+
+        assert_eq!(Point::new(1, 2), translated.first.top_left);
+        assert_eq!(Point::new(4, 2), translated.second.top_left);
+        assert_eq!(Point::zero(), view_group.first.top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(1, 2), Size::new(5, 2)),
+            View::bounds(&translated)
+        );
+    }
+
+    #[test]
+    fn derived_enum_len_depends_on_variant() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        assert_eq!(0, ViewGroup::len(&Screen::Blank));
+        assert_eq!(1, ViewGroup::len(&Screen::Single(rect)));
+        assert_eq!(Screen::LEN, ViewGroup::len(&Screen::Split(rect, rect)));
+    }
+
+    #[test]
+    fn derive_without_color_param() {
+        let view_group = NoColor {
+            first: Rectangle::new(Point::zero(), Size::new(2, 2)),
+            second: Rectangle::new(Point::new(3, 0), Size::new(2, 2)),
+        };
+
+        assert_eq!(2, ViewGroup::len(&view_group));
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(5, 2)),
+            view_group.bounds()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn derived_at_panics_for_out_of_bounds_index() {
+        pair().at(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn derived_at_mut_panics_for_out_of_bounds_index() {
+        pair().at_mut(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chain_at_panics_for_out_of_bounds_index() {
+        Chain::new(pair()).append(pair()).at(2);
+    }
+
+    #[test]
+    fn try_at_returns_none_for_out_of_bounds_index() {
+        let mut pair = pair();
+
+        assert!(pair.try_at(1).is_some());
+        assert!(pair.try_at(2).is_none());
+        assert!(pair.try_at_mut(2).is_none());
+        assert!(EmptyViewGroup.try_at(0).is_none());
+    }
 }
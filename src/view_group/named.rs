@@ -0,0 +1,220 @@
+//! Struct-based view groups with named fields.
+
+/// Defines a struct with named view fields and implements [`ViewGroup`] for it.
+///
+/// Object chains are convenient, but their elements can only be accessed by position.
+/// `view_group_struct!` declares a plain struct instead, so the views can be accessed by name
+/// after arranging them. The order of the fields is the order of the views in the view group.
+///
+/// The color type after the struct name is used to implement `Drawable` and
+/// [`DrawableViewGroup`]. Only lifetime parameters are supported. Use `derive(ViewGroup)` for
+/// structs that need type parameters.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, view_group_struct};
+///
+/// type Label<'txt> = Text<'txt, MonoTextStyle<'static, BinaryColor>>;
+///
+/// view_group_struct! {
+///     /// The contents of the status screen.
+///     pub struct Status<'txt>: BinaryColor {
+///         pub title: Label<'txt>,
+///         pub body: Label<'txt>,
+///     }
+/// }
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let layout = LinearLayout::vertical(Status {
+///     title: Text::new("Status", Point::zero(), text_style),
+///     body: Text::new("All good", Point::zero(), text_style),
+/// })
+/// .arrange();
+///
+/// let status = layout.inner();
+/// assert!(status.body.bounds().top_left.y > status.title.bounds().top_left.y);
+/// ```
+///
+/// [`ViewGroup`]: crate::view_group::ViewGroup
+/// [`DrawableViewGroup`]: crate::view_group::DrawableViewGroup
+#[macro_export]
+macro_rules! view_group_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $(<$($lt:lifetime),+ $(,)?>)? : $color:ty {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name $(<$($lt),+>)? {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )+
+        }
+
+        impl $(<$($lt),+>)? $crate::View for $name $(<$($lt),+>)? {
+            #[inline]
+            fn translate_impl(&mut self, by: $crate::__private::Point) {
+                $crate::view_group::ViewGroupHelper::translate(self, by);
+            }
+
+            #[inline]
+            fn bounds(&self) -> $crate::__private::Rectangle {
+                $crate::view_group::ViewGroupHelper::bounds(self)
+            }
+        }
+
+        impl $(<$($lt),+>)? $crate::view_group::ViewGroup for $name $(<$($lt),+>)? {
+            #[inline]
+            fn len(&self) -> usize {
+                [$(stringify!($field)),+].len()
+            }
+
+            #[inline]
+            fn at(&self, index: usize) -> &dyn $crate::View {
+                match self.try_at(index) {
+                    Some(view) => view,
+                    None => panic!("ViewGroup index out of bounds: {}", index),
+                }
+            }
+
+            #[inline]
+            fn at_mut(&mut self, index: usize) -> &mut dyn $crate::View {
+                match self.try_at_mut(index) {
+                    Some(view) => view,
+                    None => panic!("ViewGroup index out of bounds: {}", index),
+                }
+            }
+
+            #[inline]
+            fn try_at(&self, index: usize) -> Option<&dyn $crate::View> {
+                [$(&self.$field as &dyn $crate::View),+].get(index).copied()
+            }
+
+            #[inline]
+            fn try_at_mut(&mut self, index: usize) -> Option<&mut dyn $crate::View> {
+                IntoIterator::into_iter([$(&mut self.$field as &mut dyn $crate::View),+]).nth(index)
+            }
+        }
+
+        impl $(<$($lt),+>)? $crate::view_group::ConstLenViewGroup for $name $(<$($lt),+>)? {
+            const LEN: usize = [$(stringify!($field)),+].len();
+        }
+
+        impl $(<$($lt),+>)? $crate::view_group::DrawableViewGroup<$color> for $name $(<$($lt),+>)? {
+            #[inline]
+            fn drawable_at(
+                &self,
+                index: usize,
+            ) -> &dyn $crate::view_group::DrawableView<$color> {
+                [$(&self.$field as &dyn $crate::view_group::DrawableView<$color>),+][index]
+            }
+        }
+
+        impl $(<$($lt),+>)? $crate::__private::Drawable for $name $(<$($lt),+>)? {
+            type Color = $color;
+            type Output = ();
+
+            #[inline]
+            fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+            where
+                D: $crate::__private::DrawTarget<Color = $color>,
+            {
+                $(
+                    $crate::__private::Drawable::draw(&self.$field, display)?;
+                )+
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        layout::linear::LinearLayout,
+        prelude::*,
+        view_group::{ConstLenViewGroup, ViewGroup},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Point, Primitive, Size},
+        primitives::{PrimitiveStyle, Rectangle, Styled},
+        Drawable,
+    };
+
+    type Filled = Styled<Rectangle, PrimitiveStyle<BinaryColor>>;
+
+    view_group_struct! {
+        struct Row: BinaryColor {
+            left: Filled,
+            right: Filled,
+        }
+    }
+
+    view_group_struct! {
+        struct Borrowed<'a>: BinaryColor {
+            rect: Filled,
+            others: Views<'a, Filled>,
+        }
+    }
+
+    #[test]
+    fn fields_are_accessible_by_name() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+
+        let layout = LinearLayout::horizontal(Row {
+            left: rect,
+            right: rect,
+        })
+        .arrange();
+
+        let row = layout.inner();
+        assert_eq!(2, row.len());
+        assert_eq!(2, Row::LEN);
+        assert_eq!(Point::new(2, 0), row.right.bounds().top_left);
+        assert!(row.try_at(2).is_none());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "####", //
+            "####", //
+        ]);
+    }
+
+    #[test]
+    fn lifetime_parameters() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let mut rects = [Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style)];
+
+        let mut group = Borrowed {
+            rect: Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style),
+            others: Views::new(&mut rects),
+        };
+        group.translate_mut(Point::new(1, 1));
+
+        assert_eq!(Point::new(1, 1), group.rect.primitive.top_left);
+        assert_eq!(Point::new(1, 1), group.others[0].primitive.top_left);
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+            group.bounds()
+        );
+    }
+}
@@ -7,8 +7,7 @@ use embedded_graphics::{
 
 use crate::{
     object_chain::{Chain, ChainElement, Link},
-    prelude::RectExt,
-    view_group::ViewGroup,
+    view_group::{envelope_views, ConstLenViewGroup, ViewGroup},
     View,
 };
 
@@ -40,9 +39,7 @@ where
 {
     #[inline]
     fn bounds(&self) -> Rectangle {
-        let bounds = self.object.bounds();
-
-        bounds.enveloping(&self.parent.bounds())
+        envelope_views(self.object.bounds(), self.parent.bounds())
     }
 
     #[inline]
@@ -113,6 +110,24 @@ where
         self.parent.at_mut(index)
     }
 
+    #[inline]
+    fn try_at(&self, index: usize) -> Option<&dyn View> {
+        if index == ViewGroup::len(self) - 1 {
+            return Some(&self.object);
+        }
+
+        self.parent.try_at(index)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, index: usize) -> Option<&mut dyn View> {
+        if index == ViewGroup::len(self) - 1 {
+            return Some(&mut self.object);
+        }
+
+        self.parent.try_at_mut(index)
+    }
+
     #[inline]
     fn bounds_of(&self, index: usize) -> Rectangle {
         if index == ViewGroup::len(self) - 1 {
@@ -132,6 +147,14 @@ where
     }
 }
 
+impl<V, VC> ConstLenViewGroup for Link<V, VC>
+where
+    V: View,
+    VC: ConstLenViewGroup + ChainElement,
+{
+    const LEN: usize = VC::LEN + 1;
+}
+
 impl<V> ViewGroup for Chain<V>
 where
     V: View,
@@ -155,6 +178,24 @@ where
         &mut self.object
     }
 
+    #[inline]
+    fn try_at(&self, index: usize) -> Option<&dyn View> {
+        if index == 0 {
+            Some(&self.object)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, index: usize) -> Option<&mut dyn View> {
+        if index == 0 {
+            Some(&mut self.object)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn bounds_of(&self, index: usize) -> Rectangle {
         assert_eq!(index, 0);
@@ -169,3 +210,31 @@ where
         self.object.translate_impl(by)
     }
 }
+
+impl<V> ConstLenViewGroup for Chain<V>
+where
+    V: View,
+{
+    const LEN: usize = 1;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{prelude::*, view_group::ViewGroup};
+    use embedded_graphics::{
+        prelude::{Point, Size},
+        primitives::Rectangle,
+    };
+
+    #[test]
+    fn try_at_out_of_bounds() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+
+        let mut chain = Chain::new(rect).append(rect.translate(Point::new(1, 1)));
+
+        assert_eq!(Some(rect), chain.try_at(0).map(|view| view.bounds()));
+        assert!(chain.try_at(2).is_none());
+        assert!(chain.try_at_mut(2).is_none());
+        assert!(chain.parent.try_at(1).is_none());
+    }
+}
@@ -0,0 +1,243 @@
+use core::ops::Range;
+
+use embedded_graphics::{
+    draw_target::DrawTarget, pixelcolor::PixelColor, prelude::Point, primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup, ViewGroupHelper},
+    View,
+};
+
+/// A range of [`View`] objects in a parent [`ViewGroup`], exposed as a [`ViewGroup`].
+///
+/// Index `0` of the sub-group is the first index of the range in the parent. This makes it
+/// possible to arrange a part of a view group using a different layout than the rest of it, for
+/// example to place the header items of a screen in one row and the footer items in another.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     view_group::{EmptyViewGroup, SubGroup, ViewGroup},
+/// };
+///
+/// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+/// let mut items = Chain::new(rect).append(rect).append(rect).append(rect);
+///
+/// LinearLayout::horizontal(EmptyViewGroup).arrange_in_place(&mut SubGroup::new(&mut items, 0..2));
+/// LinearLayout::horizontal(EmptyViewGroup)
+///     .translate(Point::new(0, 10))
+///     .arrange_in_place(&mut SubGroup::new(&mut items, 2..4));
+///
+/// assert_eq!(Point::new(5, 0), items.bounds_of(1).top_left);
+/// assert_eq!(Point::new(5, 10), items.bounds_of(3).top_left);
+/// ```
+pub struct SubGroup<'a, VG> {
+    parent: &'a mut VG,
+    range: Range<usize>,
+}
+
+impl<'a, VG> SubGroup<'a, VG>
+where
+    VG: ViewGroup,
+{
+    /// Exposes the views of `parent` in `range` as a view group.
+    ///
+    /// The range is limited to the views that exist in `parent`.
+    #[inline]
+    pub fn new(parent: &'a mut VG, range: Range<usize>) -> Self {
+        let end = range.end.min(parent.len());
+        let start = range.start.min(end);
+
+        Self {
+            parent,
+            range: start..end,
+        }
+    }
+
+    /// Returns the range of indexes in the parent view group.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    fn parent_index(&self, idx: usize) -> Option<usize> {
+        if idx < self.range.len() {
+            Some(self.range.start + idx)
+        } else {
+            None
+        }
+    }
+}
+
+impl<VG> View for SubGroup<'_, VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<VG> ViewGroup for SubGroup<'_, VG>
+where
+    VG: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        match self.parent_index(idx) {
+            Some(idx) => self.parent.at(idx),
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        match self.parent_index(idx) {
+            Some(idx) => self.parent.at_mut(idx),
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        let idx = self.parent_index(idx)?;
+        self.parent.try_at(idx)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        let idx = self.parent_index(idx)?;
+        self.parent.try_at_mut(idx)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        match self.parent_index(idx) {
+            Some(idx) => self.parent.bounds_of(idx),
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        match self.parent_index(idx) {
+            Some(idx) => self.parent.translate_child(idx, by),
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+}
+
+impl<C, VG> DrawableViewGroup<C> for SubGroup<'_, VG>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        match self.parent_index(idx) {
+            Some(idx) => self.parent.drawable_at(idx),
+            None => panic!("ViewGroup index out of bounds: {}", idx),
+        }
+    }
+}
+
+impl<C, VG> Drawable for SubGroup<'_, VG>
+where
+    C: PixelColor,
+    VG: DrawableViewGroup<C> + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        ViewGroupHelper::draw(self, display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::LinearLayout,
+        prelude::*,
+        view_group::{EmptyViewGroup, MappedViews},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn indexes_are_offset() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+        let mut views = [rect, rect.translate(Point::new(1, 0)), rect];
+        let mut views = Views::new(&mut views);
+
+        let mut group = SubGroup::new(&mut views, 1..5);
+
+        assert_eq!(1..3, group.range());
+        assert_eq!(2, group.len());
+        assert_eq!(Point::new(1, 0), group.bounds_of(0).top_left);
+        assert!(group.try_at(2).is_none());
+
+        group.translate_mut(Point::new(0, 2));
+        assert_eq!(
+            Rectangle::new(Point::new(0, 2), Size::new(2, 1)),
+            group.bounds()
+        );
+        assert_eq!(Point::zero(), views[0].top_left);
+    }
+
+    #[test]
+    fn only_the_range_is_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style);
+        let mut views = Chain::new(rect)
+            .append(rect.translate(Point::new(1, 0)))
+            .append(rect.translate(Point::new(2, 0)));
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        SubGroup::new(&mut views, 1..2).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[" #"]);
+    }
+
+    #[test]
+    fn children_are_measured_and_moved_by_the_parent() {
+        let widths = [1, 2, 3, 4];
+        let mut positions = [Point::zero(); 4];
+        let mut views = MappedViews::new(&widths, &mut positions, |&width| {
+            Rectangle::new(Point::zero(), Size::new(width, 1))
+        });
+
+        LinearLayout::horizontal(EmptyViewGroup)
+            .arrange_in_place(&mut SubGroup::new(&mut views, 1..3));
+
+        assert_eq!(Point::zero(), views.bounds_of(0).top_left);
+        assert_eq!(Point::zero(), views.bounds_of(1).top_left);
+        assert_eq!(Point::new(2, 0), views.bounds_of(2).top_left);
+    }
+}
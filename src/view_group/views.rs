@@ -27,6 +27,50 @@ where
     pub fn new(views: &'a mut [T]) -> Self {
         Self { views }
     }
+
+    /// Divides the views into two view groups at an index.
+    ///
+    /// The first group contains the views in `[0, mid)`, the second group contains the views in
+    /// `[mid, len)`. This makes it possible to arrange parts of the same array using different
+    /// layouts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+    ///
+    /// let mut items = [Rectangle::new(Point::zero(), Size::new(5, 5)); 5];
+    ///
+    /// let (header, footer) = Views::new(&mut items).split_at(3);
+    ///
+    /// let header = LinearLayout::horizontal(header).arrange();
+    /// let footer = LinearLayout::horizontal(footer)
+    ///     .arrange()
+    ///     .translate(Point::new(0, 20));
+    ///
+    /// assert_eq!(3, header.inner().len());
+    /// assert_eq!(2, footer.inner().len());
+    /// ```
+    #[inline]
+    pub fn split_at(self, mid: usize) -> (Views<'a, T>, Views<'a, T>) {
+        let (first, second) = self.views.split_at_mut(mid);
+
+        (Views::new(first), Views::new(second))
+    }
+
+    /// Wraps a slice of references to views.
+    ///
+    /// Use this function to group views that are stored in different places, e.g. in the fields of
+    /// a struct, without moving them into a single array. See [`ViewRefs`] for more information.
+    #[inline]
+    pub fn new_from_refs<'r>(views: &'a mut [&'r mut T]) -> ViewRefs<'a, 'r, T> {
+        ViewRefs::new(views)
+    }
 }
 
 impl<T> ViewGroup for Views<'_, T>
@@ -48,6 +92,16 @@ where
         &mut self.views[idx]
     }
 
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.get(idx).map(|view| view as &dyn View)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views.get_mut(idx).map(|view| view as &mut dyn View)
+    }
+
     #[inline]
     fn bounds_of(&self, idx: usize) -> Rectangle {
         self.views[idx].bounds()
@@ -118,6 +172,154 @@ where
     }
 }
 
+/// Wrapper that implements ViewGroup for a slice of references to views.
+///
+/// `ViewRefs` works like [`Views`], but it doesn't own the storage of the views. This makes it
+/// possible to arrange views that are stored in different places as a single view group.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*};
+///
+/// struct Screen {
+///     title: Rectangle,
+///     body: Rectangle,
+/// }
+///
+/// let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+/// let mut screen = Screen {
+///     title: rect,
+///     body: rect,
+/// };
+/// let mut footer = rect;
+///
+/// let mut refs = [&mut screen.title, &mut screen.body, &mut footer];
+/// LinearLayout::vertical(Views::new_from_refs(&mut refs)).arrange();
+///
+/// assert_eq!(Point::new(0, 5), screen.body.top_left);
+/// assert_eq!(Point::new(0, 10), footer.top_left);
+/// ```
+pub struct ViewRefs<'a, 'r, T>
+where
+    T: View,
+{
+    views: &'a mut [&'r mut T],
+}
+
+impl<'a, 'r, T> ViewRefs<'a, 'r, T>
+where
+    T: View,
+{
+    /// Wraps the given slice of references.
+    #[inline]
+    pub fn new(views: &'a mut [&'r mut T]) -> Self {
+        Self { views }
+    }
+}
+
+impl<T> ViewGroup for ViewRefs<'_, '_, T>
+where
+    T: View,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        &*self.views[idx]
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        &mut *self.views[idx]
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        self.views.get(idx).map(|view| &**view as &dyn View)
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        self.views
+            .get_mut(idx)
+            .map(|view| &mut **view as &mut dyn View)
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        self.views[idx].bounds()
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        self.views[idx].translate_impl(by)
+    }
+}
+
+impl<T> View for ViewRefs<'_, '_, T>
+where
+    T: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        ViewGroupHelper::translate(self, by)
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        ViewGroupHelper::bounds(self)
+    }
+}
+
+impl<'a, 'r, T> Deref for ViewRefs<'a, 'r, T>
+where
+    T: View,
+{
+    type Target = [&'r mut T];
+
+    #[inline]
+    fn deref(&self) -> &[&'r mut T] {
+        self.views
+    }
+}
+
+impl<'a, 'r, T> DerefMut for ViewRefs<'a, 'r, T>
+where
+    T: View,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [&'r mut T] {
+        self.views
+    }
+}
+
+impl<C, T> Drawable for ViewRefs<'_, '_, T>
+where
+    C: PixelColor,
+    T: View,
+    T: Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        for view in self.views.iter() {
+            view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -137,6 +339,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::iter_count)]
     fn views_behaves_as_slice() {
         let mut views = [
             Line::new(Point::zero(), Point::new(1, 2)),
@@ -149,4 +352,53 @@ mod test {
         // deliberate count() because Views only exposes `iter()` through `Deref`.
         assert_eq!(1, vg[1..2].iter().count());
     }
+
+    #[test]
+    fn try_at_out_of_bounds() {
+        let mut views = [
+            Line::new(Point::zero(), Point::new(1, 2)),
+            Line::new(Point::new(1, 2), Point::new(3, 1)),
+        ];
+
+        let mut vg = Views::new(&mut views);
+
+        assert!(vg.try_at(1).is_some());
+        assert!(vg.try_at(2).is_none());
+        assert!(vg.try_at_mut(2).is_none());
+    }
+
+    #[test]
+    fn split_at_divides_views() {
+        let mut views = [
+            Line::new(Point::zero(), Point::new(1, 2)),
+            Line::new(Point::new(1, 2), Point::new(3, 1)),
+            Line::new(Point::new(3, 1), Point::zero()),
+        ];
+
+        let (mut first, second) = Views::new(&mut views).split_at(1);
+        first.translate_mut(Point::new(1, 1));
+
+        assert_eq!(1, first.len());
+        assert_eq!(2, second.len());
+        assert_eq!(Point::new(1, 1), views[0].start);
+        assert_eq!(Point::new(1, 2), views[1].start);
+    }
+
+    #[test]
+    fn refs_translate_referenced_views() {
+        let mut first = Line::new(Point::zero(), Point::new(1, 2));
+        let mut second = Line::new(Point::new(1, 2), Point::new(3, 1));
+
+        let mut refs = [&mut first, &mut second];
+        let mut vg = Views::new_from_refs(&mut refs);
+
+        assert_eq!(2, vg.len());
+        assert!(vg.try_at(2).is_none());
+
+        vg.translate_child(1, Point::new(1, 1));
+        vg.translate_mut(Point::new(0, 1));
+
+        assert_eq!(Point::new(0, 1), first.start);
+        assert_eq!(Point::new(2, 4), second.start);
+    }
 }
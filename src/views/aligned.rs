@@ -0,0 +1,195 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{align::Alignment, View};
+
+/// Overrides the secondary alignment of a single view in a layout.
+///
+/// Layouts like [`LinearLayout`] apply the same secondary alignment to every view. Wrapping a view
+/// in `Aligned` places that view using the given alignment instead, without affecting how the
+/// other views are placed.
+///
+/// The alignment must work on the secondary axis of the layout, i.e. use a [`horizontal`]
+/// alignment in vertical layouts and a [`vertical`] alignment in horizontal layouts.
+///
+/// # Example
+///
+/// Create a left-aligned list with one right-aligned item:
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::Aligned};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let _ = LinearLayout::vertical(
+///     Chain::new(Text::new("Left aligned", Point::zero(), text_style))
+///         .append(Aligned::new(
+///             Text::new("Right", Point::zero(), text_style),
+///             horizontal::Right,
+///         ))
+///         .append(Text::new("Left again", Point::zero(), text_style)),
+/// )
+/// .with_alignment(horizontal::Left)
+/// .arrange();
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`horizontal`]: crate::align::horizontal
+/// [`vertical`]: crate::align::vertical
+pub struct Aligned<V, A> {
+    view: V,
+    alignment: A,
+}
+
+impl<V, A> Aligned<V, A>
+where
+    V: View,
+    A: Alignment,
+{
+    /// Wraps `view` to be placed using `alignment`.
+    #[inline]
+    pub fn new(view: V, alignment: A) -> Self {
+        Self { view, alignment }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V, A> Clone for Aligned<V, A>
+where
+    V: Clone,
+    A: Alignment,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            alignment: self.alignment,
+        }
+    }
+}
+
+impl<V, A> View for Aligned<V, A>
+where
+    V: View,
+    A: Alignment,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn secondary_alignment_override(&self, reference: Rectangle) -> Option<i32> {
+        Some(self.alignment.align(self.view.bounds(), reference))
+    }
+
+    forward_view_hooks!(field view; except secondary_alignment_override);
+}
+
+impl<C, V, A> Drawable for Aligned<V, A>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+    A: Alignment,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn override_does_not_affect_other_views() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let wide = Rectangle::new(Point::zero(), Size::new(6, 2)).into_styled(style);
+        let narrow = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+
+        LinearLayout::vertical(
+            Chain::new(wide)
+                .append(Aligned::new(narrow, horizontal::Right))
+                .append(narrow),
+        )
+        .arrange()
+        .draw(&mut disp)
+        .unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "######", //
+                "######", "    ##", "    ##", "##    ", "##    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn horizontal_layout_override() {
+        let small = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let tall = Rectangle::new(Point::zero(), Size::new(2, 6));
+
+        let layout = LinearLayout::horizontal(
+            Chain::new(tall)
+                .append(Aligned::new(small, vertical::Top))
+                .append(small),
+        )
+        .arrange();
+
+        let views = layout.into_inner();
+
+        assert_eq!(Point::new(2, 0), views.parent.object.bounds().top_left);
+        assert_eq!(Point::new(4, 4), views.object.bounds().top_left);
+    }
+}
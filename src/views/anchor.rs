@@ -0,0 +1,188 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::{Circle, Ellipse, Rectangle},
+    text::{renderer::TextRenderer, Text},
+    Drawable,
+};
+
+use crate::View;
+
+/// A [`View`] that has a visual anchor point other than the corners of its bounds.
+///
+/// Implement this trait for views that get their [`View`] implementation from
+/// `embedded-graphics`, and wrap them in [`WithAnchor`] to make their anchor available to
+/// alignments. Custom views can implement [`View::anchor`] directly instead.
+pub trait AnchorView: View {
+    /// Returns the anchor point of the view.
+    fn anchor_point(&self) -> Point;
+}
+
+impl<S> AnchorView for Text<'_, S>
+where
+    S: TextRenderer + Clone,
+{
+    /// Returns the position of the text, i.e. the point that the text style's alignment and
+    /// baseline refer to.
+    #[inline]
+    fn anchor_point(&self) -> Point {
+        self.position
+    }
+}
+
+impl AnchorView for Circle {
+    /// Returns the center of the circle.
+    #[inline]
+    fn anchor_point(&self) -> Point {
+        self.center()
+    }
+}
+
+impl AnchorView for Ellipse {
+    /// Returns the center of the ellipse.
+    #[inline]
+    fn anchor_point(&self) -> Point {
+        self.center()
+    }
+}
+
+/// Makes the anchor point of the wrapped view available to alignments.
+///
+/// Use this wrapper together with [`Align::align_anchor_to`] to position views by their visual
+/// reference point, instead of their bounds.
+///
+/// # Example
+///
+/// Place a text on the bottom edge of a box, so that the descenders hang below it:
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::Rectangle,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{prelude::*, views::WithAnchor};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let frame = Rectangle::new(Point::new(10, 10), Size::new(40, 20));
+///
+/// // Text uses the alphabetic baseline by default
+/// let label = WithAnchor::new(Text::new("Jpg", Point::zero(), text_style)).align_anchor_to(
+///     &frame,
+///     horizontal::Left,
+///     vertical::Bottom,
+/// );
+///
+/// assert_eq!(Point::new(10, 29), label.inner().position);
+/// ```
+///
+/// [`Align::align_anchor_to`]: crate::align::Align::align_anchor_to
+pub struct WithAnchor<V> {
+    view: V,
+}
+
+impl<V> WithAnchor<V>
+where
+    V: AnchorView,
+{
+    /// Wraps the given view.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self { view }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for WithAnchor<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+        }
+    }
+}
+
+impl<V> View for WithAnchor<V>
+where
+    V: AnchorView,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn anchor(&self) -> Option<Point> {
+        Some(self.view.anchor_point())
+    }
+
+    forward_view_hooks!(field view; except anchor);
+}
+
+impl<C, V> Drawable for WithAnchor<V>
+where
+    C: PixelColor,
+    V: AnchorView + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use embedded_graphics::prelude::Size;
+
+    #[test]
+    fn views_without_anchor_are_aligned_by_bounds() {
+        let reference = Rectangle::new(Point::new(10, 10), Size::new(10, 10));
+        let circle = Circle::new(Point::zero(), 5);
+
+        assert_eq!(
+            circle.align_to(&reference, horizontal::Right, vertical::Top),
+            circle.align_anchor_to(&reference, horizontal::Right, vertical::Top)
+        );
+
+        let anchored =
+            WithAnchor::new(circle).align_anchor_to(&reference, horizontal::Right, vertical::Top);
+        assert_eq!(Point::new(19, 10), anchored.inner().center());
+    }
+}
@@ -0,0 +1,244 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::AnchorPoint,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::{horizontal, vertical, Align},
+    layout::ResizableView,
+    View,
+};
+
+/// Keeps a view at a fixed aspect ratio within an available area.
+///
+/// The bounds of `AspectRatio` are the largest rectangle with the given ratio that fits into the
+/// available area, centered in it. The wrapped view is resized to these bounds if it is
+/// resizable, and centered in them. The rest of the area is left empty, like the letterbox around
+/// a video.
+///
+/// This is useful to fit images and gauges into layout cells whose size is only known at runtime.
+/// When placed in a layout that resizes its views, the available area is changed to the size
+/// assigned by the layout.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{prelude::*, primitives::Rectangle};
+/// use embedded_layout::{prelude::*, views::AspectRatio};
+///
+/// let gauge = Rectangle::new(Point::zero(), Size::new(4, 3));
+/// let cell = Rectangle::new(Point::new(0, 10), Size::new(40, 20));
+///
+/// let gauge = AspectRatio::new(gauge, 1, 1).with_area(cell);
+///
+/// assert_eq!(Rectangle::new(Point::new(10, 10), Size::new(20, 20)), gauge.bounds());
+/// assert_eq!(Point::new(18, 18), gauge.inner().top_left);
+/// ```
+pub struct AspectRatio<V> {
+    view: V,
+    ratio: (u32, u32),
+    area: Rectangle,
+}
+
+impl<V> AspectRatio<V>
+where
+    V: View,
+{
+    /// Keeps `view` at the aspect ratio of `width`:`height`.
+    ///
+    /// The available area is initially the bounds of `view`.
+    #[inline]
+    pub fn new(view: V, width: u32, height: u32) -> Self {
+        let mut this = Self {
+            area: view.bounds(),
+            view,
+            ratio: (width, height),
+        };
+        this.place_view();
+        this
+    }
+
+    /// Sets the area the view is placed in.
+    #[inline]
+    #[must_use]
+    pub fn with_area(mut self, area: Rectangle) -> Self {
+        self.set_area(area);
+        self
+    }
+
+    /// Sets the area the view is placed in.
+    #[inline]
+    pub fn set_area(&mut self, area: Rectangle) {
+        self.area = area;
+        self.place_view();
+    }
+
+    /// Returns the area the view is placed in.
+    #[inline]
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+
+    /// Returns the aspect ratio as a `(width, height)` pair.
+    #[inline]
+    pub fn ratio(&self) -> (u32, u32) {
+        self.ratio
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    ///
+    /// The view is not placed again until the area is changed.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the object and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+
+    /// Resizes and centers the view in the bounds.
+    fn place_view(&mut self) {
+        let bounds = self.bounds();
+
+        if let Some(view) = self.view.as_resizable_mut() {
+            view.resize(bounds.size);
+        }
+
+        self.view
+            .align_to_mut(&bounds, horizontal::Center, vertical::Center);
+    }
+}
+
+impl<V> Clone for AspectRatio<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            ratio: self.ratio,
+            area: self.area,
+        }
+    }
+}
+
+impl<V> View for AspectRatio<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.area.top_left += by;
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let (width, height) = (self.area.size.width as u64, self.area.size.height as u64);
+        let (ratio_w, ratio_h) = (self.ratio.0 as u64, self.ratio.1 as u64);
+
+        let size = if ratio_w == 0 || ratio_h == 0 {
+            Size::zero()
+        } else if width * ratio_h <= height * ratio_w {
+            Size::new(width as u32, (width * ratio_h / ratio_w) as u32)
+        } else {
+            Size::new((height * ratio_w / ratio_h) as u32, height as u32)
+        };
+
+        self.area.resized(size, AnchorPoint::Center)
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        Some(self)
+    }
+
+    forward_view_hooks!(field view; except as_measurable_mut, as_resizable_mut);
+}
+
+impl<V> ResizableView for AspectRatio<V>
+where
+    V: View,
+{
+    /// Changes the size of the available area.
+    #[inline]
+    fn resize(&mut self, size: Size) {
+        let area = Rectangle::new(self.bounds().top_left, size);
+        self.set_area(area);
+    }
+}
+
+impl<C, V> Drawable for AspectRatio<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::views::Marquee;
+
+    #[test]
+    fn bounds_are_letterboxed() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+        let area = Rectangle::new(Point::new(2, 2), Size::new(10, 4));
+
+        let wide = AspectRatio::new(rect, 2, 1).with_area(area);
+        assert_eq!(
+            Rectangle::new(Point::new(3, 2), Size::new(8, 4)),
+            wide.bounds()
+        );
+
+        let tall = AspectRatio::new(rect, 1, 4).with_area(area);
+        assert_eq!(
+            Rectangle::new(Point::new(6, 2), Size::new(1, 4)),
+            tall.bounds()
+        );
+
+        let empty = AspectRatio::new(rect, 0, 1).with_area(area);
+        assert_eq!(Size::zero(), empty.size());
+    }
+
+    #[test]
+    fn resizable_view_is_resized_to_bounds() {
+        let rect = Rectangle::new(Point::zero(), Size::new(1, 1));
+        let area = Rectangle::new(Point::zero(), Size::new(10, 10));
+
+        let mut view = AspectRatio::new(Marquee::new(rect, 1), 2, 1).with_area(area);
+        assert_eq!(Size::new(10, 5), view.size());
+        assert_eq!(view.bounds(), view.inner().bounds());
+
+        let top_left = view.bounds().top_left;
+        view.resize(Size::new(3, 6));
+        assert_eq!(Rectangle::new(top_left, Size::new(3, 6)), view.area());
+        assert_eq!(Size::new(3, 1), view.inner().size());
+        assert_eq!(view.bounds(), view.inner().bounds());
+    }
+}
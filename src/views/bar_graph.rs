@@ -0,0 +1,269 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    align::horizontal,
+    layout::linear::spacing::{ElementSpacing, Tight},
+    View,
+};
+
+/// A bar graph that shows the values of a data slice as vertical bars.
+///
+/// The height of a bar is proportional to its value, where `max` fills the height of the graph.
+/// Bars are placed from left to right using an [`ElementSpacing`], the same way views are placed
+/// in a horizontal [`LinearLayout`]: [`Tight`] by default, [`FixedMargin`] for gaps of a given
+/// width, or [`DistributeFill`] to spread the bars over the width of the graph.
+///
+/// By default, the width of the graph is divided evenly between the bars. The bar positions are
+/// computed when the graph is drawn, so changing the data doesn't require arranging the graph.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+/// use embedded_layout::{
+///     layout::linear::spacing::DistributeFill,
+///     prelude::*,
+///     views::BarGraph,
+/// };
+///
+/// let samples = [12, 40, 33, 8, 25];
+///
+/// let graph = BarGraph::new(&samples, 40, Size::new(60, 20), BinaryColor::On)
+///     .with_bar_width(8)
+///     .with_spacing(DistributeFill(60));
+///
+/// // 5 bars with 5px gaps between them
+/// assert_eq!(Some(Rectangle::new(Point::new(13, 0), Size::new(8, 20))), graph.bar_bounds(1));
+/// assert_eq!(Some(Rectangle::new(Point::new(52, 8), Size::new(8, 12))), graph.bar_bounds(4));
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`FixedMargin`]: crate::layout::linear::spacing::FixedMargin
+/// [`DistributeFill`]: crate::layout::linear::spacing::DistributeFill
+#[derive(Copy, Clone)]
+pub struct BarGraph<'a, C, S = Tight> {
+    bounds: Rectangle,
+    data: &'a [u32],
+    max: u32,
+    bar_width: Option<u32>,
+    spacing: S,
+    color: C,
+}
+
+impl<'a, C> BarGraph<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a bar graph of the given size that shows `data` using `color`.
+    ///
+    /// The graph is placed at the origin. Values greater than or equal to `max` fill the height of
+    /// the graph.
+    #[inline]
+    pub fn new(data: &'a [u32], max: u32, size: Size, color: C) -> Self {
+        Self {
+            bounds: Rectangle::new(Point::zero(), size),
+            data,
+            max,
+            bar_width: None,
+            spacing: Tight,
+            color,
+        }
+    }
+}
+
+impl<'a, C, S> BarGraph<'a, C, S>
+where
+    C: PixelColor,
+    S: ElementSpacing,
+{
+    /// Sets the width of the bars.
+    #[inline]
+    #[must_use]
+    pub fn with_bar_width(mut self, width: u32) -> Self {
+        self.bar_width = Some(width);
+        self
+    }
+
+    /// Changes the element spacing used to place the bars.
+    #[inline]
+    pub fn with_spacing<ES>(self, spacing: ES) -> BarGraph<'a, C, ES>
+    where
+        ES: ElementSpacing,
+    {
+        BarGraph {
+            bounds: self.bounds,
+            data: self.data,
+            max: self.max,
+            bar_width: self.bar_width,
+            spacing,
+            color: self.color,
+        }
+    }
+
+    /// Changes the data shown by the graph.
+    #[inline]
+    pub fn set_data(&mut self, data: &'a [u32]) {
+        self.data = data;
+    }
+
+    /// Returns the data shown by the graph.
+    #[inline]
+    pub fn data(&self) -> &'a [u32] {
+        self.data
+    }
+
+    /// Returns the area of the bar at position `idx`, or `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn bar_bounds(&self, idx: usize) -> Option<Rectangle> {
+        self.bars().nth(idx)
+    }
+
+    fn bar_width(&self) -> u32 {
+        match self.bar_width {
+            Some(width) => width,
+            None if self.data.is_empty() => 0,
+            None => self.bounds.size.width / self.data.len() as u32,
+        }
+    }
+
+    fn bar_height(&self, value: u32) -> u32 {
+        if self.max == 0 {
+            return 0;
+        }
+
+        let height = self.bounds.size.height as u64 * value.min(self.max) as u64 / self.max as u64;
+        height as u32
+    }
+
+    /// Returns the area of every bar, from left to right.
+    fn bars(&self) -> impl Iterator<Item = Rectangle> + '_ {
+        let count = self.data.len();
+        let width = self.bar_width();
+        let total_size = width * count as u32;
+        let bottom = self.bounds.top_left.y + self.bounds.size.height as i32;
+
+        // The full-height bar is placed like a view, then shortened to the value.
+        let slot = Rectangle::new(
+            self.bounds.top_left,
+            Size::new(width, self.bounds.size.height),
+        );
+        let mut previous = self.bounds;
+
+        self.data.iter().enumerate().map(move |(n, &value)| {
+            let offset = if n == 0 {
+                self.spacing
+                    .align(horizontal::Left, slot, previous, n, count, total_size)
+            } else {
+                self.spacing.align(
+                    horizontal::LeftToRight,
+                    slot,
+                    previous,
+                    n,
+                    count,
+                    total_size,
+                )
+            };
+            previous = slot.translate(Point::new(offset, 0));
+
+            let height = self.bar_height(value);
+            Rectangle::new(
+                Point::new(previous.top_left.x, bottom - height as i32),
+                Size::new(width, height),
+            )
+        })
+    }
+}
+
+impl<C, S> View for BarGraph<'_, C, S>
+where
+    C: PixelColor,
+    S: ElementSpacing,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<C, S> Drawable for BarGraph<'_, C, S>
+where
+    C: PixelColor,
+    S: ElementSpacing,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        for bar in self.bars() {
+            display.fill_solid(&bar, self.color)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::layout::linear::spacing::{DistributeFill, FixedMargin};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn spacings_place_bars() {
+        let data = [2, 4, 1];
+        let graph = BarGraph::new(&data, 4, Size::new(8, 4), BinaryColor::On)
+            .with_bar_width(2)
+            .translate(Point::new(1, 1));
+
+        let mut margin = MockDisplay::new();
+        graph
+            .with_spacing(FixedMargin(1))
+            .draw(&mut margin)
+            .unwrap();
+
+        let mut distributed = MockDisplay::new();
+        graph
+            .with_spacing(DistributeFill(8))
+            .draw(&mut distributed)
+            .unwrap();
+
+        margin.assert_pattern(&[
+            "         ", //
+            "    ##   ", //
+            "    ##   ", //
+            " ## ##   ", //
+            " ## ## ##", //
+        ]);
+        assert_eq!(margin, distributed);
+    }
+
+    #[test]
+    fn bars_share_width_by_default() {
+        let data = [1, 5, 2];
+        let graph = BarGraph::new(&data, 2, Size::new(10, 6), BinaryColor::On);
+
+        assert_eq!(
+            Some(Rectangle::new(Point::new(0, 3), Size::new(3, 3))),
+            graph.bar_bounds(0)
+        );
+        assert_eq!(
+            Some(Rectangle::new(Point::new(3, 0), Size::new(3, 6))),
+            graph.bar_bounds(1)
+        );
+        assert_eq!(None, graph.bar_bounds(3));
+    }
+}
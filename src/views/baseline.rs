@@ -0,0 +1,223 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable,
+};
+
+use crate::View;
+
+/// A [`View`] that has a baseline, like text.
+///
+/// Implement this trait for views that get their [`View`] implementation from
+/// `embedded-graphics`, and wrap them in [`WithBaseline`] to make their baseline available to
+/// layouts. Custom views can implement [`View::baseline`] directly instead.
+pub trait BaselineView: View {
+    /// Returns the y coordinate of the baseline.
+    ///
+    /// For multi-line views, this is the baseline of the first line.
+    fn baseline_y(&self) -> i32;
+}
+
+impl<S> BaselineView for Text<'_, S>
+where
+    S: TextRenderer + Clone,
+{
+    #[inline]
+    fn baseline_y(&self) -> i32 {
+        let first_line = self.text.lines().next().unwrap_or("");
+
+        // Measure how far the text's own baseline setting is from the alphabetic baseline.
+        let top = |baseline| {
+            self.character_style
+                .measure_string(first_line, Point::zero(), baseline)
+                .bounding_box
+                .top_left
+                .y
+        };
+
+        self.position.y + top(self.text_style.baseline) - top(Baseline::Alphabetic)
+    }
+}
+
+/// Makes the baseline of the wrapped view available to layouts.
+///
+/// Use this wrapper together with [`vertical::Baseline`] to line up texts of different fonts in a
+/// horizontal [`LinearLayout`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::{FONT_6X9, FONT_10X20}, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     views::{BaselineView, WithBaseline},
+/// };
+///
+/// let small = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let large = MonoTextStyle::new(&FONT_10X20, BinaryColor::On);
+///
+/// let layout = LinearLayout::horizontal(
+///     Chain::new(WithBaseline::new(Text::new("42", Point::zero(), large)))
+///         .append(WithBaseline::new(Text::new("km/h", Point::zero(), small))),
+/// )
+/// .with_alignment(vertical::Baseline)
+/// .arrange();
+///
+/// let views = layout.inner();
+/// assert_eq!(
+///     views.parent.object.inner().baseline_y(),
+///     views.object.inner().baseline_y()
+/// );
+/// ```
+///
+/// [`vertical::Baseline`]: crate::align::vertical::Baseline
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+pub struct WithBaseline<V> {
+    view: V,
+}
+
+impl<V> WithBaseline<V>
+where
+    V: BaselineView,
+{
+    /// Wraps the given view.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self { view }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for WithBaseline<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+        }
+    }
+}
+
+impl<V> View for WithBaseline<V>
+where
+    V: BaselineView,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn baseline(&self) -> Option<i32> {
+        Some(self.view.baseline_y())
+    }
+
+    forward_view_hooks!(field view; except baseline);
+}
+
+impl<C, V> Drawable for WithBaseline<V>
+where
+    C: PixelColor,
+    V: BaselineView + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*, view_group::ViewGroup};
+    use embedded_graphics::{
+        mono_font::{
+            ascii::{FONT_10X20, FONT_6X9},
+            MonoTextStyle,
+        },
+        pixelcolor::BinaryColor,
+        prelude::Size,
+        text::TextStyleBuilder,
+    };
+
+    #[test]
+    fn text_baseline_does_not_depend_on_baseline_setting() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let alphabetic = Text::new("Hello", Point::new(0, 10), character_style);
+        assert_eq!(10, alphabetic.baseline_y());
+
+        let top = Text::with_text_style(
+            "Hello",
+            Point::new(0, 10),
+            character_style,
+            TextStyleBuilder::new().baseline(Baseline::Top).build(),
+        );
+        let ascent = 10 - alphabetic.bounds().top_left.y;
+        assert_eq!(10, top.bounds().top_left.y);
+        assert_eq!(10 + ascent, top.baseline_y());
+    }
+
+    #[test]
+    fn baselines_are_aligned_in_layout() {
+        let small = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let large = MonoTextStyle::new(&FONT_10X20, BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 3));
+
+        let layout = LinearLayout::horizontal(
+            Chain::new(WithBaseline::new(Text::new("a", Point::zero(), small)))
+                .append(WithBaseline::new(Text::new("b", Point::zero(), large)))
+                .append(rect),
+        )
+        .with_alignment(vertical::Baseline)
+        .arrange();
+
+        let baseline = layout.at(1).baseline().unwrap();
+        assert_eq!(Some(baseline), layout.at(0).baseline());
+        assert_eq!(baseline, layout.bounds_of(2).bottom_right().unwrap().y);
+
+        // The tallest text is placed at the top of the layout.
+        assert_eq!(0, layout.bounds_of(1).top_left.y);
+        assert_eq!(Point::zero(), layout.bounds().top_left);
+    }
+}
@@ -0,0 +1,396 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Primitive, Size},
+    primitives::{PrimitiveStyle, Rectangle},
+    Drawable,
+};
+
+use crate::{
+    prelude::{Insets, RectExt},
+    View,
+};
+
+/// The colors of a [`Button`] in one of its states.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ButtonStyle<C> {
+    border: Option<C>,
+    fill: Option<C>,
+}
+
+impl<C> ButtonStyle<C>
+where
+    C: PixelColor,
+{
+    /// Creates a style without a border and a fill.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            border: None,
+            fill: None,
+        }
+    }
+
+    /// Sets the color of the 1px border.
+    #[inline]
+    #[must_use]
+    pub const fn with_border(mut self, color: C) -> Self {
+        self.border = Some(color);
+        self
+    }
+
+    /// Sets the color of the background.
+    #[inline]
+    #[must_use]
+    pub const fn with_fill(mut self, color: C) -> Self {
+        self.fill = Some(color);
+        self
+    }
+}
+
+impl<C> Default for ButtonStyle<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A button with an icon and a label.
+///
+/// The icon and the label are placed next to each other, vertically centered, inside the border
+/// of the button. The button is drawn using its normal style, or its pressed style while it is
+/// pressed. A 1px space is reserved for the border even if the style has no border, so the button
+/// doesn't change its size when its state changes.
+///
+/// Any view can be used as the icon, for example an `Image`. Use an [`EmptyView`] for buttons
+/// without an icon.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Circle, PrimitiveStyle},
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     prelude::*,
+///     views::{Button, ButtonStyle},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let icon = Circle::new(Point::zero(), 5).into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+///
+/// let mut button = Button::new(
+///     icon,
+///     Text::new("OK", Point::zero(), text_style),
+///     ButtonStyle::new().with_border(BinaryColor::On),
+/// )
+/// .with_pressed_style(
+///     ButtonStyle::new()
+///         .with_border(BinaryColor::On)
+///         .with_fill(BinaryColor::Off),
+/// )
+/// .with_padding(Insets::symmetric(2, 1));
+///
+/// // Border, padding, icon, spacing and label
+/// assert_eq!(Size::new(1 + 2 + 5 + 2 + 12 + 2 + 1, 1 + 1 + 9 + 1 + 1), button.size());
+/// assert_eq!(Point::new(3, 2), button.content_area().top_left);
+///
+/// button.set_pressed(true);
+/// ```
+///
+/// [`EmptyView`]: crate::views::EmptyView
+pub struct Button<I, L, C> {
+    icon: I,
+    label: L,
+    style: ButtonStyle<C>,
+    pressed_style: ButtonStyle<C>,
+    pressed: bool,
+    padding: Insets,
+    spacing: u32,
+    bounds: Rectangle,
+}
+
+impl<I, L, C> Button<I, L, C>
+where
+    I: View,
+    L: View,
+    C: PixelColor,
+{
+    /// Creates a button with the given icon, label and style.
+    ///
+    /// The button is placed at the origin. The pressed style is the same as `style`, the padding
+    /// is 1px and the space between the icon and the label is 2px.
+    #[inline]
+    pub fn new(icon: I, label: L, style: ButtonStyle<C>) -> Self {
+        let mut button = Self {
+            icon,
+            label,
+            style,
+            pressed_style: style,
+            pressed: false,
+            padding: Insets::uniform(1),
+            spacing: 2,
+            bounds: Rectangle::zero(),
+        };
+        button.arrange(Point::zero());
+        button
+    }
+
+    /// Sets the style used while the button is pressed.
+    #[inline]
+    #[must_use]
+    pub fn with_pressed_style(mut self, style: ButtonStyle<C>) -> Self {
+        self.pressed_style = style;
+        self
+    }
+
+    /// Sets the space between the border and the content.
+    #[inline]
+    #[must_use]
+    pub fn with_padding(mut self, padding: Insets) -> Self {
+        self.padding = padding;
+        self.arrange(self.bounds.top_left);
+        self
+    }
+
+    /// Sets the space between the icon and the label.
+    ///
+    /// The space is omitted if the icon is empty.
+    #[inline]
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self.arrange(self.bounds.top_left);
+        self
+    }
+
+    /// Changes the state of the button.
+    #[inline]
+    pub fn set_pressed(&mut self, pressed: bool) {
+        self.pressed = pressed;
+    }
+
+    /// Returns `true` if the button is pressed.
+    #[inline]
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Returns the area inside the border and the padding.
+    #[inline]
+    pub fn content_area(&self) -> Rectangle {
+        self.bounds.shrink(Insets::uniform(1)).shrink(self.padding)
+    }
+
+    /// Returns a reference to the icon.
+    #[inline]
+    pub fn icon(&self) -> &I {
+        &self.icon
+    }
+
+    /// Returns a reference to the label.
+    #[inline]
+    pub fn label(&self) -> &L {
+        &self.label
+    }
+
+    /// Modifies the label and arranges the button again.
+    ///
+    /// The top left corner of the button stays in place.
+    #[inline]
+    pub fn modify_label<R>(&mut self, f: impl FnOnce(&mut L) -> R) -> R {
+        let result = f(&mut self.label);
+        self.arrange(self.bounds.top_left);
+        result
+    }
+
+    /// Consumes the button and returns the icon and the label.
+    #[inline]
+    pub fn into_inner(self) -> (I, L) {
+        (self.icon, self.label)
+    }
+
+    /// Places the icon and the label, with the top left corner of the button at `position`.
+    fn arrange(&mut self, position: Point) {
+        let icon = self.icon.bounds();
+        let label = self.label.bounds();
+
+        let spacing = if icon.is_zero_sized() {
+            0
+        } else {
+            self.spacing
+        };
+        let content = Size::new(
+            icon.size.width + spacing + label.size.width,
+            icon.size.height.max(label.size.height),
+        );
+        let content_top_left =
+            position + Point::new(1 + self.padding.left as i32, 1 + self.padding.top as i32);
+
+        let centered = |height: u32| ((content.height - height) / 2) as i32;
+
+        self.icon.translate_impl(
+            content_top_left + Point::new(0, centered(icon.size.height)) - icon.top_left,
+        );
+        self.label.translate_impl(
+            content_top_left
+                + Point::new(
+                    (icon.size.width + spacing) as i32,
+                    centered(label.size.height),
+                )
+                - label.top_left,
+        );
+
+        self.bounds = Rectangle::new(
+            position,
+            content
+                + Size::new(self.padding.horizontal(), self.padding.vertical())
+                + Size::new(2, 2),
+        );
+    }
+}
+
+impl<I, L, C> Clone for Button<I, L, C>
+where
+    I: Clone,
+    L: Clone,
+    C: PixelColor,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            icon: self.icon.clone(),
+            label: self.label.clone(),
+            style: self.style,
+            pressed_style: self.pressed_style,
+            pressed: self.pressed,
+            padding: self.padding,
+            spacing: self.spacing,
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl<I, L, C> View for Button<I, L, C>
+where
+    I: View,
+    L: View,
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.icon.translate_impl(by);
+        self.label.translate_impl(by);
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<I, L, C> Drawable for Button<I, L, C>
+where
+    I: View + Drawable<Color = C>,
+    L: View + Drawable<Color = C>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let style = if self.pressed {
+            self.pressed_style
+        } else {
+            self.style
+        };
+
+        if let Some(fill) = style.fill {
+            display.fill_solid(&self.bounds, fill)?;
+        }
+        if let Some(border) = style.border {
+            self.bounds
+                .into_styled(PrimitiveStyle::with_stroke(border, 1))
+                .draw(display)?;
+        }
+
+        self.icon.draw(display)?;
+        self.label.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::views::EmptyView;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn draws_state_style() {
+        let icon = Rectangle::new(Point::zero(), Size::new(1, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+        let label = Rectangle::new(Point::new(10, 10), Size::new(2, 3))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let mut button = Button::new(icon, label, ButtonStyle::new().with_border(BinaryColor::On))
+            .with_pressed_style(ButtonStyle::new().with_fill(BinaryColor::Off))
+            .with_padding(Insets::uniform(0))
+            .with_spacing(1);
+
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(4, 3)),
+            button.content_area()
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        button.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "######", //
+            "#  ###", //
+            "## ###", //
+            "#  ###", //
+            "######", //
+        ]);
+
+        button.set_pressed(true);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        button.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "......", //
+            "...##.", //
+            ".#.##.", //
+            "...##.", //
+            "......", //
+        ]);
+    }
+
+    #[test]
+    fn empty_icon_has_no_spacing() {
+        let label = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        let button = Button::new(
+            EmptyView::<BinaryColor>::new(),
+            label,
+            ButtonStyle::<BinaryColor>::new(),
+        );
+
+        assert_eq!(Size::new(8, 6), button.size());
+        assert_eq!(Point::new(2, 2), button.label().top_left);
+    }
+}
@@ -0,0 +1,443 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Primitive, Size},
+    primitives::{Circle, PrimitiveStyle, Rectangle},
+    Drawable,
+};
+
+use crate::{
+    prelude::{Insets, RectExt},
+    View,
+};
+
+/// The default size of the indicator of a [`Toggle`].
+const DEFAULT_SIZE: u32 = 7;
+
+/// The default space between the indicator and the label.
+const DEFAULT_SPACING: u32 = 2;
+
+/// Draws the indicator of a [`Toggle`].
+///
+/// See [`CheckboxShape`] and [`RadioButtonShape`] for the built-in shapes.
+pub trait ToggleShape {
+    /// Draws the indicator into `area` using `color`.
+    fn draw_indicator<C, D>(
+        &self,
+        area: Rectangle,
+        checked: bool,
+        color: C,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>;
+}
+
+/// A square outline that is filled when checked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CheckboxShape;
+
+impl ToggleShape for CheckboxShape {
+    #[inline]
+    fn draw_indicator<C, D>(
+        &self,
+        area: Rectangle,
+        checked: bool,
+        color: C,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        area.into_styled(PrimitiveStyle::with_stroke(color, 1))
+            .draw(display)?;
+
+        if checked {
+            display.fill_solid(&area.shrink(Insets::uniform(2)), color)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A circle outline with a dot in the middle when checked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RadioButtonShape;
+
+impl ToggleShape for RadioButtonShape {
+    #[inline]
+    fn draw_indicator<C, D>(
+        &self,
+        area: Rectangle,
+        checked: bool,
+        color: C,
+        display: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        Circle::new(area.top_left, area.size.width)
+            .into_styled(PrimitiveStyle::with_stroke(color, 1))
+            .draw(display)?;
+
+        if checked {
+            let dot = area.shrink(Insets::uniform(2));
+            Circle::new(dot.top_left, dot.size.width)
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A box that can be checked, with a label next to it.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     views::{Checkbox, OutlineIndicator, Selectable},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let option = |label| {
+///     Selectable::new(
+///         Checkbox::new(Text::new(label, Point::zero(), text_style), BinaryColor::On),
+///         OutlineIndicator::new(BinaryColor::On),
+///     )
+/// };
+///
+/// let mut settings = LinearLayout::vertical(Chain::new(option("Sound")).append(option("Vibrate")))
+///     .arrange();
+///
+/// settings.select_next();
+/// settings.inner_mut().parent.object.inner_mut().toggle();
+///
+/// assert!(settings.inner().parent.object.inner().is_checked());
+/// assert!(!settings.inner().object.inner().is_checked());
+/// ```
+pub type Checkbox<L, C> = Toggle<CheckboxShape, L, C>;
+
+/// A circle that marks the chosen option of a group, with a label next to it.
+///
+/// Radio buttons don't uncheck each other: when an option is chosen, uncheck the previously
+/// chosen one.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     views::{OutlineIndicator, RadioButton, Selectable},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let option = |label| {
+///     Selectable::new(
+///         RadioButton::new(Text::new(label, Point::zero(), text_style), BinaryColor::On),
+///         OutlineIndicator::new(BinaryColor::On),
+///     )
+/// };
+///
+/// let mut brightness = LinearLayout::vertical(
+///     Chain::new(option("Low"))
+///         .append(option("Medium"))
+///         .append(option("High")),
+/// )
+/// .arrange();
+///
+/// brightness.select_next();
+/// brightness.inner_mut().parent.parent.object.inner_mut().set_checked(true);
+///
+/// // Moving the selection doesn't change the chosen option
+/// brightness.select_next();
+/// assert!(brightness.inner().parent.parent.object.inner().is_checked());
+/// assert!(!brightness.inner().parent.object.inner().is_checked());
+/// ```
+pub type RadioButton<L, C> = Toggle<RadioButtonShape, L, C>;
+
+/// An indicator that can be checked, with a label next to it.
+///
+/// The indicator is drawn by the [`ToggleShape`] `S`. The label is placed to the right of the
+/// indicator, and the two are vertically centered. Use an [`EmptyView`] for a toggle without a
+/// label. [`Checkbox`] and [`RadioButton`] are toggles with the built-in shapes.
+///
+/// Toggles can't be selected by themselves, so that moving the selection in a settings list
+/// doesn't change them. Wrap them in [`Selectable`] to make them part of the selection, and
+/// check or uncheck the selected toggle.
+///
+/// [`EmptyView`]: crate::views::EmptyView
+/// [`Selectable`]: crate::views::Selectable
+pub struct Toggle<S, L, C> {
+    shape: S,
+    label: L,
+    color: C,
+    checked: bool,
+    size: u32,
+    spacing: u32,
+    indicator: Rectangle,
+    bounds: Rectangle,
+}
+
+impl<S, L, C> Toggle<S, L, C>
+where
+    S: ToggleShape + Default,
+    L: View,
+    C: PixelColor,
+{
+    /// Creates an unchecked toggle with the given label, drawn using `color`.
+    ///
+    /// The toggle is placed at the origin. The indicator is 7px wide, and the space between the
+    /// indicator and the label is 2px.
+    #[inline]
+    pub fn new(label: L, color: C) -> Self {
+        Self::with_shape(S::default(), label, color)
+    }
+}
+
+impl<S, L, C> Toggle<S, L, C>
+where
+    S: ToggleShape,
+    L: View,
+    C: PixelColor,
+{
+    /// Creates an unchecked toggle with the given indicator shape and label, drawn using `color`.
+    #[inline]
+    pub fn with_shape(shape: S, label: L, color: C) -> Self {
+        let mut toggle = Self {
+            shape,
+            label,
+            color,
+            checked: false,
+            size: DEFAULT_SIZE,
+            spacing: DEFAULT_SPACING,
+            indicator: Rectangle::zero(),
+            bounds: Rectangle::zero(),
+        };
+        toggle.arrange(Point::zero());
+        toggle
+    }
+
+    /// Sets the width and height of the indicator.
+    #[inline]
+    #[must_use]
+    pub fn with_size(mut self, size: u32) -> Self {
+        self.size = size;
+        self.arrange(self.bounds.top_left);
+        self
+    }
+
+    /// Sets the space between the indicator and the label.
+    ///
+    /// The space is omitted if the label is empty.
+    #[inline]
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self.arrange(self.bounds.top_left);
+        self
+    }
+
+    /// Checks or unchecks the toggle.
+    #[inline]
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// Inverts the state of the toggle and returns the new state.
+    #[inline]
+    pub fn toggle(&mut self) -> bool {
+        self.checked = !self.checked;
+        self.checked
+    }
+
+    /// Returns `true` if the toggle is checked.
+    #[inline]
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Returns a reference to the label.
+    #[inline]
+    pub fn label(&self) -> &L {
+        &self.label
+    }
+
+    /// Consumes the toggle and returns the label.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.label
+    }
+
+    /// Places the indicator and the label next to each other, vertically centered, with the top
+    /// left corner at `position`.
+    fn arrange(&mut self, position: Point) {
+        let label_bounds = self.label.bounds();
+        let spacing = if label_bounds.is_zero_sized() {
+            0
+        } else {
+            self.spacing
+        };
+        let height = self.size.max(label_bounds.size.height);
+
+        self.indicator = Rectangle::new(
+            position + Point::new(0, ((height - self.size) / 2) as i32),
+            Size::new(self.size, self.size),
+        );
+        self.label.translate_impl(
+            position
+                + Point::new(
+                    (self.size + spacing) as i32,
+                    ((height - label_bounds.size.height) / 2) as i32,
+                )
+                - label_bounds.top_left,
+        );
+        self.bounds = Rectangle::new(
+            position,
+            Size::new(self.size + spacing + label_bounds.size.width, height),
+        );
+    }
+}
+
+impl<S, L, C> Clone for Toggle<S, L, C>
+where
+    S: Clone,
+    L: Clone,
+    C: PixelColor,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            label: self.label.clone(),
+            color: self.color,
+            checked: self.checked,
+            size: self.size,
+            spacing: self.spacing,
+            indicator: self.indicator,
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl<S, L, C> View for Toggle<S, L, C>
+where
+    S: ToggleShape,
+    L: View,
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.label.translate_impl(by);
+        self.indicator.top_left += by;
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<S, L, C> Drawable for Toggle<S, L, C>
+where
+    S: ToggleShape,
+    L: View + Drawable<Color = C>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.shape
+            .draw_indicator(self.indicator, self.checked, self.color, display)?;
+        self.label.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::LinearLayout,
+        prelude::*,
+        views::{EmptyView, OutlineIndicator, Selectable},
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn checked_box_is_filled() {
+        let mut checkbox =
+            Checkbox::new(EmptyView::<BinaryColor>::new(), BinaryColor::On).with_size(5);
+        checkbox.toggle();
+
+        let mut display = MockDisplay::new();
+        checkbox.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "#####", //
+            "#   #", //
+            "# # #", //
+            "#   #", //
+            "#####", //
+        ]);
+    }
+
+    #[test]
+    fn label_is_centered_next_to_indicator() {
+        let label = Rectangle::new(Point::new(5, 5), Size::new(10, 3));
+
+        let checkbox = Checkbox::new(label, BinaryColor::On).translate(Point::new(1, 1));
+
+        assert_eq!(
+            Rectangle::new(Point::new(1, 1), Size::new(19, 7)),
+            checkbox.bounds()
+        );
+        assert_eq!(Point::new(10, 3), checkbox.label().top_left);
+    }
+
+    #[test]
+    fn moving_selection_keeps_radio_choice() {
+        let option = Selectable::new(
+            RadioButton::new(EmptyView::<BinaryColor>::new(), BinaryColor::On),
+            OutlineIndicator::new(BinaryColor::On),
+        );
+
+        let mut layout =
+            LinearLayout::horizontal(Chain::new(option.clone()).append(option)).arrange();
+        layout
+            .inner_mut()
+            .parent
+            .object
+            .inner_mut()
+            .set_checked(true);
+
+        assert_eq!(Some(0), layout.select_next());
+        assert_eq!(Some(1), layout.select_next());
+        assert!(layout.inner().parent.object.inner().is_checked());
+        assert!(!layout.inner().object.inner().is_checked());
+    }
+}
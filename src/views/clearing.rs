@@ -0,0 +1,195 @@
+use core::cell::Cell;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// Clears the area where the wrapped view was drawn the last time before drawing it again.
+///
+/// Moving or changing a view and drawing it again leaves the pixels of its previous location on
+/// the display. `Clearing` remembers where the view was drawn, and fills that area with the
+/// background color before drawing the view at its new location.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::Clearing};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// display.set_allow_overdraw(true);
+///
+/// let mut status = Clearing::new(
+///     LinearLayout::horizontal(
+///         Chain::new(Text::new("Speed:", Point::zero(), text_style))
+///             .append(Text::new("100", Point::zero(), text_style)),
+///     )
+///     .arrange(),
+///     BinaryColor::Off,
+/// );
+/// status.draw(&mut display).unwrap();
+///
+/// // Shorten the text and draw again. The removed character is cleared from the display.
+/// status.inner_mut().inner_mut().object.text = "5";
+/// status.inner_mut().rearrange();
+/// status.draw(&mut display).unwrap();
+/// ```
+pub struct Clearing<V, C> {
+    view: V,
+    background: C,
+    drawn: Cell<Option<Rectangle>>,
+}
+
+impl<V, C> Clearing<V, C>
+where
+    V: View,
+    C: PixelColor,
+{
+    /// Wraps the given view.
+    ///
+    /// `background` is used to clear the previous location of the view.
+    #[inline]
+    pub fn new(view: V, background: C) -> Self {
+        Self {
+            view,
+            background,
+            drawn: Cell::new(None),
+        }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+
+    /// Returns the area the view was drawn to the last time, if it was drawn.
+    #[inline]
+    pub fn drawn_bounds(&self) -> Option<Rectangle> {
+        self.drawn.get()
+    }
+
+    /// Forgets the previous location of the view, e.g. after the display was cleared.
+    #[inline]
+    pub fn reset(&self) {
+        self.drawn.set(None);
+    }
+}
+
+impl<V, C> Clone for Clearing<V, C>
+where
+    V: Clone,
+    C: PixelColor,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            background: self.background,
+            drawn: self.drawn.clone(),
+        }
+    }
+}
+
+impl<V, C> View for Clearing<V, C>
+where
+    V: View,
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    forward_view_hooks!(field view);
+}
+
+impl<V, C> Drawable for Clearing<V, C>
+where
+    V: View + Drawable<Color = C>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(previous) = self.drawn.get() {
+            display.fill_solid(&previous, self.background)?;
+        }
+
+        self.view.draw(display)?;
+        self.drawn.set(Some(self.view.bounds()));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn previous_location_is_cleared() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let mut view = Clearing::new(rect, BinaryColor::Off);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        view.draw(&mut display).unwrap();
+        assert_eq!(
+            Some(Rectangle::new(Point::zero(), Size::new(2, 2))),
+            view.drawn_bounds()
+        );
+
+        view.translate_mut(Point::new(1, 1));
+        view.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            ".. ", //
+            ".##", //
+            " ##", //
+        ]);
+    }
+}
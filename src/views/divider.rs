@@ -0,0 +1,260 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Primitive, Size},
+    primitives::{PrimitiveStyle, Rectangle},
+    Drawable,
+};
+
+use crate::{
+    layout::ResizableView,
+    prelude::{Insets, RectExt},
+    View,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+/// A horizontal or vertical line that separates views in a layout.
+///
+/// A `Divider` placed in a [`LinearLayout`] is resized to the width (or height) of the layout,
+/// so it spans the whole layout regardless of the layout's secondary alignment. The inset leaves
+/// some empty space at both ends of the line.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::{spacing::FixedMargin, LinearLayout},
+///     prelude::*,
+///     view_group::ViewGroup,
+///     views::Divider,
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let layout = LinearLayout::vertical(
+///     Chain::new(Text::new("Title", Point::zero(), text_style))
+///         .append(Divider::horizontal(BinaryColor::On).with_inset(2))
+///         .append(Text::new("Some content", Point::zero(), text_style)),
+/// )
+/// .with_spacing(FixedMargin(1))
+/// .arrange();
+///
+/// // The divider is as wide as the layout.
+/// assert_eq!(layout.size().width, layout.bounds_of(1).size.width);
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+#[derive(Copy, Clone)]
+pub struct Divider<C> {
+    bounds: Rectangle,
+    orientation: Orientation,
+    color: C,
+    inset: u32,
+}
+
+impl<C> Divider<C>
+where
+    C: PixelColor,
+{
+    /// Creates a horizontal divider with a thickness of 1px.
+    ///
+    /// The divider has no length until it is placed in a layout, or its length is set using
+    /// [`Divider::with_length`].
+    #[inline]
+    pub fn horizontal(color: C) -> Self {
+        Self {
+            bounds: Rectangle::new(Point::zero(), Size::new(0, 1)),
+            orientation: Orientation::Horizontal,
+            color,
+            inset: 0,
+        }
+    }
+
+    /// Creates a vertical divider with a thickness of 1px.
+    ///
+    /// The divider has no length until it is placed in a layout, or its length is set using
+    /// [`Divider::with_length`].
+    #[inline]
+    pub fn vertical(color: C) -> Self {
+        Self {
+            bounds: Rectangle::new(Point::zero(), Size::new(1, 0)),
+            orientation: Orientation::Vertical,
+            color,
+            inset: 0,
+        }
+    }
+
+    /// Sets the thickness of the line.
+    #[inline]
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: u32) -> Self {
+        match self.orientation {
+            Orientation::Horizontal => self.bounds.size.height = thickness,
+            Orientation::Vertical => self.bounds.size.width = thickness,
+        }
+        self
+    }
+
+    /// Sets the length of the divider, including the inset.
+    #[inline]
+    #[must_use]
+    pub fn with_length(mut self, length: u32) -> Self {
+        self.set_length(length);
+        self
+    }
+
+    /// Sets the empty space left at both ends of the line.
+    #[inline]
+    #[must_use]
+    pub fn with_inset(mut self, inset: u32) -> Self {
+        self.inset = inset;
+        self
+    }
+
+    /// Sets the color of the line.
+    #[inline]
+    #[must_use]
+    pub fn with_color(mut self, color: C) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn set_length(&mut self, length: u32) {
+        match self.orientation {
+            Orientation::Horizontal => self.bounds.size.width = length,
+            Orientation::Vertical => self.bounds.size.height = length,
+        }
+    }
+
+    /// Returns the area covered by the line.
+    fn line(&self) -> Rectangle {
+        let insets = match self.orientation {
+            Orientation::Horizontal => Insets::symmetric(self.inset, 0),
+            Orientation::Vertical => Insets::symmetric(0, self.inset),
+        };
+
+        self.bounds.shrink(insets)
+    }
+}
+
+impl<C> View for Divider<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        Some(self)
+    }
+}
+
+impl<C> ResizableView for Divider<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn resize(&mut self, size: Size) {
+        let length = match self.orientation {
+            Orientation::Horizontal => size.width,
+            Orientation::Vertical => size.height,
+        };
+        self.set_length(length);
+    }
+
+    #[inline]
+    fn fills_layout(&self) -> bool {
+        true
+    }
+}
+
+impl<C> Drawable for Divider<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.line()
+            .into_styled(PrimitiveStyle::with_fill(self.color))
+            .draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn divider_fills_layout() {
+        let rect = Rectangle::new(Point::zero(), Size::new(6, 1));
+
+        let layout = LinearLayout::vertical(
+            Chain::new(rect)
+                .append(
+                    Divider::horizontal(BinaryColor::On)
+                        .with_thickness(2)
+                        .with_inset(1),
+                )
+                .append(rect),
+        )
+        .with_alignment(horizontal::Center)
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(0, 1), Size::new(6, 2)),
+            layout.bounds_of(1)
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.inner().parent.object.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "      ", //
+            " #### ", //
+            " #### ", //
+        ]);
+    }
+
+    #[test]
+    fn vertical_divider() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 4));
+
+        let layout = LinearLayout::horizontal(
+            Chain::new(rect)
+                .append(Divider::vertical(BinaryColor::On))
+                .append(rect),
+        )
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(2, 0), Size::new(1, 4)),
+            layout.bounds_of(1)
+        );
+    }
+}
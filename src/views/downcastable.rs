@@ -0,0 +1,210 @@
+use core::any::Any;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// Makes the wrapped view available for downcasting.
+///
+/// View groups return their views as `&dyn View`, which can't be used to access the content of a
+/// view, e.g. the text of a label. Wrapped views can be downcast to the wrapper type using
+/// [`ViewGroup::at_any`] or [`View::as_any`], so application code can update already arranged
+/// views without rebuilding the layout. Other wrappers, like [`Cached`] or [`Selectable`], forward
+/// the downcasting hooks, so a `Downcastable` view can be wrapped further.
+///
+/// This type is only available with the `any` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::{Baseline, Text},
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout, prelude::*, view_group::ViewGroup, views::Downcastable,
+/// };
+///
+/// type Label = Downcastable<Text<'static, MonoTextStyle<'static, BinaryColor>>>;
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let label = |text| {
+///     Downcastable::new(Text::with_baseline(text, Point::zero(), text_style, Baseline::Top))
+/// };
+///
+/// let mut layout = LinearLayout::vertical(Chain::new(label("Speed:")).append(label("0 km/h")))
+///     .arrange()
+///     .into_inner();
+///
+/// if let Some(value) = layout
+///     .at_any_mut(1)
+///     .and_then(|view| view.downcast_mut::<Label>())
+/// {
+///     value.inner_mut().text = "42 km/h";
+/// }
+///
+/// assert_eq!("42 km/h", layout.object.inner().text);
+/// ```
+///
+/// [`ViewGroup::at_any`]: crate::view_group::ViewGroup::at_any
+/// [`Cached`]: crate::utils::cached::Cached
+/// [`Selectable`]: crate::views::Selectable
+pub struct Downcastable<V> {
+    view: V,
+}
+
+impl<V> Downcastable<V>
+where
+    V: View + 'static,
+{
+    /// Wraps the given view.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self { view }
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for Downcastable<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+        }
+    }
+}
+
+impl<V> View for Downcastable<V>
+where
+    V: View + 'static,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+
+    forward_view_hooks!(field view; except as_any, as_any_mut);
+}
+
+impl<C, V> Drawable for Downcastable<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C> + 'static,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{prelude::*, view_group::ViewGroup};
+    use embedded_graphics::{prelude::Size, primitives::Circle};
+
+    #[test]
+    fn only_wrapped_views_can_be_downcast() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let mut chain = Chain::new(rect)
+            .append(Downcastable::new(Circle::new(Point::zero(), 3)))
+            .append(Downcastable::new(rect));
+
+        assert!(chain.at_any(0).is_none());
+        assert!(chain.at_any(3).is_none());
+        assert!(chain
+            .at_any(1)
+            .and_then(|view| view.downcast_ref::<Downcastable<Rectangle>>())
+            .is_none());
+
+        chain
+            .at_any_mut(2)
+            .and_then(|view| view.downcast_mut::<Downcastable<Rectangle>>())
+            .unwrap()
+            .inner_mut()
+            .size = Size::new(1, 1);
+
+        assert_eq!(Size::new(1, 1), chain.object.inner().size);
+    }
+
+    #[test]
+    fn wrapped_downcastable_views_can_be_downcast() {
+        use crate::views::{OutlineIndicator, Selectable, Visibility};
+        use embedded_graphics::pixelcolor::BinaryColor;
+
+        type Wrapped = Downcastable<Rectangle>;
+
+        let rect = Downcastable::new(Rectangle::new(Point::zero(), Size::new(5, 5)));
+        let mut chain = Chain::new(Cached::new(rect.clone()))
+            .append(Visibility::new(rect.clone()))
+            .append(Selectable::new(
+                rect,
+                OutlineIndicator::new(BinaryColor::On),
+            ));
+
+        for idx in 0..3 {
+            assert!(chain
+                .at_any(idx)
+                .and_then(|view| view.downcast_ref::<Wrapped>())
+                .is_some());
+        }
+
+        chain
+            .at_any_mut(2)
+            .and_then(|view| view.downcast_mut::<Wrapped>())
+            .unwrap()
+            .inner_mut()
+            .size = Size::new(1, 1);
+
+        assert_eq!(Size::new(1, 1), chain.object.inner().inner().size);
+    }
+}
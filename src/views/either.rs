@@ -0,0 +1,306 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{
+    view_group::{DrawableView, DrawableViewGroup, ViewGroup},
+    View,
+};
+
+/// One of two different views.
+///
+/// `Either` can be used when different code paths produce views of different types, but a single
+/// type is needed, for example to store the result in a view group. `Either` implements [`View`],
+/// [`Drawable`] and [`ViewGroup`] when both of its variants do.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Circle, PrimitiveStyle, Rectangle, Styled},
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::Either};
+///
+/// let style = PrimitiveStyle::with_fill(BinaryColor::On);
+///
+/// let connected = true;
+/// let icon: Either<Styled<Circle, _>, Styled<Rectangle, _>> = if connected {
+///     Either::Left(Circle::new(Point::zero(), 9).into_styled(style))
+/// } else {
+///     Either::Right(Rectangle::new(Point::zero(), Size::new(9, 9)).into_styled(style))
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let layout = LinearLayout::horizontal(
+///     Chain::new(icon).append(Text::new("Status", Point::zero(), text_style)),
+/// )
+/// .arrange();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Either<L, R> {
+    /// The first kind of view.
+    Left(L),
+
+    /// The second kind of view.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns `true` if this is an `Either::Left`.
+    #[inline]
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns `true` if this is an `Either::Right`.
+    #[inline]
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Returns a reference to the left view, if present.
+    #[inline]
+    pub fn left(&self) -> Option<&L> {
+        match self {
+            Either::Left(view) => Some(view),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Returns a reference to the right view, if present.
+    #[inline]
+    pub fn right(&self) -> Option<&R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(view) => Some(view),
+        }
+    }
+
+    /// Returns a mutable reference to the left view, if present.
+    #[inline]
+    pub fn left_mut(&mut self) -> Option<&mut L> {
+        match self {
+            Either::Left(view) => Some(view),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the right view, if present.
+    #[inline]
+    pub fn right_mut(&mut self) -> Option<&mut R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(view) => Some(view),
+        }
+    }
+}
+
+impl<L, R> Either<L, R>
+where
+    L: View,
+    R: View,
+{
+    fn view(&self) -> Option<&dyn View> {
+        match self {
+            Either::Left(view) => Some(view),
+            Either::Right(view) => Some(view),
+        }
+    }
+
+    fn view_mut(&mut self) -> Option<&mut dyn View> {
+        match self {
+            Either::Left(view) => Some(view),
+            Either::Right(view) => Some(view),
+        }
+    }
+}
+
+impl<L, R> View for Either<L, R>
+where
+    L: View,
+    R: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        match self {
+            Either::Left(view) => view.translate_impl(by),
+            Either::Right(view) => view.translate_impl(by),
+        }
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        match self {
+            Either::Left(view) => view.bounds(),
+            Either::Right(view) => view.bounds(),
+        }
+    }
+
+    forward_view_hooks!(fn view, view_mut);
+}
+
+impl<L, R> ViewGroup for Either<L, R>
+where
+    L: ViewGroup,
+    R: ViewGroup,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Either::Left(vg) => vg.len(),
+            Either::Right(vg) => vg.len(),
+        }
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> &dyn View {
+        match self {
+            Either::Left(vg) => vg.at(idx),
+            Either::Right(vg) => vg.at(idx),
+        }
+    }
+
+    #[inline]
+    fn at_mut(&mut self, idx: usize) -> &mut dyn View {
+        match self {
+            Either::Left(vg) => vg.at_mut(idx),
+            Either::Right(vg) => vg.at_mut(idx),
+        }
+    }
+
+    #[inline]
+    fn try_at(&self, idx: usize) -> Option<&dyn View> {
+        match self {
+            Either::Left(vg) => vg.try_at(idx),
+            Either::Right(vg) => vg.try_at(idx),
+        }
+    }
+
+    #[inline]
+    fn try_at_mut(&mut self, idx: usize) -> Option<&mut dyn View> {
+        match self {
+            Either::Left(vg) => vg.try_at_mut(idx),
+            Either::Right(vg) => vg.try_at_mut(idx),
+        }
+    }
+
+    #[inline]
+    fn bounds_of(&self, idx: usize) -> Rectangle {
+        match self {
+            Either::Left(vg) => vg.bounds_of(idx),
+            Either::Right(vg) => vg.bounds_of(idx),
+        }
+    }
+
+    #[inline]
+    fn translate_child(&mut self, idx: usize, by: Point) {
+        match self {
+            Either::Left(vg) => vg.translate_child(idx, by),
+            Either::Right(vg) => vg.translate_child(idx, by),
+        }
+    }
+}
+
+impl<C, L, R> DrawableViewGroup<C> for Either<L, R>
+where
+    C: PixelColor,
+    L: DrawableViewGroup<C>,
+    R: DrawableViewGroup<C>,
+{
+    #[inline]
+    fn drawable_at(&self, idx: usize) -> &dyn DrawableView<C> {
+        match self {
+            Either::Left(vg) => vg.drawable_at(idx),
+            Either::Right(vg) => vg.drawable_at(idx),
+        }
+    }
+}
+
+impl<C, L, R> Drawable for Either<L, R>
+where
+    C: PixelColor,
+    L: Drawable<Color = C>,
+    R: Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match self {
+            Either::Left(view) => {
+                view.draw(display)?;
+            }
+            Either::Right(view) => {
+                view.draw(display)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*, view_group::EmptyViewGroup};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::{Line, PrimitiveStyle},
+    };
+
+    #[test]
+    fn either_is_drawn_in_layout() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let views: [Either<Line, Rectangle>; 2] = [
+            Either::Left(Line::new(Point::zero(), Point::new(2, 0))),
+            Either::Right(Rectangle::new(Point::zero(), Size::new(3, 2))),
+        ];
+
+        let mut views = views.map(|view| match view {
+            Either::Left(line) => Either::Left(line.into_styled(style)),
+            Either::Right(rect) => Either::Right(rect.into_styled(style)),
+        });
+
+        LinearLayout::vertical(EmptyViewGroup).arrange_in_place(&mut Views::new(&mut views));
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        views[0].draw(&mut disp).unwrap();
+        views[1].draw(&mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "###", //
+                "###", //
+                "###",
+            ])
+        );
+    }
+
+    #[test]
+    fn either_view_group() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let group: Either<Chain<Rectangle>, chain! { Rectangle, Rectangle }> =
+            Either::Right(Chain::new(rect).append(rect));
+
+        let layout = LinearLayout::horizontal(group).arrange();
+
+        assert_eq!(2, layout.inner().len());
+        assert_eq!(Size::new(4, 2), layout.size());
+    }
+}
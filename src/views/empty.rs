@@ -0,0 +1,113 @@
+use core::marker::PhantomData;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// A view that takes up no space and draws nothing.
+///
+/// `EmptyView` is a placeholder for intentionally empty places, e.g. empty cells of a grid, or
+/// the "nothing" variant of an [`Either`]. It can be used with any color type, so it satisfies
+/// `Drawable<Color = C>` wherever a drawable view is needed.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     views::{Either, EmptyView},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let warning_count = 0;
+/// let warning: Either<_, EmptyView<BinaryColor>> = if warning_count > 0 {
+///     Either::Left(Text::new("Warning!", Point::zero(), text_style))
+/// } else {
+///     Either::Right(EmptyView::new())
+/// };
+///
+/// let status = Text::new("Ready", Point::zero(), text_style);
+/// let layout = LinearLayout::vertical(Chain::new(status).append(warning)).arrange();
+///
+/// assert_eq!(status.size(), layout.size());
+/// ```
+///
+/// [`Either`]: crate::views::Either
+pub struct EmptyView<C> {
+    position: Point,
+    _marker: PhantomData<C>,
+}
+
+impl<C> EmptyView<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new empty view at `(0, 0)`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            position: Point::zero(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for EmptyView<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> Clone for EmptyView<C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for EmptyView<C> {}
+
+impl<C> View for EmptyView<C> {
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.position, Size::zero())
+    }
+}
+
+impl<C> Drawable for EmptyView<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, _display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        Ok(())
+    }
+}
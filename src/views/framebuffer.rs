@@ -0,0 +1,327 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{Dimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable, Pixel,
+};
+
+use crate::View;
+
+/// An off-screen buffer that holds a pre-rendered image of a view.
+///
+/// Drawing a complex view, like a group of icons and labels, can be slow and may send a lot of
+/// overlapping pixels to the display. `FramebufferView` stores the pixels of such a view in memory
+/// once, and then draws them as a single block. This is useful when a part of the screen rarely
+/// changes, but has to be drawn often, e.g. when other parts of the screen are redrawn.
+///
+/// The buffer is `W` pixels wide and `H` pixels tall. It is a [`DrawTarget`] with its top left
+/// corner at `(0, 0)`, and it is a [`View`] that can be placed in layouts. Pixels that are not
+/// drawn into the buffer are transparent.
+///
+/// If every pixel of the buffer is set, it is drawn using a single
+/// [`fill_contiguous`](DrawTarget::fill_contiguous) call. Otherwise only the pixels that are set
+/// are drawn.
+///
+/// This type is only available if the `framebuffer` feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::{Circle, PrimitiveStyle},
+/// # };
+/// use embedded_layout::{prelude::*, views::FramebufferView};
+///
+/// let style = PrimitiveStyle::with_fill(BinaryColor::On);
+/// let icon = Circle::new(Point::new(10, 10), 5).into_styled(style);
+///
+/// // Render the icon once.
+/// let icon: FramebufferView<BinaryColor, 5, 5> = FramebufferView::from_view(&icon);
+/// assert_eq!(Point::new(10, 10), icon.bounds().top_left);
+///
+/// // Move and draw the buffer like any other view.
+/// let icon = icon.translate(Point::new(-10, -10));
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// icon.draw(&mut display).unwrap();
+///
+/// display.assert_pattern(&[
+///     " ### ", //
+///     "#####", //
+///     "#####", //
+///     "#####", //
+///     " ### ", //
+/// ]);
+/// ```
+pub struct FramebufferView<C, const W: usize, const H: usize> {
+    pixels: [[Option<C>; W]; H],
+    top_left: Point,
+}
+
+impl<C, const W: usize, const H: usize> FramebufferView<C, W, H>
+where
+    C: PixelColor,
+{
+    /// Creates an empty, transparent buffer at `(0, 0)`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            pixels: [[None; W]; H],
+            top_left: Point::zero(),
+        }
+    }
+
+    /// Renders `view` into a new buffer.
+    ///
+    /// The top left corner of `view` is drawn at the top left corner of the buffer, and the buffer
+    /// is placed where `view` is. Parts of the view outside of the buffer are discarded.
+    #[inline]
+    pub fn from_view<V>(view: &V) -> Self
+    where
+        V: View + Drawable<Color = C>,
+    {
+        let mut buffer = Self::new();
+        buffer.top_left = view.bounds().top_left;
+        buffer.render(view);
+        buffer
+    }
+
+    /// Clears the buffer and renders `view` into it.
+    ///
+    /// The top left corner of `view` is drawn at the top left corner of the buffer. The position
+    /// of the buffer is not changed.
+    #[inline]
+    pub fn render<V>(&mut self, view: &V)
+    where
+        V: View + Drawable<Color = C>,
+    {
+        self.clear_buffer();
+
+        let offset = view.bounds().top_left;
+        let mut target = Offset {
+            buffer: &mut self.pixels,
+            offset,
+        };
+
+        // Drawing into the buffer is infallible.
+        let _ = view.draw(&mut target);
+    }
+
+    /// Makes every pixel of the buffer transparent.
+    #[inline]
+    pub fn clear_buffer(&mut self) {
+        self.pixels = [[None; W]; H];
+    }
+
+    /// Returns the color of the pixel at `point`, relative to the top left corner of the buffer.
+    ///
+    /// Returns `None` if the pixel is transparent or outside of the buffer.
+    #[inline]
+    pub fn pixel(&self, point: Point) -> Option<C> {
+        let (x, y) = index_of::<W, H>(point)?;
+        self.pixels[y][x]
+    }
+
+    /// Returns `true` if every pixel of the buffer is set.
+    #[inline]
+    pub fn is_opaque(&self) -> bool {
+        self.pixels.iter().flatten().all(Option::is_some)
+    }
+}
+
+impl<C, const W: usize, const H: usize> Default for FramebufferView<C, W, H>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, const W: usize, const H: usize> Clone for FramebufferView<C, W, H>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            pixels: self.pixels,
+            top_left: self.top_left,
+        }
+    }
+}
+
+/// Returns the `(column, row)` index of `point` in a `W` x `H` buffer.
+fn index_of<const W: usize, const H: usize>(point: Point) -> Option<(usize, usize)> {
+    let x = usize::try_from(point.x).ok()?;
+    let y = usize::try_from(point.y).ok()?;
+
+    if x < W && y < H {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+/// Writes pixels into the buffer, offset by `-offset`.
+struct Offset<'a, C, const W: usize, const H: usize> {
+    buffer: &'a mut [[Option<C>; W]; H],
+    offset: Point,
+}
+
+impl<C, const W: usize, const H: usize> Dimensions for Offset<'_, C, W, H> {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.offset, Size::new(W as u32, H as u32))
+    }
+}
+
+impl<C, const W: usize, const H: usize> DrawTarget for Offset<'_, C, W, H>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some((x, y)) = index_of::<W, H>(point - self.offset) {
+                self.buffer[y][x] = Some(color);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, const W: usize, const H: usize> Dimensions for FramebufferView<C, W, H> {
+    /// Returns the area of the buffer, with its top left corner at `(0, 0)`.
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(Point::zero(), Size::new(W as u32, H as u32))
+    }
+}
+
+impl<C, const W: usize, const H: usize> DrawTarget for FramebufferView<C, W, H>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        Offset {
+            buffer: &mut self.pixels,
+            offset: Point::zero(),
+        }
+        .draw_iter(pixels)
+    }
+}
+
+impl<C, const W: usize, const H: usize> View for FramebufferView<C, W, H>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.top_left, Size::new(W as u32, H as u32))
+    }
+}
+
+impl<C, const W: usize, const H: usize> Drawable for FramebufferView<C, W, H>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.is_opaque() {
+            display.fill_contiguous(
+                &View::bounds(self),
+                self.pixels.iter().flatten().filter_map(|color| *color),
+            )
+        } else {
+            let top_left = self.top_left;
+
+            display.draw_iter(self.pixels.iter().enumerate().flat_map(|(y, row)| {
+                row.iter().enumerate().filter_map(move |(x, color)| {
+                    color.map(|color| Pixel(top_left + Point::new(x as i32, y as i32), color))
+                })
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Rectangle},
+    };
+
+    #[test]
+    fn view_is_rendered_in_local_coordinates() {
+        let rect = Rectangle::new(Point::new(5, 7), Size::new(2, 1))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let buffer: FramebufferView<BinaryColor, 3, 2> = FramebufferView::from_view(&rect);
+
+        assert_eq!(
+            Rectangle::new(Point::new(5, 7), Size::new(3, 2)),
+            buffer.bounds()
+        );
+        assert_eq!(Some(BinaryColor::On), buffer.pixel(Point::new(1, 0)));
+        assert_eq!(None, buffer.pixel(Point::new(2, 0)));
+        assert_eq!(None, buffer.pixel(Point::new(3, 0)));
+        assert!(!buffer.is_opaque());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        buffer
+            .translate(Point::new(-4, -7))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[" ##"]);
+    }
+
+    #[test]
+    fn opaque_buffer_is_drawn_as_block() {
+        let mut buffer: FramebufferView<BinaryColor, 2, 2> = FramebufferView::new();
+        buffer.clear(BinaryColor::Off).unwrap();
+        Pixel(Point::new(1, 1), BinaryColor::On)
+            .draw(&mut buffer)
+            .unwrap();
+        assert!(buffer.is_opaque());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        buffer.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "..", //
+            ".#", //
+        ]);
+    }
+}
@@ -0,0 +1,255 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    mono_font::MonoTextStyle,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable,
+};
+
+use crate::{layout::ResizableView, View};
+
+/// A row with a left-aligned label and a right-aligned value.
+///
+/// The space between the label and the value can be filled with dots. The row is as wide as its
+/// text, but in a [`LinearLayout`] it is resized to the width of the layout, so the values of
+/// every row line up on the right edge of the layout. The width of the layout can be set using
+/// [`LinearLayout::with_secondary_size`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     view_group::ViewGroup,
+///     views::KeyValueRow,
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let settings = LinearLayout::vertical(
+///     Chain::new(KeyValueRow::new("Volume", "80%", text_style).with_filler(true))
+///         .append(KeyValueRow::new("Light", "On", text_style).with_filler(true)),
+/// )
+/// .with_secondary_size(64)
+/// .arrange();
+///
+/// assert_eq!(64, settings.bounds_of(0).size.width);
+/// assert_eq!(64, settings.bounds_of(1).size.width);
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+/// [`LinearLayout::with_secondary_size`]: crate::layout::linear::LinearLayout::with_secondary_size
+#[derive(Copy, Clone)]
+pub struct KeyValueRow<'a, C> {
+    label: &'a str,
+    value: &'a str,
+    style: MonoTextStyle<'a, C>,
+    filler: bool,
+    bounds: Rectangle,
+}
+
+impl<'a, C> KeyValueRow<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a row with the given label, value and text style.
+    ///
+    /// The row is placed at the origin and is as wide as the label and the value, separated by a
+    /// space.
+    #[inline]
+    pub fn new(label: &'a str, value: &'a str, style: MonoTextStyle<'a, C>) -> Self {
+        let mut row = Self {
+            label,
+            value,
+            style,
+            filler: false,
+            bounds: Rectangle::zero(),
+        };
+        row.bounds.size = Size::new(row.min_width(), row.style.line_height());
+        row
+    }
+
+    /// Enables or disables filling the space between the label and the value with dots.
+    #[inline]
+    #[must_use]
+    pub fn with_filler(mut self, filler: bool) -> Self {
+        self.filler = filler;
+        self
+    }
+
+    /// Returns the label.
+    #[inline]
+    pub fn label(&self) -> &'a str {
+        self.label
+    }
+
+    /// Returns the value.
+    #[inline]
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Changes the value.
+    ///
+    /// The row grows if the new value doesn't fit, but it doesn't shrink.
+    #[inline]
+    pub fn set_value(&mut self, value: &'a str) {
+        self.value = value;
+        self.bounds.size.width = self.bounds.size.width.max(self.min_width());
+    }
+
+    /// Returns the width of a character, including the spacing after it.
+    fn char_width(&self) -> u32 {
+        self.style.font.character_size.width + self.style.font.character_spacing
+    }
+
+    fn text_width(&self, text: &str) -> u32 {
+        self.style
+            .measure_string(text, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+            .width
+    }
+
+    /// The width of the label and the value, separated by a space.
+    fn min_width(&self) -> u32 {
+        self.text_width(self.label) + self.char_width() + self.text_width(self.value)
+    }
+
+    /// Returns the horizontal position of the value.
+    fn value_x(&self) -> i32 {
+        let right = self.bounds.top_left.x + self.bounds.size.width as i32;
+        right - self.text_width(self.value) as i32
+    }
+}
+
+impl<C> View for KeyValueRow<'_, C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        Some(self)
+    }
+}
+
+impl<C> ResizableView for KeyValueRow<'_, C>
+where
+    C: PixelColor,
+{
+    /// Changes the width of the row.
+    ///
+    /// The height of the row is the line height of the text style and is not changed. The row is
+    /// never narrower than its label and value, separated by a space.
+    #[inline]
+    fn resize(&mut self, size: Size) {
+        self.bounds.size.width = size.width.max(self.min_width());
+    }
+
+    #[inline]
+    fn fills_layout(&self) -> bool {
+        true
+    }
+}
+
+impl<C> Drawable for KeyValueRow<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let top_left = self.bounds.top_left;
+        let value_x = self.value_x();
+
+        Text::with_baseline(self.label, top_left, self.style, Baseline::Top).draw(display)?;
+
+        if self.filler {
+            // Dots are placed on the character grid of the row, leaving a space after the label
+            // and before the value.
+            let char_width = self.char_width() as i32;
+            let first = (self.text_width(self.label) as i32 + 2 * char_width - 1) / char_width;
+
+            let mut x = top_left.x + first * char_width;
+            while x + 2 * char_width <= value_x {
+                Text::with_baseline(".", Point::new(x, top_left.y), self.style, Baseline::Top)
+                    .draw(display)?;
+                x += char_width;
+            }
+        }
+
+        Text::with_baseline(
+            self.value,
+            Point::new(value_x, top_left.y),
+            self.style,
+            Baseline::Top,
+        )
+        .draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        mock_display::MockDisplay, mono_font::ascii::FONT_6X9, pixelcolor::BinaryColor,
+    };
+
+    #[test]
+    fn row_is_resized_by_layout() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let layout = LinearLayout::vertical(
+            Chain::new(KeyValueRow::new("A", "1", style))
+                .append(KeyValueRow::new("BBBBBB", "22", style)),
+        )
+        .arrange();
+
+        // The second row is as wide as its label, a space and its value
+        assert_eq!(Size::new(54, 9), layout.bounds_of(0).size);
+        assert_eq!(Size::new(54, 9), layout.bounds_of(1).size);
+    }
+
+    #[test]
+    fn filler_is_drawn_between_label_and_value() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let mut row = KeyValueRow::new("A", "1", style).with_filler(true);
+        row.resize(Size::new(36, 0));
+
+        let mut display = MockDisplay::new();
+        row.draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline("A .. 1", Point::zero(), style, Baseline::Top)
+            .draw(&mut expected)
+            .unwrap();
+
+        assert_eq!(expected, display);
+    }
+}
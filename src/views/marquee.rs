@@ -0,0 +1,308 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{layout::ResizableView, View};
+
+/// A fixed width view that scrolls wider content horizontally in a loop.
+///
+/// `Marquee` clips its content to a fixed width. If the content is wider than the marquee, every
+/// call to [`tick`] scrolls it to the left. When the content has left the marquee, it comes back
+/// from the right after an empty gap. Content that fits into the marquee is not scrolled.
+///
+/// By default, the gap is as wide as the marquee, so the content leaves the marquee completely
+/// before it reappears. Use [`with_gap`] to change this.
+///
+/// When placed in a [`LinearLayout`] with a `Stretch` secondary alignment, the marquee is resized
+/// to the width of the layout.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::Marquee};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let mut layout = LinearLayout::vertical(
+///     Chain::new(Text::new("Now playing:", Point::zero(), text_style)).append(
+///         Marquee::new(
+///             Text::new("A very long song title", Point::zero(), text_style),
+///             64,
+///         )
+///         .with_gap(12),
+///     ),
+/// )
+/// .arrange();
+///
+/// // Call this periodically, e.g. from a timer.
+/// layout.inner_mut().object.tick();
+///
+/// assert_eq!(1, layout.inner().object.offset());
+/// ```
+///
+/// [`tick`]: Marquee::tick
+/// [`with_gap`]: Marquee::with_gap
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+pub struct Marquee<V> {
+    content: V,
+    viewport: Rectangle,
+    offset: u32,
+    gap: Option<u32>,
+    step: u32,
+}
+
+impl<V> Marquee<V>
+where
+    V: View,
+{
+    /// Wraps `content` in a marquee of the given width.
+    ///
+    /// The marquee is placed to the top left corner of the content, and is as tall as the content.
+    #[inline]
+    pub fn new(content: V, width: u32) -> Self {
+        let bounds = content.bounds();
+
+        Self {
+            content,
+            viewport: Rectangle::new(bounds.top_left, Size::new(width, bounds.size.height)),
+            offset: 0,
+            gap: None,
+            step: 1,
+        }
+    }
+
+    /// Sets the empty space between the end of the content and its next appearance.
+    #[inline]
+    #[must_use]
+    pub fn with_gap(mut self, gap: u32) -> Self {
+        self.gap = Some(gap);
+        self.reset();
+        self
+    }
+
+    /// Sets the number of pixels the content is scrolled by in every [`tick`].
+    ///
+    /// [`tick`]: Marquee::tick
+    #[inline]
+    #[must_use]
+    pub fn with_step(mut self, step: u32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns `true` if the content is wider than the marquee.
+    #[inline]
+    pub fn is_scrolling(&self) -> bool {
+        self.content.size().width > self.viewport.size.width
+    }
+
+    /// Returns the number of pixels the content is currently scrolled by.
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Scrolls the content by one step.
+    ///
+    /// Does nothing if the content fits into the marquee.
+    #[inline]
+    pub fn tick(&mut self) {
+        if self.is_scrolling() {
+            self.scroll_to((self.offset + self.step) % self.period());
+        }
+    }
+
+    /// Scrolls the content back to its starting position.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.scroll_to(0);
+    }
+
+    /// Returns a reference to the content.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.content
+    }
+
+    /// Returns a mutable reference to the content.
+    ///
+    /// Translating the content will not update the scroll offset.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.content
+    }
+
+    /// Consumes the marquee and returns the content.
+    #[inline]
+    pub fn into_inner(mut self) -> V {
+        self.reset();
+        self.content
+    }
+
+    /// The distance between two consecutive appearances of the content.
+    fn period(&self) -> u32 {
+        let gap = self.gap.unwrap_or(self.viewport.size.width);
+
+        self.content.size().width + gap
+    }
+
+    fn scroll_to(&mut self, offset: u32) {
+        self.content
+            .translate_impl(Point::new(self.offset as i32 - offset as i32, 0));
+        self.offset = offset;
+    }
+}
+
+impl<V> Clone for Marquee<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            content: self.content.clone(),
+            viewport: self.viewport,
+            offset: self.offset,
+            gap: self.gap,
+            step: self.step,
+        }
+    }
+}
+
+impl<V> View for Marquee<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.viewport.top_left += by;
+        self.content.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.viewport
+    }
+
+    #[inline]
+    fn baseline(&self) -> Option<i32> {
+        self.content.baseline()
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        Some(self)
+    }
+
+    forward_view_hooks!(field content; except baseline, anchor, as_measurable_mut, as_resizable_mut);
+}
+
+impl<V> ResizableView for Marquee<V>
+where
+    V: View,
+{
+    #[inline]
+    fn resize(&mut self, size: Size) {
+        self.viewport.size = size;
+        self.reset();
+    }
+}
+
+impl<C, V> Drawable for Marquee<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut clipped = display.clipped(&self.viewport);
+        self.content.draw(&mut clipped)?;
+
+        if self.is_scrolling() {
+            let next = Point::new(self.period() as i32, 0);
+            self.content.draw(&mut clipped.translated(next))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Primitive,
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn content_is_scrolled_in_a_loop() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let content = Rectangle::new(Point::zero(), Size::new(4, 1)).into_styled(style);
+
+        let mut marquee = Marquee::new(content, 3).with_gap(1);
+        assert!(marquee.is_scrolling());
+
+        marquee.tick();
+        marquee.tick();
+        assert_eq!(2, marquee.offset());
+        assert_eq!(Point::new(-2, 0), marquee.inner().primitive.top_left);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        marquee.draw(&mut display).unwrap();
+        display.assert_pattern(&["## "]);
+
+        marquee.tick();
+        marquee.tick();
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        marquee.draw(&mut display).unwrap();
+        display.assert_pattern(&[" ##"]);
+
+        marquee.tick();
+        assert_eq!(0, marquee.offset());
+        assert_eq!(Point::zero(), marquee.inner().primitive.top_left);
+    }
+
+    #[test]
+    fn short_content_is_not_scrolled() {
+        let content = Rectangle::new(Point::zero(), Size::new(4, 1));
+
+        let mut marquee = Marquee::new(content, 10);
+        marquee.tick();
+
+        assert!(!marquee.is_scrolling());
+        assert_eq!(0, marquee.offset());
+    }
+
+    #[test]
+    fn marquee_is_stretched_in_layout() {
+        let rect = Rectangle::new(Point::zero(), Size::new(20, 2));
+        let content = Rectangle::new(Point::zero(), Size::new(30, 4));
+
+        let layout = LinearLayout::vertical(Chain::new(rect).append(Marquee::new(content, 5)))
+            .with_alignment(horizontal::Stretch)
+            .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(0, 2), Size::new(20, 4)),
+            layout.bounds_of(1)
+        );
+    }
+}
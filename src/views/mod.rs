@@ -0,0 +1,70 @@
+//! Built-in views and view wrappers
+//!
+//! This module contains [`View`] implementations that extend or wrap other views.
+//!
+//! # Using views from other crates
+//!
+//! Every `embedded-graphics` object that implements `Transform` and `Dimensions` is a [`View`].
+//! This includes the `TextBox` type of [`embedded-text`], so text boxes can be placed in layouts
+//! without a wrapper. A text box keeps the bounds it was created with, so the width assigned to
+//! it has to be chosen when it is created, for example using [`RectExt::split_columns`].
+//!
+//! [`View`]: crate::View
+//! [`embedded-text`]: https://crates.io/crates/embedded-text
+//! [`RectExt::split_columns`]: crate::utils::rect_helper::RectExt::split_columns
+
+mod aligned;
+mod anchor;
+mod aspect_ratio;
+mod bar_graph;
+mod baseline;
+mod button;
+mod checkbox;
+mod clearing;
+mod divider;
+#[cfg(feature = "any")]
+mod downcastable;
+mod either;
+mod empty;
+#[cfg(feature = "framebuffer")]
+mod framebuffer;
+mod key_value_row;
+mod marquee;
+mod optional;
+mod overlay;
+mod progress_bar;
+mod rotated;
+mod scroll_view;
+mod selectable;
+mod seven_segment;
+mod titled_panel;
+mod visibility;
+mod z_order;
+
+pub use aligned::Aligned;
+pub use anchor::{AnchorView, WithAnchor};
+pub use aspect_ratio::AspectRatio;
+pub use bar_graph::BarGraph;
+pub use baseline::{BaselineView, WithBaseline};
+pub use button::{Button, ButtonStyle};
+pub use checkbox::{Checkbox, CheckboxShape, RadioButton, RadioButtonShape, Toggle, ToggleShape};
+pub use clearing::Clearing;
+pub use divider::Divider;
+#[cfg(feature = "any")]
+pub use downcastable::Downcastable;
+pub use either::Either;
+pub use empty::EmptyView;
+#[cfg(feature = "framebuffer")]
+pub use framebuffer::FramebufferView;
+pub use key_value_row::KeyValueRow;
+pub use marquee::Marquee;
+pub use optional::Optional;
+pub use overlay::Overlay;
+pub use progress_bar::ProgressBar;
+pub use rotated::{Rotated, RotatedDrawTarget, Rotation};
+pub use scroll_view::ScrollView;
+pub use selectable::{InvertIndicator, OutlineIndicator, Selectable, SelectionIndicator};
+pub use seven_segment::SevenSegment;
+pub use titled_panel::TitledPanel;
+pub use visibility::Visibility;
+pub use z_order::ZOrder;
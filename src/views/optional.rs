@@ -0,0 +1,241 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// A view that may or may not be present.
+///
+/// `View` can't be implemented for `Option<V>` because it would conflict with the blanket
+/// implementation for `embedded-graphics` objects, so this wrapper is used instead. A missing view
+/// takes up no space and is not drawn. The position of the missing view is tracked, so layouts
+/// can still be arranged.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::Optional};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let unread_messages = 0;
+/// let notification = if unread_messages > 0 {
+///     Some(Text::new("New messages", Point::zero(), text_style))
+/// } else {
+///     None
+/// };
+///
+/// let title = Text::new("Inbox", Point::zero(), text_style);
+///
+/// let layout = LinearLayout::vertical(Chain::new(title).append(Optional::from(notification)))
+///     .arrange();
+///
+/// // The missing notification takes up no space.
+/// assert_eq!(title.size(), layout.size());
+/// ```
+pub struct Optional<V> {
+    view: Option<V>,
+    position: Point,
+}
+
+impl<V> Optional<V>
+where
+    V: View,
+{
+    /// Wraps the given optional view.
+    #[inline]
+    pub fn new(view: Option<V>) -> Self {
+        Self {
+            view,
+            position: Point::zero(),
+        }
+    }
+
+    /// Returns a reference to the wrapped view, if present.
+    #[inline]
+    pub fn get(&self) -> Option<&V> {
+        self.view.as_ref()
+    }
+
+    /// Returns a mutable reference to the wrapped view, if present.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut V> {
+        self.view.as_mut()
+    }
+
+    /// Replaces the wrapped view and returns the previous one.
+    #[inline]
+    pub fn set(&mut self, view: Option<V>) -> Option<V> {
+        let previous = self.take();
+        self.view = view;
+        previous
+    }
+
+    /// Removes the wrapped view and returns it.
+    ///
+    /// The position of the removed view is kept, so the empty wrapper stays in place.
+    #[inline]
+    pub fn take(&mut self) -> Option<V> {
+        if let Some(view) = self.view.as_ref() {
+            self.position = view.bounds().top_left;
+        }
+
+        self.view.take()
+    }
+
+    /// Returns `true` if the view is present.
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        self.view.is_some()
+    }
+
+    /// Returns `true` if the view is missing.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.view.is_none()
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> Option<V> {
+        self.view
+    }
+}
+
+impl<V> From<Option<V>> for Optional<V>
+where
+    V: View,
+{
+    #[inline]
+    fn from(view: Option<V>) -> Self {
+        Self::new(view)
+    }
+}
+
+impl<V> Default for Optional<V>
+where
+    V: View,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<V> Clone for Optional<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            position: self.position,
+        }
+    }
+}
+
+impl<V> View for Optional<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+
+        if let Some(view) = self.view.as_mut() {
+            view.translate_impl(by);
+        }
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        match self.view.as_ref() {
+            Some(view) => view.bounds(),
+            None => Rectangle::new(self.position, Size::zero()),
+        }
+    }
+
+    forward_view_hooks!(fn get, get_mut);
+}
+
+impl<C, V> Drawable for Optional<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(view) = self.view.as_ref() {
+            view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Styled},
+    };
+
+    #[test]
+    fn missing_view_is_collapsed_and_not_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+
+        let layout = LinearLayout::horizontal(
+            Chain::new(Optional::new(Some(rect)))
+                .append(Optional::<Styled<Rectangle, _>>::new(None))
+                .append(Optional::new(Some(rect))),
+        )
+        .arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "####", //
+                "####",
+            ])
+        );
+    }
+
+    #[test]
+    fn position_is_kept_when_view_is_removed() {
+        let rect = Rectangle::new(Point::new(1, 2), Size::new(3, 4));
+        let mut view = Optional::new(Some(rect));
+
+        view.translate_mut(Point::new(1, 1));
+        assert_eq!(Some(rect.translate(Point::new(1, 1))), view.take());
+
+        assert!(view.is_none());
+        assert_eq!(
+            Rectangle::new(Point::new(2, 3), Size::zero()),
+            view.bounds()
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use core::cell::Cell;
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::AnchorPoint,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::{prelude::RectExt, View};
+
+/// Places a transient view, like a toast notification, on top of the screen.
+///
+/// `Overlay` places the view at one of nine positions of an area, usually the display: at a
+/// corner, at the center of an edge or at the center of the area. A margin keeps the view away
+/// from the edges of the area.
+///
+/// The overlay remembers the area it covered when it was drawn. Use [`clear`] to restore the
+/// background when the notification is dismissed, or to clear the previous location before the
+/// view is drawn again, e.g. after its text was changed.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_graphics::geometry::AnchorPoint;
+/// use embedded_layout::{prelude::*, views::Overlay};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// display.set_allow_overdraw(true);
+///
+/// let toast = Overlay::new(
+///     Text::new("Saved", Point::zero(), text_style),
+///     display.bounding_box(),
+///     AnchorPoint::BottomCenter,
+/// )
+/// .with_margin(4);
+///
+/// assert_eq!(Point::new(17, 51), toast.bounds().top_left);
+///
+/// toast.draw(&mut display).unwrap();
+/// assert_eq!(Some(toast.bounds()), toast.occluded());
+///
+/// // Dismiss the notification.
+/// toast.clear(&mut display, BinaryColor::Off).unwrap();
+/// assert_eq!(None, toast.occluded());
+/// ```
+///
+/// [`clear`]: Overlay::clear
+pub struct Overlay<V> {
+    view: V,
+    area: Rectangle,
+    anchor: AnchorPoint,
+    margin: u32,
+    occluded: Cell<Option<Rectangle>>,
+}
+
+impl<V> Overlay<V>
+where
+    V: View,
+{
+    /// Places `view` at the `anchor` point of `area`.
+    #[inline]
+    pub fn new(view: V, area: Rectangle, anchor: AnchorPoint) -> Self {
+        let mut overlay = Self {
+            view,
+            area,
+            anchor,
+            margin: 0,
+            occluded: Cell::new(None),
+        };
+        overlay.reposition();
+        overlay
+    }
+
+    /// Sets the distance between the view and the edges of the area.
+    ///
+    /// The margin is not applied along an axis where the view is centered.
+    #[inline]
+    #[must_use]
+    pub fn with_margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self.reposition();
+        self
+    }
+
+    /// Moves the view to the `anchor` point of the area.
+    #[inline]
+    pub fn set_anchor(&mut self, anchor: AnchorPoint) {
+        self.anchor = anchor;
+        self.reposition();
+    }
+
+    /// Returns the position of the view in the area.
+    #[inline]
+    pub fn anchor(&self) -> AnchorPoint {
+        self.anchor
+    }
+
+    /// Returns the distance between the view and the edges of the area.
+    #[inline]
+    pub fn margin(&self) -> u32 {
+        self.margin
+    }
+
+    /// Returns the area the view is placed in.
+    #[inline]
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+
+    /// Places the view again.
+    ///
+    /// Call this method after the size of the view changed, e.g. after changing its text.
+    #[inline]
+    pub fn reposition(&mut self) {
+        let reference = self.area.offset(-(self.margin as i32));
+        let bounds = self.view.bounds();
+
+        let by = reference.anchor_point(self.anchor) - bounds.anchor_point(self.anchor);
+        self.view.translate_impl(by);
+    }
+
+    /// Returns the area covered by the view since it was last cleared, if it was drawn.
+    #[inline]
+    pub fn occluded(&self) -> Option<Rectangle> {
+        self.occluded.get()
+    }
+
+    /// Fills the area covered by the view with the background color, and forgets it.
+    #[inline]
+    pub fn clear<D>(&self, display: &mut D, background: D::Color) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+    {
+        if let Some(occluded) = self.occluded.take() {
+            display.fill_solid(&occluded, background)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    ///
+    /// Call [`reposition`] after changing the size of the view.
+    ///
+    /// [`reposition`]: Overlay::reposition
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the overlay and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for Overlay<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            area: self.area,
+            anchor: self.anchor,
+            margin: self.margin,
+            occluded: self.occluded.clone(),
+        }
+    }
+}
+
+impl<V> View for Overlay<V>
+where
+    V: View,
+{
+    /// Moves the view and the area it is placed in.
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.area.top_left += by;
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    forward_view_hooks!(field view; except as_measurable_mut, as_resizable_mut);
+}
+
+impl<C, V> Drawable for Overlay<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+
+        let bounds = self.view.bounds();
+        let occluded = self
+            .occluded
+            .get()
+            .map_or(bounds, |occluded| occluded.enveloping(&bounds));
+        self.occluded.set(Some(occluded));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Primitive, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn view_is_placed_at_anchor_with_margin() {
+        let area = Rectangle::new(Point::new(10, 10), Size::new(20, 10));
+        let rect = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        let mut overlay = Overlay::new(rect, area, AnchorPoint::TopLeft).with_margin(1);
+        assert_eq!(Point::new(11, 11), overlay.bounds().top_left);
+
+        overlay.set_anchor(AnchorPoint::BottomRight);
+        assert_eq!(Point::new(25, 17), overlay.bounds().top_left);
+
+        overlay.set_anchor(AnchorPoint::CenterLeft);
+        assert_eq!(Point::new(11, 14), overlay.bounds().top_left);
+    }
+
+    #[test]
+    fn occluded_area_is_cleared() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let dot = Rectangle::new(Point::zero(), Size::new(1, 1)).into_styled(style);
+        let area = Rectangle::new(Point::zero(), Size::new(3, 2));
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        let mut overlay = Overlay::new(dot, area, AnchorPoint::TopLeft);
+        overlay.draw(&mut display).unwrap();
+        overlay.set_anchor(AnchorPoint::BottomRight);
+        overlay.draw(&mut display).unwrap();
+
+        assert_eq!(
+            Some(Rectangle::new(Point::zero(), Size::new(3, 2))),
+            overlay.occluded()
+        );
+
+        overlay.clear(&mut display, BinaryColor::Off).unwrap();
+        display.assert_pattern(&[
+            "...", //
+            "...", //
+        ]);
+        assert_eq!(None, overlay.occluded());
+    }
+}
@@ -0,0 +1,274 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::AnchorPoint,
+    prelude::{PixelColor, Point, Primitive, Size},
+    primitives::{PrimitiveStyle, Rectangle},
+    Drawable,
+};
+
+use crate::{
+    layout::ResizableView,
+    prelude::{Insets, RectExt},
+    View,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+/// A bar that shows progress as a percentage.
+///
+/// The bar is drawn as a 1px border and a filled area inside it. Horizontal bars fill up from
+/// left to right, vertical bars from the bottom up. The padding is the empty space between the
+/// border and the filled area.
+///
+/// When placed in a [`LinearLayout`] with a `Stretch` secondary alignment, the bar is resized to
+/// the width (or height) of the layout.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// use embedded_layout::{
+///     layout::linear::{spacing::FixedMargin, LinearLayout},
+///     prelude::*,
+///     views::ProgressBar,
+/// };
+///
+/// let mut download = ProgressBar::horizontal(Size::new(64, 8), BinaryColor::On);
+/// download.set_progress(30);
+///
+/// let layout = LinearLayout::vertical(
+///     Chain::new(download).append(
+///         ProgressBar::horizontal(Size::new(32, 6), BinaryColor::On)
+///             .with_padding(0)
+///             .with_progress(100),
+///     ),
+/// )
+/// .with_spacing(FixedMargin(4))
+/// .arrange();
+///
+/// assert_eq!(30, layout.inner().parent.object.progress());
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+#[derive(Copy, Clone)]
+pub struct ProgressBar<C> {
+    bounds: Rectangle,
+    orientation: Orientation,
+    stroke_color: C,
+    fill_color: C,
+    padding: u32,
+    progress: u32,
+}
+
+impl<C> ProgressBar<C>
+where
+    C: PixelColor,
+{
+    /// Creates an empty progress bar that fills up from left to right.
+    ///
+    /// The border and the filled area are both drawn using `color`, with 1px padding between
+    /// them.
+    #[inline]
+    pub fn horizontal(size: Size, color: C) -> Self {
+        Self::new(size, color, Orientation::Horizontal)
+    }
+
+    /// Creates an empty progress bar that fills up from the bottom to the top.
+    ///
+    /// The border and the filled area are both drawn using `color`, with 1px padding between
+    /// them.
+    #[inline]
+    pub fn vertical(size: Size, color: C) -> Self {
+        Self::new(size, color, Orientation::Vertical)
+    }
+
+    fn new(size: Size, color: C, orientation: Orientation) -> Self {
+        Self {
+            bounds: Rectangle::new(Point::zero(), size),
+            orientation,
+            stroke_color: color,
+            fill_color: color,
+            padding: 1,
+            progress: 0,
+        }
+    }
+
+    /// Sets the color of the border.
+    #[inline]
+    #[must_use]
+    pub fn with_stroke_color(mut self, color: C) -> Self {
+        self.stroke_color = color;
+        self
+    }
+
+    /// Sets the color of the filled area.
+    #[inline]
+    #[must_use]
+    pub fn with_fill_color(mut self, color: C) -> Self {
+        self.fill_color = color;
+        self
+    }
+
+    /// Sets the empty space between the border and the filled area.
+    #[inline]
+    #[must_use]
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the progress, in percent.
+    #[inline]
+    #[must_use]
+    pub fn with_progress(mut self, progress: u32) -> Self {
+        self.set_progress(progress);
+        self
+    }
+
+    /// Sets the progress, in percent.
+    ///
+    /// Values over 100 are clamped to 100.
+    #[inline]
+    pub fn set_progress(&mut self, progress: u32) {
+        self.progress = progress.min(100);
+    }
+
+    /// Returns the progress, in percent.
+    #[inline]
+    pub fn progress(&self) -> u32 {
+        self.progress
+    }
+
+    /// Returns the area covered by the filled part of the bar.
+    fn filled_area(&self) -> Rectangle {
+        let inner = self.bounds.shrink(Insets::uniform(self.padding + 1));
+
+        match self.orientation {
+            Orientation::Horizontal => inner.relative(self.progress, 100, AnchorPoint::CenterLeft),
+            Orientation::Vertical => inner.relative(100, self.progress, AnchorPoint::BottomCenter),
+        }
+    }
+}
+
+impl<C> View for ProgressBar<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        Some(self)
+    }
+}
+
+impl<C> ResizableView for ProgressBar<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn resize(&mut self, size: Size) {
+        self.bounds.size = size;
+    }
+}
+
+impl<C> Drawable for ProgressBar<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.bounds
+            .into_styled(PrimitiveStyle::with_stroke(self.stroke_color, 1))
+            .draw(display)?;
+
+        self.filled_area()
+            .into_styled(PrimitiveStyle::with_fill(self.fill_color))
+            .draw(display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn horizontal_bar_fills_from_the_left() {
+        let bar = ProgressBar::horizontal(Size::new(8, 5), BinaryColor::On).with_progress(50);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        bar.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "########", //
+            "#      #", //
+            "# ##   #", //
+            "#      #", //
+            "########", //
+        ]);
+    }
+
+    #[test]
+    fn vertical_bar_fills_from_the_bottom() {
+        let bar = ProgressBar::vertical(Size::new(4, 6), BinaryColor::On)
+            .with_padding(0)
+            .with_fill_color(BinaryColor::Off)
+            .with_progress(50);
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        bar.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "####", //
+            "#  #", //
+            "#  #", //
+            "#..#", //
+            "#..#", //
+            "####", //
+        ]);
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        let mut bar = ProgressBar::horizontal(Size::new(8, 5), BinaryColor::On);
+        bar.set_progress(150);
+
+        assert_eq!(100, bar.progress());
+    }
+
+    #[test]
+    fn bar_is_stretched_in_layout() {
+        let rect = Rectangle::new(Point::zero(), Size::new(20, 2));
+
+        let layout = LinearLayout::vertical(
+            Chain::new(rect).append(ProgressBar::horizontal(Size::new(5, 4), BinaryColor::On)),
+        )
+        .with_alignment(horizontal::Stretch)
+        .arrange();
+
+        assert_eq!(
+            Rectangle::new(Point::new(0, 2), Size::new(20, 4)),
+            layout.bounds_of(1)
+        );
+    }
+}
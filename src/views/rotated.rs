@@ -0,0 +1,428 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{Dimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable, Pixel,
+};
+
+use crate::{
+    layout::{Constraints, MeasurableView, ResizableView},
+    View,
+};
+
+/// Clockwise rotation of a [`Rotated`] view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    /// The view is not rotated.
+    Rotate0,
+
+    /// The view is rotated by 90 degrees clockwise.
+    Rotate90,
+
+    /// The view is rotated by 180 degrees.
+    Rotate180,
+
+    /// The view is rotated by 270 degrees clockwise.
+    Rotate270,
+}
+
+impl Rotation {
+    /// Returns the size of an object of the given size after rotating it.
+    #[inline]
+    pub fn rotate_size(self, size: Size) -> Size {
+        match self {
+            Rotation::Rotate0 | Rotation::Rotate180 => size,
+            Rotation::Rotate90 | Rotation::Rotate270 => Size::new(size.height, size.width),
+        }
+    }
+
+    /// Returns the constraints of an object after rotating it.
+    fn rotate_constraints(self, constraints: Constraints) -> Constraints {
+        match self {
+            Rotation::Rotate0 | Rotation::Rotate180 => constraints,
+            Rotation::Rotate90 | Rotation::Rotate270 => Constraints {
+                max_width: constraints.max_height,
+                max_height: constraints.max_width,
+            },
+        }
+    }
+
+    /// Maps `point` of the `source` area to the rotated area at `target`.
+    fn map_point(self, point: Point, source: Rectangle, target: Point) -> Point {
+        let d = point - source.top_left;
+        let w = source.size.width as i32;
+        let h = source.size.height as i32;
+
+        let rotated = match self {
+            Rotation::Rotate0 => d,
+            Rotation::Rotate90 => Point::new(h - 1 - d.y, d.x),
+            Rotation::Rotate180 => Point::new(w - 1 - d.x, h - 1 - d.y),
+            Rotation::Rotate270 => Point::new(d.y, w - 1 - d.x),
+        };
+
+        target + rotated
+    }
+
+    /// Maps `point` of the rotated area at `target` back to the `source` area.
+    fn unmap_point(self, point: Point, source: Rectangle, target: Point) -> Point {
+        let d = point - target;
+        let w = source.size.width as i32;
+        let h = source.size.height as i32;
+
+        let original = match self {
+            Rotation::Rotate0 => d,
+            Rotation::Rotate90 => Point::new(d.y, h - 1 - d.x),
+            Rotation::Rotate180 => Point::new(w - 1 - d.x, h - 1 - d.y),
+            Rotation::Rotate270 => Point::new(w - 1 - d.y, d.x),
+        };
+
+        source.top_left + original
+    }
+}
+
+/// Transforms a rectangle by mapping two of its corners.
+fn map_rectangle(rect: &Rectangle, map: impl Fn(Point) -> Point) -> Rectangle {
+    match rect.bottom_right() {
+        Some(bottom_right) => Rectangle::with_corners(map(rect.top_left), map(bottom_right)),
+        None => Rectangle::new(map(rect.top_left), Size::zero()),
+    }
+}
+
+/// A [`View`] that is drawn rotated by a multiple of 90 degrees.
+///
+/// The wrapped view is arranged and drawn in its own, unrotated coordinate system, and `Rotated`
+/// maps its pixels to the display. This lets the same layout code serve a display that is mounted
+/// sideways, or show a vertical label next to other views.
+///
+/// The bounds of `Rotated` are the rotated bounds of the wrapped view, so a layout that contains
+/// it places the rotated area. When measured or resized by a layout, the constraints and sizes are
+/// rotated before they are passed to the wrapped view.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mock_display::MockDisplay,
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     views::{Rotated, Rotation},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let layout = LinearLayout::vertical(
+///     Chain::new(Text::new("Menu", Point::zero(), text_style))
+///         .append(Text::new("Settings", Point::zero(), text_style)),
+/// )
+/// .arrange();
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+///
+/// // The display is mounted sideways.
+/// let screen = Rotated::new(layout, Rotation::Rotate90).align_to(
+///     &display.bounding_box(),
+///     horizontal::Left,
+///     vertical::Top,
+/// );
+/// assert_eq!(Size::new(18, 48), screen.size());
+///
+/// screen.draw(&mut display).unwrap();
+/// ```
+pub struct Rotated<V> {
+    view: V,
+    rotation: Rotation,
+    top_left: Point,
+}
+
+impl<V> Rotated<V>
+where
+    V: View,
+{
+    /// Rotates `view`.
+    ///
+    /// The rotated view is placed to the top left corner of the original view.
+    #[inline]
+    pub fn new(view: V, rotation: Rotation) -> Self {
+        Self {
+            top_left: view.bounds().top_left,
+            view,
+            rotation,
+        }
+    }
+
+    /// Returns the rotation of the view.
+    #[inline]
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Changes the rotation of the view, keeping its top left corner in place.
+    #[inline]
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    ///
+    /// The wrapped view uses its own coordinate system. Translating it doesn't move the rotated
+    /// view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the object and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for Rotated<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            rotation: self.rotation,
+            top_left: self.top_left,
+        }
+    }
+}
+
+impl<V> View for Rotated<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.top_left, self.rotation.rotate_size(self.view.size()))
+    }
+
+    #[inline]
+    fn as_measurable_mut(&mut self) -> Option<&mut dyn MeasurableView> {
+        if self.view.as_measurable_mut().is_some() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_resizable_mut(&mut self) -> Option<&mut dyn ResizableView> {
+        if self.view.as_resizable_mut().is_some() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    forward_view_hooks!(field view; except secondary_alignment_override, baseline, anchor, as_measurable_mut, as_resizable_mut);
+}
+
+impl<V> MeasurableView for Rotated<V>
+where
+    V: View,
+{
+    #[inline]
+    fn measure(&mut self, constraints: Constraints) -> Size {
+        let constraints = self.rotation.rotate_constraints(constraints);
+
+        if let Some(view) = self.view.as_measurable_mut() {
+            view.measure(constraints);
+        }
+
+        self.size()
+    }
+}
+
+impl<V> ResizableView for Rotated<V>
+where
+    V: View,
+{
+    #[inline]
+    fn resize(&mut self, size: Size) {
+        let size = self.rotation.rotate_size(size);
+
+        if let Some(view) = self.view.as_resizable_mut() {
+            view.resize(size);
+        }
+    }
+}
+
+impl<C, V> Drawable for Rotated<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut rotated =
+            RotatedDrawTarget::new(display, self.rotation, self.view.bounds(), self.top_left);
+        self.view.draw(&mut rotated)?;
+
+        Ok(())
+    }
+}
+
+/// A [`DrawTarget`] adapter that rotates the pixels drawn into an area.
+///
+/// Pixels drawn into the `source` area are rotated, and drawn to the parent draw target so that
+/// the top left corner of the rotated area is at `target`. Pixels outside of `source` are drawn
+/// to the same relative positions. The bounding box of the adapter is the bounding box of the
+/// parent, mapped back to the unrotated coordinate system.
+///
+/// This is the adapter [`Rotated`] uses to draw its view.
+pub struct RotatedDrawTarget<'a, D> {
+    parent: &'a mut D,
+    rotation: Rotation,
+    source: Rectangle,
+    target: Point,
+}
+
+impl<'a, D> RotatedDrawTarget<'a, D>
+where
+    D: DrawTarget,
+{
+    /// Creates a new rotating adapter.
+    #[inline]
+    pub fn new(parent: &'a mut D, rotation: Rotation, source: Rectangle, target: Point) -> Self {
+        Self {
+            parent,
+            rotation,
+            source,
+            target,
+        }
+    }
+}
+
+impl<D> Dimensions for RotatedDrawTarget<'_, D>
+where
+    D: DrawTarget,
+{
+    #[inline]
+    fn bounding_box(&self) -> Rectangle {
+        let (rotation, source, target) = (self.rotation, self.source, self.target);
+
+        map_rectangle(&self.parent.bounding_box(), |p| {
+            rotation.unmap_point(p, source, target)
+        })
+    }
+}
+
+impl<D> DrawTarget for RotatedDrawTarget<'_, D>
+where
+    D: DrawTarget,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    #[inline]
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (rotation, source, target) = (self.rotation, self.source, self.target);
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(p, color)| Pixel(rotation.map_point(p, source, target), color)),
+        )
+    }
+
+    #[inline]
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let (rotation, source, target) = (self.rotation, self.source, self.target);
+        let area = map_rectangle(area, |p| rotation.map_point(p, source, target));
+
+        self.parent.fill_solid(&area, color)
+    }
+
+    #[inline]
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{Line, PrimitiveStyle},
+    };
+
+    #[test]
+    fn pixels_are_rotated() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        // An L shape.
+        let shape = Chain::new(Line::new(Point::new(5, 5), Point::new(5, 7)).into_styled(style))
+            .append(Line::new(Point::new(6, 7), Point::new(7, 7)).into_styled(style));
+
+        let patterns: [(Rotation, &[&str]); 4] = [
+            (Rotation::Rotate0, &["#  ", "#  ", "###"]),
+            (Rotation::Rotate90, &["###", "#  ", "#  "]),
+            (Rotation::Rotate180, &["###", "  #", "  #"]),
+            (Rotation::Rotate270, &["  #", "  #", "###"]),
+        ];
+
+        for (rotation, pattern) in patterns.iter() {
+            let rotated = Rotated::new(shape.clone(), *rotation).translate(Point::new(-5, -5));
+
+            let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+            rotated.draw(&mut display).unwrap();
+
+            display.assert_pattern(pattern);
+        }
+    }
+
+    #[test]
+    fn rotated_view_is_arranged_by_its_rotated_size() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let bar = Rectangle::new(Point::zero(), Size::new(3, 1)).into_styled(style);
+
+        let layout =
+            LinearLayout::horizontal(Chain::new(bar).append(Rotated::new(bar, Rotation::Rotate90)))
+                .arrange();
+
+        assert_eq!(Size::new(4, 3), layout.size());
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "   #", //
+            "   #", //
+            "####", //
+        ]);
+    }
+}
@@ -0,0 +1,260 @@
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// A view that displays a part of a larger view.
+///
+/// `ScrollView` wraps a view (the content) that may be larger than the area available to it. Only
+/// the part of the content that is inside the viewport will be drawn. Use [`scroll_to`] and
+/// [`scroll_by`] to change which part of the content is visible.
+///
+/// For the purposes of layouts and alignment, `ScrollView` reports the viewport as its bounds.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::ScrollView};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let menu = LinearLayout::vertical(
+///     Chain::new(Text::new("First", Point::zero(), text_style))
+///         .append(Text::new("Second", Point::zero(), text_style))
+///         .append(Text::new("Third", Point::zero(), text_style)),
+/// )
+/// .arrange();
+///
+/// // Only show two lines of the menu at a time.
+/// let mut scroll_view = ScrollView::new(menu, Size::new(36, 18));
+///
+/// // Scroll down by one line.
+/// scroll_view.scroll_by(Point::new(0, 9));
+///
+/// assert_eq!(Point::new(0, 9), scroll_view.scroll_position());
+/// ```
+///
+/// [`scroll_to`]: ScrollView::scroll_to
+/// [`scroll_by`]: ScrollView::scroll_by
+pub struct ScrollView<V> {
+    content: V,
+    viewport: Rectangle,
+    scroll_position: Point,
+}
+
+impl<V> ScrollView<V>
+where
+    V: View,
+{
+    /// Wraps `content` in a viewport of the given size.
+    ///
+    /// The viewport is placed to the top left corner of the content.
+    #[inline]
+    pub fn new(content: V, size: Size) -> Self {
+        let viewport = Rectangle::new(content.bounds().top_left, size);
+
+        Self {
+            content,
+            viewport,
+            scroll_position: Point::zero(),
+        }
+    }
+
+    /// Returns the position of the viewport relative to the top left corner of the content.
+    #[inline]
+    pub fn scroll_position(&self) -> Point {
+        self.scroll_position
+    }
+
+    /// Returns the largest possible scroll position.
+    ///
+    /// The content can't be scrolled further than where its bottom right corner is visible.
+    #[inline]
+    pub fn max_scroll_position(&self) -> Point {
+        let max = self.content.size().saturating_sub(self.viewport.size);
+
+        Point::new(max.width as i32, max.height as i32)
+    }
+
+    /// Scrolls the content so that `position` (relative to the top left corner of the content) is
+    /// displayed in the top left corner of the viewport.
+    ///
+    /// The position is clamped so that the viewport does not leave the content.
+    #[inline]
+    pub fn scroll_to(&mut self, position: Point) -> &mut Self {
+        let position = position
+            .component_max(Point::zero())
+            .component_min(self.max_scroll_position());
+
+        self.content.translate_impl(self.scroll_position - position);
+        self.scroll_position = position;
+
+        self
+    }
+
+    /// Scrolls the content by the given number of (x, y) pixels.
+    ///
+    /// The resulting position is clamped so that the viewport does not leave the content.
+    #[inline]
+    pub fn scroll_by(&mut self, by: Point) -> &mut Self {
+        self.scroll_to(self.scroll_position + by)
+    }
+
+    /// Returns a reference to the content.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.content
+    }
+
+    /// Returns a mutable reference to the content.
+    ///
+    /// Translating the content will not update the scroll position.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.content
+    }
+
+    /// Consumes the scroll view and returns the content.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.content
+    }
+}
+
+impl<V> View for ScrollView<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.viewport.top_left += by;
+        self.content.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.viewport
+    }
+
+    forward_view_hooks!(field content; except secondary_alignment_override, baseline, anchor, as_measurable_mut, as_resizable_mut);
+}
+
+impl<C, V> Drawable for ScrollView<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.content.draw(&mut display.clipped(&self.viewport))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::{FixedMargin, LinearLayout},
+        prelude::*,
+        views::{OutlineIndicator, Selectable},
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Rectangle},
+    };
+
+    #[test]
+    fn bounds_are_the_viewport() {
+        let content = Rectangle::new(Point::new(2, 3), Size::new(10, 20));
+        let mut scroll_view = ScrollView::new(content, Size::new(5, 5));
+
+        assert_eq!(
+            Rectangle::new(Point::new(2, 3), Size::new(5, 5)),
+            scroll_view.bounds()
+        );
+
+        scroll_view.scroll_by(Point::new(1, 4));
+
+        assert_eq!(
+            Rectangle::new(Point::new(2, 3), Size::new(5, 5)),
+            scroll_view.bounds()
+        );
+        assert_eq!(Point::new(1, -1), scroll_view.inner().top_left);
+    }
+
+    #[test]
+    fn scroll_position_is_clamped() {
+        let content = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let mut scroll_view = ScrollView::new(content, Size::new(5, 5));
+
+        scroll_view.scroll_to(Point::new(100, 100));
+        assert_eq!(Point::new(5, 15), scroll_view.scroll_position());
+
+        scroll_view.scroll_by(Point::new(-100, -1));
+        assert_eq!(Point::new(0, 14), scroll_view.scroll_position());
+    }
+
+    #[test]
+    fn translate_moves_viewport_and_content() {
+        let content = Rectangle::new(Point::zero(), Size::new(10, 20));
+        let mut scroll_view = ScrollView::new(content, Size::new(5, 5));
+        scroll_view.scroll_to(Point::new(0, 2));
+
+        let scroll_view = scroll_view.translate(Point::new(3, 3));
+
+        assert_eq!(Point::new(3, 3), scroll_view.bounds().top_left);
+        assert_eq!(Point::new(3, 1), scroll_view.inner().top_left);
+        assert_eq!(Point::new(0, 2), scroll_view.scroll_position());
+    }
+
+    #[test]
+    fn draw_is_clipped_to_viewport() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 2)).into_styled(style);
+
+        let layout = LinearLayout::vertical(Chain::new(rect).append(rect).append(rect))
+            .with_spacing(FixedMargin(1))
+            .arrange();
+
+        let mut scroll_view = ScrollView::new(layout, Size::new(2, 4));
+        scroll_view.scroll_by(Point::new(1, 2));
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        scroll_view.draw(&mut disp).unwrap();
+
+        assert_eq!(disp, MockDisplay::from_pattern(&["  ", "##", "##", "  ",]));
+    }
+
+    #[test]
+    fn selection_is_forwarded_to_the_content() {
+        let content = Selectable::new(
+            Rectangle::new(Point::zero(), Size::new(10, 20)),
+            OutlineIndicator::new(BinaryColor::On),
+        );
+        let mut scroll_view = ScrollView::new(content, Size::new(5, 5));
+
+        scroll_view.set_selected(true);
+
+        assert_eq!(Some(true), scroll_view.is_selected());
+    }
+}
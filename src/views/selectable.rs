@@ -0,0 +1,379 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, PixelColor, Point},
+    primitives::{PrimitiveStyleBuilder, Rectangle, StrokeAlignment, StyledDrawable},
+    Drawable, Pixel,
+};
+
+use crate::View;
+
+/// Marks a selected view when it is drawn.
+///
+/// See [`OutlineIndicator`] and [`InvertIndicator`] for the built-in indicators.
+pub trait SelectionIndicator<C>
+where
+    C: PixelColor,
+{
+    /// Draws a selected view.
+    fn draw_selected<V, D>(&self, view: &V, display: &mut D) -> Result<(), D::Error>
+    where
+        V: View + Drawable<Color = C>,
+        D: DrawTarget<Color = C>;
+}
+
+/// Draws a 1px outline around the selected view.
+///
+/// The outline is drawn outside of the bounds of the view, so make sure there is enough space
+/// around the view, for example by using [`FixedMargin`] spacing in a layout.
+///
+/// [`FixedMargin`]: crate::layout::linear::FixedMargin
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutlineIndicator<C> {
+    color: C,
+}
+
+impl<C> OutlineIndicator<C>
+where
+    C: PixelColor,
+{
+    /// Creates an indicator that draws an outline using `color`.
+    #[inline]
+    pub const fn new(color: C) -> Self {
+        Self { color }
+    }
+}
+
+impl<C> SelectionIndicator<C> for OutlineIndicator<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn draw_selected<V, D>(&self, view: &V, display: &mut D) -> Result<(), D::Error>
+    where
+        V: View + Drawable<Color = C>,
+        D: DrawTarget<Color = C>,
+    {
+        view.draw(display)?;
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(self.color)
+            .stroke_width(1)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .build();
+
+        view.bounds().draw_styled(&style, display)
+    }
+}
+
+/// Draws the selected view with inverted colors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct InvertIndicator;
+
+impl SelectionIndicator<BinaryColor> for InvertIndicator {
+    #[inline]
+    fn draw_selected<V, D>(&self, view: &V, display: &mut D) -> Result<(), D::Error>
+    where
+        V: View + Drawable<Color = BinaryColor>,
+        D: DrawTarget<Color = BinaryColor>,
+    {
+        display.fill_solid(&view.bounds(), BinaryColor::On)?;
+        view.draw(&mut Inverted { display })?;
+
+        Ok(())
+    }
+}
+
+/// Inverts the colors drawn to the wrapped draw target.
+struct Inverted<'a, D> {
+    display: &'a mut D,
+}
+
+impl<D> Dimensions for Inverted<'_, D>
+where
+    D: DrawTarget<Color = BinaryColor>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.display.bounding_box()
+    }
+}
+
+impl<D> DrawTarget for Inverted<'_, D>
+where
+    D: DrawTarget<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.display.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(point, color.invert())),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.display
+            .fill_contiguous(area, colors.into_iter().map(BinaryColor::invert))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.display.fill_solid(area, color.invert())
+    }
+}
+
+/// A view that can be selected, for example an item in a menu.
+///
+/// Selected views are drawn using a [`SelectionIndicator`]. Layouts can move the selection
+/// between their selectable views, see [`LinearLayout::select_next`] and
+/// [`LinearLayout::select_prev`].
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{
+///     layout::linear::LinearLayout,
+///     prelude::*,
+///     views::{InvertIndicator, Selectable},
+/// };
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+/// let item = |label| Selectable::new(Text::new(label, Point::zero(), text_style), InvertIndicator);
+///
+/// let mut menu = LinearLayout::vertical(
+///     Chain::new(item("Start"))
+///         .append(item("Settings"))
+///         .append(item("About")),
+/// )
+/// .arrange();
+///
+/// assert_eq!(Some(0), menu.select_next());
+/// assert_eq!(Some(1), menu.select_next());
+/// assert_eq!(Some(0), menu.select_prev());
+/// assert_eq!(Some(2), menu.select_prev());
+/// ```
+///
+/// [`LinearLayout::select_next`]: crate::layout::linear::LinearLayout::select_next
+/// [`LinearLayout::select_prev`]: crate::layout::linear::LinearLayout::select_prev
+pub struct Selectable<V, I> {
+    view: V,
+    indicator: I,
+    selected: bool,
+}
+
+impl<V, I> Selectable<V, I>
+where
+    V: View,
+{
+    /// Wraps the given view. The view is not selected by default.
+    #[inline]
+    pub fn new(view: V, indicator: I) -> Self {
+        Self {
+            view,
+            indicator,
+            selected: false,
+        }
+    }
+
+    /// Selects the view.
+    #[inline]
+    pub fn select(&mut self) -> &mut Self {
+        self.selected = true;
+        self
+    }
+
+    /// Deselects the view.
+    #[inline]
+    pub fn deselect(&mut self) -> &mut Self {
+        self.selected = false;
+        self
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V, I> Clone for Selectable<V, I>
+where
+    V: Clone,
+    I: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            indicator: self.indicator.clone(),
+            selected: self.selected,
+        }
+    }
+}
+
+impl<V, I> View for Selectable<V, I>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn is_selected(&self) -> Option<bool> {
+        Some(self.selected)
+    }
+
+    #[inline]
+    fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    forward_view_hooks!(field view; except is_selected, set_selected);
+}
+
+impl<C, V, I> Drawable for Selectable<V, I>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+    I: SelectionIndicator<C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.selected {
+            self.indicator.draw_selected(&self.view, display)
+        } else {
+            self.view.draw(display)?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::linear::LinearLayout, prelude::*, views::Visibility};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        prelude::{Primitive, Size},
+        primitives::{Line, PrimitiveStyle},
+    };
+
+    #[test]
+    fn outline_indicator() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        let line = Line::new(Point::new(1, 2), Point::new(3, 2)).into_styled(style);
+
+        let mut view = Selectable::new(line, OutlineIndicator::new(BinaryColor::On));
+        view.select();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        view.draw(&mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "     ", //
+                "#####", //
+                "#####", //
+                "#####", //
+            ])
+        );
+    }
+
+    #[test]
+    fn invert_indicator() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let dot = Rectangle::new(Point::new(1, 0), Size::new(1, 1)).into_styled(style);
+        let line = Line::new(Point::new(0, 1), Point::new(2, 1))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        let mut view = Selectable::new(Chain::new(dot).append(line), InvertIndicator);
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        view.draw(&mut disp).unwrap();
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                " # ", //
+                "###", //
+            ])
+        );
+
+        view.select();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        disp.set_allow_overdraw(true);
+        view.draw(&mut disp).unwrap();
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "#.#", //
+                "...", //
+            ])
+        );
+    }
+
+    #[test]
+    fn navigation_skips_views_that_cannot_be_selected() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+        let item = Selectable::new(rect, OutlineIndicator::new(BinaryColor::On));
+
+        let mut layout = LinearLayout::vertical(
+            Chain::new(item.clone())
+                .append(Visibility::new(rect))
+                .append(item),
+        )
+        .arrange();
+
+        assert_eq!(None, layout.selected());
+        assert_eq!(Some(2), layout.select_prev());
+        assert_eq!(Some(0), layout.select_next());
+        assert_eq!(Some(2), layout.select_next());
+        assert_eq!(Some(2), layout.selected());
+        assert_eq!(
+            Some(false),
+            layout.inner().parent.parent.object.is_selected()
+        );
+
+        let mut plain = LinearLayout::vertical(Chain::new(rect)).arrange();
+        assert_eq!(None, plain.select_next());
+    }
+}
@@ -0,0 +1,328 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// Segment bits, in the usual `a` to `g` order: top, top right, bottom right, bottom, bottom left,
+/// top left and middle.
+const DIGITS: [u8; 10] = [0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F];
+const MINUS: u8 = 0x40;
+const BLANK: u8 = 0x00;
+
+/// A number shown using seven-segment digits.
+///
+/// The number is right-aligned in a fixed number of digits, so the size of the view doesn't
+/// depend on the value. Negative numbers are shown with a minus sign in front of them. If the
+/// number doesn't fit, every digit shows a minus sign.
+///
+/// Segments that are not lit can be drawn using a separate color, like on an LCD.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+/// use embedded_layout::{prelude::*, views::SevenSegment};
+///
+/// let mut clock = SevenSegment::new(4, BinaryColor::On)
+///     .with_digit_size(Size::new(10, 19))
+///     .with_thickness(3)
+///     .with_leading_zeros(true);
+///
+/// clock.set_value(905);
+///
+/// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+/// let clock = clock.align_to(&display_area, horizontal::Center, vertical::Center);
+///
+/// // 4 digits with 3px space between them
+/// assert_eq!(Size::new(49, 19), clock.size());
+/// ```
+#[derive(Copy, Clone)]
+pub struct SevenSegment<C> {
+    position: Point,
+    value: i32,
+    digits: u32,
+    digit_size: Size,
+    thickness: u32,
+    spacing: u32,
+    leading_zeros: bool,
+    color: C,
+    off_color: Option<C>,
+}
+
+impl<C> SevenSegment<C>
+where
+    C: PixelColor,
+{
+    /// Creates a view that shows `0` using the given number of digits and color.
+    ///
+    /// The view is placed at the origin. Digits are 8px wide and 14px tall, segments are 2px
+    /// thick and the space between digits is the thickness of the segments.
+    #[inline]
+    pub fn new(digits: u32, color: C) -> Self {
+        Self {
+            position: Point::zero(),
+            value: 0,
+            digits,
+            digit_size: Size::new(8, 14),
+            thickness: 2,
+            spacing: 2,
+            leading_zeros: false,
+            color,
+            off_color: None,
+        }
+    }
+
+    /// Sets the size of a digit.
+    #[inline]
+    #[must_use]
+    pub fn with_digit_size(mut self, size: Size) -> Self {
+        self.digit_size = size;
+        self
+    }
+
+    /// Sets the thickness of the segments, and the space between digits.
+    ///
+    /// Use [`SevenSegment::with_spacing`] after this method to set a different space between
+    /// digits.
+    #[inline]
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: u32) -> Self {
+        self.thickness = thickness;
+        self.spacing = thickness;
+        self
+    }
+
+    /// Sets the space between digits.
+    #[inline]
+    #[must_use]
+    pub fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Shows zeros instead of blank digits in front of the number.
+    #[inline]
+    #[must_use]
+    pub fn with_leading_zeros(mut self, leading_zeros: bool) -> Self {
+        self.leading_zeros = leading_zeros;
+        self
+    }
+
+    /// Sets the color of the segments that are not lit.
+    ///
+    /// By default, these segments are not drawn.
+    #[inline]
+    #[must_use]
+    pub fn with_off_color(mut self, color: C) -> Self {
+        self.off_color = Some(color);
+        self
+    }
+
+    /// Sets the number to show.
+    #[inline]
+    #[must_use]
+    pub fn with_value(mut self, value: i32) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Changes the number to show.
+    #[inline]
+    pub fn set_value(&mut self, value: i32) {
+        self.value = value;
+    }
+
+    /// Returns the number that is shown.
+    #[inline]
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Returns the segments of the digit at position `idx`, counted from the left.
+    fn segments(&self, idx: u32) -> u8 {
+        let magnitude = self.value.unsigned_abs();
+        let negative = self.value < 0;
+
+        let mut len = 1;
+        while len < 10 && magnitude >= 10_u32.pow(len) {
+            len += 1;
+        }
+        if len + negative as u32 > self.digits {
+            return MINUS;
+        }
+
+        // Position counted from the right
+        let place = self.digits - 1 - idx;
+        if place < len {
+            DIGITS[(magnitude / 10_u32.pow(place) % 10) as usize]
+        } else if negative
+            && (if self.leading_zeros {
+                idx == 0
+            } else {
+                place == len
+            })
+        {
+            MINUS
+        } else if self.leading_zeros {
+            DIGITS[0]
+        } else {
+            BLANK
+        }
+    }
+
+    /// Returns the areas of the segments of a digit, in the order of the segment bits.
+    fn segment_areas(&self, top_left: Point) -> [Rectangle; 7] {
+        let t = self.thickness;
+        let Size { width, height } = self.digit_size;
+
+        let horizontal = Size::new(width.saturating_sub(2 * t), t);
+        let middle = (height.saturating_sub(t) / 2) as i32;
+        let upper = Size::new(t, (middle - t as i32).max(0) as u32);
+        let lower = Size::new(
+            t,
+            (height as i32 - t as i32 - (middle + t as i32)).max(0) as u32,
+        );
+
+        let right = (width.saturating_sub(t)) as i32;
+        let t = t as i32;
+
+        [
+            Rectangle::new(top_left + Point::new(t, 0), horizontal),
+            Rectangle::new(top_left + Point::new(right, t), upper),
+            Rectangle::new(top_left + Point::new(right, middle + t), lower),
+            Rectangle::new(top_left + Point::new(t, height as i32 - t), horizontal),
+            Rectangle::new(top_left + Point::new(0, middle + t), lower),
+            Rectangle::new(top_left + Point::new(0, t), upper),
+            Rectangle::new(top_left + Point::new(t, middle), horizontal),
+        ]
+    }
+}
+
+impl<C> View for SevenSegment<C>
+where
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.position += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let width = if self.digits == 0 {
+            0
+        } else {
+            self.digits * self.digit_size.width + (self.digits - 1) * self.spacing
+        };
+
+        Rectangle::new(self.position, Size::new(width, self.digit_size.height))
+    }
+}
+
+impl<C> Drawable for SevenSegment<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let step = (self.digit_size.width + self.spacing) as i32;
+
+        for idx in 0..self.digits {
+            let segments = self.segments(idx);
+            let top_left = self.position + Point::new(idx as i32 * step, 0);
+
+            for (bit, area) in self.segment_areas(top_left).iter().enumerate() {
+                let color = if segments & (1 << bit) != 0 {
+                    Some(self.color)
+                } else {
+                    self.off_color
+                };
+
+                if let Some(color) = color {
+                    display.fill_solid(area, color)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn digits(digits: u32) -> SevenSegment<BinaryColor> {
+        SevenSegment::new(digits, BinaryColor::On)
+            .with_digit_size(Size::new(3, 5))
+            .with_thickness(1)
+    }
+
+    #[test]
+    fn draws_number() {
+        let mut display = MockDisplay::new();
+        digits(3).with_value(-28).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "     #   # ", //
+            "      # # #", //
+            " #   #   # ", //
+            "    #   # #", //
+            "     #   # ", //
+        ]);
+    }
+
+    #[test]
+    fn leading_zeros_and_off_segments() {
+        let mut display = MockDisplay::new();
+        digits(2)
+            .with_value(7)
+            .with_leading_zeros(true)
+            .with_off_color(BinaryColor::Off)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " #   # ", //
+            "# # . #", //
+            " .   . ", //
+            "# # . #", //
+            " #   . ", //
+        ]);
+    }
+
+    #[test]
+    fn minus_sign_is_placed_before_the_number() {
+        let view = digits(3).with_value(-5);
+        assert_eq!(
+            [BLANK, MINUS, DIGITS[5]],
+            [0, 1, 2].map(|idx| view.segments(idx))
+        );
+
+        let view = view.with_leading_zeros(true);
+        assert_eq!(
+            [MINUS, DIGITS[0], DIGITS[5]],
+            [0, 1, 2].map(|idx| view.segments(idx))
+        );
+    }
+
+    #[test]
+    fn overflow_shows_minus_signs() {
+        let view = digits(2).with_value(-10);
+
+        assert_eq!(MINUS, view.segments(0));
+        assert_eq!(MINUS, view.segments(1));
+        assert_eq!(Size::new(7, 5), view.size());
+    }
+}
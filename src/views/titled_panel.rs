@@ -0,0 +1,301 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::AnchorPoint,
+    prelude::{PixelColor, Point, Primitive, Size},
+    primitives::{Line, PrimitiveStyle, Rectangle},
+    Drawable,
+};
+
+use crate::View;
+
+/// A frame with a title on its top edge around a content view.
+///
+/// The title is drawn over the top edge of the frame, which is interrupted around it. The content
+/// is placed inside the frame, below the title. Both the title and the content can be any view,
+/// for example a `Text` title and a [`LinearLayout`] content.
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     primitives::Rectangle,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::TitledPanel};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let panel = TitledPanel::new(
+///     Text::new("Voltage", Point::zero(), text_style),
+///     LinearLayout::vertical(
+///         Chain::new(Text::new("U1: 3.3V", Point::zero(), text_style))
+///             .append(Text::new("U2: 5.0V", Point::zero(), text_style)),
+///     )
+///     .arrange(),
+///     BinaryColor::On,
+/// )
+/// .with_padding(2);
+///
+/// let display_area = Rectangle::new(Point::zero(), Size::new(128, 64));
+/// let panel = panel.align_to(&display_area, horizontal::Center, vertical::Center);
+/// ```
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+pub struct TitledPanel<T, V, C> {
+    title: T,
+    content: V,
+    color: C,
+    padding: u32,
+    bounds: Rectangle,
+}
+
+impl<T, V, C> TitledPanel<T, V, C>
+where
+    T: View,
+    V: View,
+    C: PixelColor,
+{
+    /// Creates a panel around `content` with the given title and frame color.
+    ///
+    /// The panel is placed at the origin. The default padding between the frame and the content
+    /// is 1px.
+    #[inline]
+    pub fn new(title: T, content: V, color: C) -> Self {
+        let mut panel = Self {
+            title,
+            content,
+            color,
+            padding: 1,
+            bounds: Rectangle::zero(),
+        };
+        panel.arrange(Point::zero());
+        panel
+    }
+
+    /// Sets the space between the frame and the content.
+    #[inline]
+    #[must_use]
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self.arrange(self.bounds.top_left);
+        self
+    }
+
+    /// Returns a reference to the title.
+    #[inline]
+    pub fn title(&self) -> &T {
+        &self.title
+    }
+
+    /// Returns a reference to the content.
+    #[inline]
+    pub fn content(&self) -> &V {
+        &self.content
+    }
+
+    /// Modifies the content and arranges the panel again.
+    ///
+    /// The top left corner of the panel stays in place, the frame grows or shrinks to fit the
+    /// modified content.
+    #[inline]
+    pub fn modify_content<R>(&mut self, f: impl FnOnce(&mut V) -> R) -> R {
+        let result = f(&mut self.content);
+        self.arrange(self.bounds.top_left);
+        result
+    }
+
+    /// Consumes the panel and returns the title and the content.
+    #[inline]
+    pub fn into_inner(self) -> (T, V) {
+        (self.title, self.content)
+    }
+
+    /// The distance of the title from the left edge of the frame.
+    fn title_indent(&self) -> u32 {
+        self.padding + 2
+    }
+
+    /// Places the title and the content, with the top left corner of the panel at `position`.
+    fn arrange(&mut self, position: Point) {
+        let title = self.title.bounds();
+        let indent = self.title_indent();
+        self.title
+            .translate_impl(position + Point::new(indent as i32, 0) - title.top_left);
+
+        let content = self.content.bounds();
+        let content_top_left = position
+            + Point::new(
+                1 + self.padding as i32,
+                (title.size.height + self.padding) as i32,
+            );
+        self.content
+            .translate_impl(content_top_left - content.top_left);
+
+        let width =
+            (content.size.width + 2 * (self.padding + 1)).max(title.size.width + 2 * indent);
+        let height = title.size.height + content.size.height + 2 * self.padding + 1;
+
+        self.bounds = Rectangle::new(position, Size::new(width, height));
+    }
+
+    /// Returns the rectangle the frame is drawn around.
+    fn frame(&self) -> Rectangle {
+        let frame_top = self.title.size().height / 2;
+
+        Rectangle::new(
+            self.bounds.top_left + Point::new(0, frame_top as i32),
+            self.bounds.size - Size::new(0, frame_top),
+        )
+    }
+}
+
+impl<T, V, C> Clone for TitledPanel<T, V, C>
+where
+    T: Clone,
+    V: Clone,
+    C: PixelColor,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            title: self.title.clone(),
+            content: self.content.clone(),
+            color: self.color,
+            padding: self.padding,
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl<T, V, C> View for TitledPanel<T, V, C>
+where
+    T: View,
+    V: View,
+    C: PixelColor,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.title.translate_impl(by);
+        self.content.translate_impl(by);
+        self.bounds.top_left += by;
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    forward_view_hooks!(field content; except secondary_alignment_override, baseline, anchor, as_measurable_mut, as_resizable_mut, z_index);
+}
+
+impl<T, V, C> Drawable for TitledPanel<T, V, C>
+where
+    T: View + Drawable<Color = C>,
+    V: View + Drawable<Color = C>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let style = PrimitiveStyle::with_stroke(self.color, 1);
+
+        let frame = self.frame();
+        let top_left = frame.top_left;
+        let bottom_right = frame.anchor_point(AnchorPoint::BottomRight);
+        let top_right = Point::new(bottom_right.x, top_left.y);
+        let bottom_left = Point::new(top_left.x, bottom_right.y);
+
+        // Leave a 1px gap on both sides of the title.
+        let title = self.title.bounds();
+        let gap_left = title.top_left.x - 2;
+        let gap_right = title.anchor_point(AnchorPoint::BottomRight).x + 2;
+
+        if gap_left >= top_left.x {
+            Line::new(top_left, Point::new(gap_left, top_left.y))
+                .into_styled(style)
+                .draw(display)?;
+        }
+        if gap_right <= top_right.x {
+            Line::new(Point::new(gap_right, top_right.y), top_right)
+                .into_styled(style)
+                .draw(display)?;
+        }
+        // The corners belong to the horizontal edges.
+        if bottom_left.y - top_left.y > 1 {
+            let inset = Point::new(0, 1);
+            Line::new(top_left + inset, bottom_left - inset)
+                .into_styled(style)
+                .draw(display)?;
+            Line::new(top_right + inset, bottom_right - inset)
+                .into_styled(style)
+                .draw(display)?;
+        }
+        Line::new(bottom_left, bottom_right)
+            .into_styled(style)
+            .draw(display)?;
+
+        self.title.draw(display)?;
+        self.content.draw(display)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn draws_frame_around_content() {
+        let fill = PrimitiveStyle::with_fill(BinaryColor::On);
+        let title = Rectangle::new(Point::new(10, 10), Size::new(3, 3)).into_styled(fill);
+        let content = Rectangle::new(Point::new(-5, 3), Size::new(2, 2)).into_styled(fill);
+
+        let panel = TitledPanel::new(title, content, BinaryColor::On);
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(9, 8)),
+            panel.bounds()
+        );
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        panel.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "   ###   ", //
+            "## ### ##", //
+            "#  ###  #", //
+            "#       #", //
+            "# ##    #", //
+            "# ##    #", //
+            "#       #", //
+            "#########", //
+        ]);
+    }
+
+    #[test]
+    fn modify_content_resizes_frame() {
+        let title = Rectangle::new(Point::zero(), Size::new(3, 3));
+        let content = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut panel = TitledPanel::new(title, content, BinaryColor::On)
+            .with_padding(0)
+            .translate(Point::new(5, 5));
+
+        panel.modify_content(|content| content.size = Size::new(10, 4));
+
+        assert_eq!(
+            Rectangle::new(Point::new(5, 5), Size::new(12, 8)),
+            panel.bounds()
+        );
+        assert_eq!(Point::new(6, 8), panel.content().top_left);
+    }
+}
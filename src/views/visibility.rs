@@ -0,0 +1,298 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point, Size},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// A view that can be shown or hidden.
+///
+/// A hidden view is not drawn and takes up no space, so layouts can collapse optional parts
+/// without changing the type of their view group. [`LinearLayout`] doesn't add spacing around
+/// hidden views.
+///
+/// [`LinearLayout`]: crate::layout::linear::LinearLayout
+///
+/// # Example
+///
+/// ```rust
+/// # use embedded_graphics::{
+/// #     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+/// #     pixelcolor::BinaryColor,
+/// #     prelude::*,
+/// #     text::Text,
+/// # };
+/// use embedded_layout::{layout::linear::LinearLayout, prelude::*, views::Visibility};
+///
+/// let text_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+///
+/// let error_occurred = false;
+///
+/// let mut error_banner = Visibility::new(Text::new("Error!", Point::zero(), text_style));
+/// error_banner.set_visible(error_occurred);
+///
+/// let status = Text::new("Everything is fine", Point::zero(), text_style);
+///
+/// let layout = LinearLayout::vertical(Chain::new(error_banner).append(status)).arrange();
+///
+/// // The hidden banner takes up no space.
+/// assert_eq!(status.size(), layout.size());
+/// ```
+pub struct Visibility<V> {
+    view: V,
+    visible: bool,
+}
+
+impl<V> Visibility<V>
+where
+    V: View,
+{
+    /// Wraps the given view. The view is visible by default.
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self {
+            view,
+            visible: true,
+        }
+    }
+
+    /// Makes the view visible.
+    #[inline]
+    pub fn show(&mut self) -> &mut Self {
+        self.set_visible(true)
+    }
+
+    /// Hides the view.
+    #[inline]
+    pub fn hide(&mut self) -> &mut Self {
+        self.set_visible(false)
+    }
+
+    /// Shows or hides the view.
+    #[inline]
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Returns whether the view is visible.
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+
+    fn visible_view(&self) -> Option<&V> {
+        if self.visible {
+            Some(&self.view)
+        } else {
+            None
+        }
+    }
+
+    fn visible_view_mut(&mut self) -> Option<&mut V> {
+        if self.visible {
+            Some(&mut self.view)
+        } else {
+            None
+        }
+    }
+}
+
+impl<V> Clone for Visibility<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            visible: self.visible,
+        }
+    }
+}
+
+impl<V> View for Visibility<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.view.bounds();
+
+        if self.visible {
+            bounds
+        } else {
+            Rectangle::new(bounds.top_left, Size::zero())
+        }
+    }
+
+    // Hidden views can't be selected, but their selection state is still updated.
+    #[inline]
+    fn set_selected(&mut self, selected: bool) {
+        self.view.set_selected(selected)
+    }
+
+    #[inline]
+    fn z_index(&self) -> i32 {
+        self.view.z_index()
+    }
+
+    #[cfg(feature = "any")]
+    #[inline]
+    fn as_any(&self) -> Option<&dyn core::any::Any> {
+        self.view.as_any()
+    }
+
+    #[cfg(feature = "any")]
+    #[inline]
+    fn as_any_mut(&mut self) -> Option<&mut dyn core::any::Any> {
+        self.view.as_any_mut()
+    }
+
+    forward_view_hooks!(fn visible_view, visible_view_mut; except set_selected, z_index, as_any, as_any_mut);
+}
+
+impl<C, V> Drawable for Visibility<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.visible {
+            self.view.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        layout::linear::{FixedMargin, LinearLayout},
+        prelude::*,
+    };
+    use embedded_graphics::{
+        mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Primitive,
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn hidden_view_is_collapsed_and_not_drawn() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2)).into_styled(style);
+        let wide = Rectangle::new(Point::zero(), Size::new(4, 1)).into_styled(style);
+
+        let mut hidden = Visibility::new(wide);
+        hidden.hide();
+
+        let layout = LinearLayout::vertical(Chain::new(rect).append(hidden).append(rect)).arrange();
+
+        let mut disp: MockDisplay<BinaryColor> = MockDisplay::new();
+        layout.draw(&mut disp).unwrap();
+
+        assert_eq!(
+            disp,
+            MockDisplay::from_pattern(&[
+                "##", //
+                "##", "##", "##",
+            ])
+        );
+    }
+
+    #[test]
+    fn hidden_view_does_not_extend_group_bounds() {
+        let rect = Rectangle::new(Point::zero(), Size::new(2, 2));
+
+        let mut hidden = Visibility::new(Rectangle::new(Point::zero(), Size::new(4, 4)));
+        hidden.hide();
+
+        let layout = LinearLayout::vertical(Chain::new(rect).append(hidden.clone())).arrange();
+        assert_eq!(rect, layout.bounds());
+
+        let layout = LinearLayout::vertical(Chain::new(hidden).append(rect)).arrange();
+        assert_eq!(rect, layout.bounds());
+    }
+
+    #[test]
+    fn hidden_view_gets_no_spacing() {
+        let row = Rectangle::new(Point::zero(), Size::new(5, 5));
+
+        let mut hidden = Visibility::new(row);
+        hidden.hide();
+
+        let layout = LinearLayout::vertical(Chain::new(row).append(hidden).append(row))
+            .with_spacing(FixedMargin(4))
+            .arrange();
+
+        assert_eq!(Size::new(5, 14), layout.size());
+        assert_eq!(Point::new(0, 9), layout.bounds_of(2).top_left);
+    }
+
+    #[test]
+    fn spacers_are_not_hidden() {
+        let rect = Rectangle::new(Point::zero(), Size::new(5, 5));
+        let spacer = Rectangle::new(Point::zero(), Size::new(10, 0));
+
+        let layout = LinearLayout::horizontal(Chain::new(rect).append(spacer)).arrange();
+        assert_eq!(Size::new(15, 5), layout.size());
+
+        let layout = LinearLayout::horizontal(Chain::new(spacer).append(rect)).arrange();
+        assert_eq!(
+            Rectangle::new(Point::zero(), Size::new(15, 5)),
+            layout.bounds()
+        );
+        assert_eq!(Point::new(10, 0), layout.bounds_of(1).top_left);
+    }
+
+    #[test]
+    fn visible_view_behaves_like_wrapped_view() {
+        let rect = Rectangle::new(Point::new(1, 2), Size::new(3, 4));
+        let mut view = Visibility::new(rect);
+
+        assert!(view.is_visible());
+        assert_eq!(rect, view.bounds());
+
+        view.hide().translate_mut(Point::new(1, 1));
+        assert_eq!(
+            Rectangle::new(Point::new(2, 3), Size::zero()),
+            view.bounds()
+        );
+
+        view.show();
+        assert_eq!(rect.translate(Point::new(1, 1)), view.bounds());
+    }
+}
@@ -0,0 +1,110 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+    Drawable,
+};
+
+use crate::View;
+
+/// Sets the stacking order of the wrapped view.
+///
+/// The z-index is used by [`DrawableViewGroup::draw_ordered`] to draw views with a higher
+/// z-index over views with a lower z-index, independently of their order in the view group. Views
+/// that are not wrapped have a z-index of `0`.
+///
+/// See [`DrawableViewGroup::draw_ordered`] for an example.
+///
+/// [`DrawableViewGroup::draw_ordered`]: crate::view_group::DrawableViewGroup::draw_ordered
+pub struct ZOrder<V> {
+    view: V,
+    z_index: i32,
+}
+
+impl<V> ZOrder<V>
+where
+    V: View,
+{
+    /// Wraps the given view with the given z-index.
+    #[inline]
+    pub fn new(view: V, z_index: i32) -> Self {
+        Self { view, z_index }
+    }
+
+    /// Changes the z-index of the view.
+    #[inline]
+    pub fn set_z_index(&mut self, z_index: i32) {
+        self.z_index = z_index;
+    }
+
+    /// Returns a reference to the wrapped view.
+    #[inline]
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Returns a mutable reference to the wrapped view.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut V {
+        &mut self.view
+    }
+
+    /// Consumes the wrapper and returns the wrapped view.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V> Clone for ZOrder<V>
+where
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            z_index: self.z_index,
+        }
+    }
+}
+
+impl<V> View for ZOrder<V>
+where
+    V: View,
+{
+    #[inline]
+    fn translate_impl(&mut self, by: Point) {
+        self.view.translate_impl(by);
+    }
+
+    #[inline]
+    fn bounds(&self) -> Rectangle {
+        self.view.bounds()
+    }
+
+    #[inline]
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    forward_view_hooks!(field view; except z_index);
+}
+
+impl<C, V> Drawable for ZOrder<V>
+where
+    C: PixelColor,
+    V: View + Drawable<Color = C>,
+{
+    type Color = C;
+    type Output = ();
+
+    #[inline]
+    fn draw<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.view.draw(display)?;
+        Ok(())
+    }
+}